@@ -0,0 +1,54 @@
+//! Measures how long it takes to generate a moderately sized file through
+//! nested `quote!` constructs.
+//!
+//! Generator binaries are commonly built without optimizations, so this is
+//! mostly interesting to run in a debug build:
+//!
+//! ```text
+//! cargo bench --profile dev --bench quote
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use genco::prelude::*;
+
+fn generate(structs: usize, fields: usize) -> rust::Tokens {
+    let map = rust::import("std::collections", "HashMap");
+
+    quote! {
+        $(for s in 0..structs join ($['\n']) =>
+            #[derive(Debug, Clone)]
+            pub struct Struct$s {
+                $(for f in 0..fields => pub field$f: $(&map)<u32, String>,$['\r'])
+            }
+
+            impl Struct$s {
+                pub fn new() -> Self {
+                    Self {
+                        $(for f in 0..fields join (,$['\r']) => field$f: $(&map)::new())
+                    }
+                }
+
+                $(ref t => if s % 2 == 0 {
+                    quote_in!(*t => pub fn is_even(&self) -> bool { true });
+                })
+            }
+        )
+    }
+}
+
+fn quote(c: &mut Criterion) {
+    c.bench_function("quote", |b| {
+        b.iter(|| black_box(generate(black_box(50), black_box(10))))
+    });
+
+    c.bench_function("quote + format", |b| {
+        b.iter(|| {
+            let tokens = generate(black_box(50), black_box(10));
+            black_box(tokens.to_file_string().unwrap())
+        })
+    });
+}
+
+criterion_group!(benches, quote);
+criterion_main!(benches);
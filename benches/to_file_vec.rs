@@ -0,0 +1,42 @@
+//! Compares formatting a large file into a new vector of lines against
+//! reusing the lines of a previous run.
+//!
+//! ```text
+//! cargo bench --bench to_file_vec
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use genco::prelude::*;
+
+/// Generate a synthetic stream with the given number of lines.
+fn generate(lines: usize) -> rust::Tokens {
+    quote! {
+        $(for n in 0..lines => let value$n = $n + $(quoted(format!("line {n}")));$['\r'])
+    }
+}
+
+fn to_file_vec(c: &mut Criterion) {
+    let tokens = generate(black_box(50_000));
+
+    c.bench_function("to_file_vec", |b| {
+        b.iter(|| black_box(tokens.to_file_vec().unwrap()))
+    });
+
+    let mut lines = Vec::new();
+
+    c.bench_function("to_file_vec_into", |b| {
+        b.iter(|| {
+            tokens.to_file_vec_into(&mut lines).unwrap();
+            black_box(&lines);
+        })
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = to_file_vec
+}
+
+criterion_main!(benches);
@@ -67,6 +67,8 @@ pub(crate) enum Name {
     Ident(String),
     /// Character name.
     Char(char),
+    /// The `~` token.
+    Tilde(Token![~]),
 }
 
 impl Name {
@@ -76,6 +78,7 @@ impl Name {
             Name::Const(..) => LiteralName::Ident("const"),
             Name::Ident(name) => LiteralName::Ident(name.as_str()),
             Name::Char(c) => LiteralName::Char(*c),
+            Name::Tilde(..) => LiteralName::Ident("~"),
         }
     }
 }
@@ -86,15 +89,19 @@ impl q::ToTokens for Name {
             Name::Const(t) => t.to_tokens(tokens),
             Name::Ident(name) => name.to_tokens(tokens),
             Name::Char(c) => c.to_tokens(tokens),
+            Name::Tilde(t) => t.to_tokens(tokens),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum ControlKind {
     Space,
+    SoftSpace,
     Push,
     Line,
+    Indent,
+    Unindent,
 }
 
 #[derive(Debug)]
@@ -122,6 +129,25 @@ impl Control {
             _ => None,
         }
     }
+
+    /// Construct a control from an identifier.
+    pub(crate) fn from_ident(span: Span, ident: &str) -> Option<Self> {
+        match ident {
+            "indent" => Some(Self {
+                kind: ControlKind::Indent,
+                span,
+            }),
+            "unindent" => Some(Self {
+                kind: ControlKind::Unindent,
+                span,
+            }),
+            "~" => Some(Self {
+                kind: ControlKind::SoftSpace,
+                span,
+            }),
+            _ => None,
+        }
+    }
 }
 
 /// Items to process from the queue.
@@ -132,6 +158,8 @@ pub(crate) enum Ast {
     String {
         has_eval: bool,
         stream: TokenStream,
+        /// The content of the string if it's entirely static.
+        literal: Option<syn::LitStr>,
     },
     /// A quoted string.
     Quoted {
@@ -0,0 +1,208 @@
+use proc_macro2::{Span, TokenStream};
+use syn::parse::ParseStream;
+use syn::spanned::Spanned as _;
+use syn::{Result, Token};
+
+use crate::Ctxt;
+
+/// The name of the attribute used to configure the derive.
+const ATTRIBUTE: &str = "genco";
+
+/// The name of the language parameter added when no language is specified.
+const LANG: &str = "__GencoLang";
+
+/// Options parsed from a `#[genco(...)]` attribute.
+#[derive(Default)]
+struct Attrs {
+    /// The language to implement `FormatInto` for.
+    lang: Option<syn::Type>,
+    /// The compiled template, as produced by the quote parser.
+    template: Option<TokenStream>,
+}
+
+impl Attrs {
+    fn parse(cx: &Ctxt, attrs: &[syn::Attribute]) -> Result<Self> {
+        let mut output = Self::default();
+
+        for attr in attrs {
+            if !attr.path().is_ident(ATTRIBUTE) {
+                continue;
+            }
+
+            attr.parse_args_with(|input: ParseStream| {
+                while !input.is_empty() {
+                    let key = input.parse::<syn::Ident>()?;
+                    input.parse::<Token![=]>()?;
+
+                    if key == "lang" {
+                        output.lang = Some(input.parse()?);
+                    } else if key == "template" {
+                        let content;
+                        syn::braced!(content in input);
+
+                        let (req, stream) = crate::quote::Quote::new(cx).parse(&content)?;
+                        let check = req.into_check(&cx.receiver);
+
+                        output.template = Some(q::quote! {
+                            #stream
+                            #check
+                        });
+                    } else {
+                        return Err(syn::Error::new(
+                            key.span(),
+                            "unsupported attribute, expected `lang` or `template`",
+                        ));
+                    }
+
+                    if input.is_empty() {
+                        break;
+                    }
+
+                    input.parse::<Token![,]>()?;
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(output)
+    }
+}
+
+pub(crate) fn expand(input: syn::DeriveInput) -> Result<TokenStream> {
+    let cx = Ctxt::default();
+    let attrs = Attrs::parse(&cx, &input.attrs)?;
+
+    let body = match &input.data {
+        syn::Data::Struct(data) => {
+            let template = match attrs.template {
+                Some(template) => template,
+                None => return Err(missing_template(input.ident.span())),
+            };
+
+            let pattern = pattern(q::quote!(Self), &data.fields);
+
+            q::quote! {
+                let #pattern = self;
+                #template
+            }
+        }
+        syn::Data::Enum(data) => {
+            if attrs.template.is_some() {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "templates are specified on each variant of an enum",
+                ));
+            }
+
+            let mut arms = Vec::new();
+
+            for variant in &data.variants {
+                let template = match Attrs::parse(&cx, &variant.attrs)? {
+                    Attrs {
+                        lang: Some(lang), ..
+                    } => {
+                        return Err(syn::Error::new(
+                            lang.span(),
+                            "the language is specified on the enum",
+                        ));
+                    }
+                    Attrs {
+                        template: Some(template),
+                        ..
+                    } => template,
+                    _ => return Err(missing_template(variant.ident.span())),
+                };
+
+                let ident = &variant.ident;
+                let pattern = pattern(q::quote!(Self::#ident), &variant.fields);
+
+                arms.push(q::quote! {
+                    #pattern => {
+                        #template
+                    }
+                });
+            }
+
+            q::quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        syn::Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "FormatInto can't be derived for unions",
+            ));
+        }
+    };
+
+    let Ctxt { receiver, module } = &cx;
+
+    let mut generics = input.generics.clone();
+
+    let lang = match attrs.lang {
+        Some(lang) => q::quote!(#lang),
+        None => {
+            let lang = syn::Ident::new(LANG, Span::call_site());
+            generics
+                .params
+                .push(syn::parse_quote!(#lang: #module::lang::Lang));
+            q::quote!(#lang)
+        }
+    };
+
+    let type_params = input
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+
+    if !type_params.is_empty() {
+        let where_clause = generics.make_where_clause();
+
+        for ident in type_params {
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#ident: #module::tokens::FormatInto<#lang>));
+        }
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    Ok(q::quote! {
+        impl #impl_generics #module::tokens::FormatInto<#lang> for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn format_into(self, #receiver: &mut #module::tokens::Tokens<#lang>) {
+                #body
+            }
+        }
+    })
+}
+
+/// Construct a pattern which binds every field of a struct or a variant, where
+/// the fields of tuples are bound to `_0`, `_1`, and so on.
+fn pattern(path: TokenStream, fields: &syn::Fields) -> TokenStream {
+    match fields {
+        syn::Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            q::quote!(#path { #(#names),* })
+        }
+        syn::Fields::Unnamed(fields) => {
+            let names = (0..fields.unnamed.len())
+                .map(|n| syn::Ident::new(&format!("_{n}"), Span::call_site()));
+            q::quote!(#path(#(#names),*))
+        }
+        syn::Fields::Unit => path,
+    }
+}
+
+fn missing_template(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "missing template, expected `#[genco(template = { ... })]`",
+    )
+}
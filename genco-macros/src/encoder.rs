@@ -3,6 +3,7 @@ use crate::cursor::Cursor;
 use crate::fake::LineColumn;
 use crate::requirements::Requirements;
 use crate::static_buffer::StaticBuffer;
+use crate::static_quote::StaticItem;
 use crate::Ctxt;
 
 use proc_macro2::{Span, TokenStream};
@@ -24,8 +25,8 @@ pub(crate) struct Encoder<'a> {
     span_end: Option<LineColumn>,
     /// TODO: make private.
     item_buffer: StaticBuffer<'a>,
-    /// The token stream we are constructing.
-    output: TokenStream,
+    /// The output we are constructing.
+    output: Output,
     /// Currently stored cursor.
     last: Option<Cursor>,
     /// Which column the last line start on.
@@ -37,18 +38,45 @@ pub(crate) struct Encoder<'a> {
     pub(crate) requirements: Requirements,
 }
 
+/// The output of the encoder.
+enum Output {
+    /// Statements which build a token stream through the receiver.
+    Stream(TokenStream),
+    /// Items of purely static content.
+    Static(Vec<StaticItem>),
+}
+
 impl<'a> Encoder<'a> {
     pub(crate) fn new(
         cx: &'a Ctxt,
         span_start: Option<LineColumn>,
         span_end: Option<LineColumn>,
+    ) -> Self {
+        Self::with_output(cx, span_start, span_end, Output::Stream(TokenStream::new()))
+    }
+
+    /// Construct an encoder which only accepts static content, and encodes it
+    /// into static items.
+    pub(crate) fn new_static(
+        cx: &'a Ctxt,
+        span_start: Option<LineColumn>,
+        span_end: Option<LineColumn>,
+    ) -> Self {
+        Self::with_output(cx, span_start, span_end, Output::Static(Vec::new()))
+    }
+
+    fn with_output(
+        cx: &'a Ctxt,
+        span_start: Option<LineColumn>,
+        span_end: Option<LineColumn>,
+        output: Output,
     ) -> Self {
         Self {
             cx,
             span_start,
             span_end,
             item_buffer: StaticBuffer::new(cx),
-            output: TokenStream::new(),
+            output,
             last: None,
             last_start_column: None,
             indents: Vec::new(),
@@ -58,15 +86,28 @@ impl<'a> Encoder<'a> {
 
     /// Encode a single item into the encoder.
     pub(crate) fn encode(&mut self, cursor: Cursor, ast: Ast) -> Result<()> {
+        if let Output::Static(..) = self.output {
+            if let Some(what) = dynamic_content(&ast) {
+                return Err(syn::Error::new(
+                    cursor.span,
+                    format!("{what} is not supported in static content"),
+                ));
+            }
+        }
+
         self.step(cursor)?;
 
         match ast {
             Ast::Tree { tt, .. } => {
                 self.encode_literal(&tt.to_string());
             }
-            Ast::String { has_eval, stream } => {
+            Ast::String {
+                has_eval,
+                stream,
+                literal,
+            } => {
                 self.requirements.lang_supports_eval |= has_eval;
-                self.encode_string(has_eval, stream);
+                self.encode_string(has_eval, stream, literal);
             }
             Ast::Quoted { s } => {
                 self.encode_quoted(s);
@@ -127,7 +168,27 @@ impl<'a> Encoder<'a> {
     /// Finalize and translate into a token stream.
     pub(crate) fn into_output(mut self) -> Result<(Requirements, TokenStream)> {
         self.finalize()?;
-        Ok((self.requirements, self.output))
+
+        match self.output {
+            Output::Stream(stream) => Ok((self.requirements, stream)),
+            Output::Static(..) => Err(syn::Error::new(
+                Span::call_site(),
+                "encoder for static content can't produce a token stream",
+            )),
+        }
+    }
+
+    /// Finalize and translate into static items.
+    pub(crate) fn into_static_items(mut self) -> Result<Vec<StaticItem>> {
+        self.finalize()?;
+
+        match self.output {
+            Output::Static(items) => Ok(items),
+            Output::Stream(..) => Err(syn::Error::new(
+                Span::call_site(),
+                "encoder for a token stream can't produce static items",
+            )),
+        }
     }
 
     pub(crate) fn step(&mut self, next: Cursor) -> Result<()> {
@@ -154,12 +215,24 @@ impl<'a> Encoder<'a> {
         self.item_buffer.push_str(string);
     }
 
-    pub(crate) fn encode_string(&mut self, has_eval: bool, stream: TokenStream) {
+    pub(crate) fn encode_string(
+        &mut self,
+        has_eval: bool,
+        stream: TokenStream,
+        literal: Option<syn::LitStr>,
+    ) {
         let Ctxt { receiver, module } = self.cx;
 
-        self.item_buffer.flush(&mut self.output);
+        self.flush();
 
-        self.output.extend(q::quote! {
+        if let Output::Static(items) = &mut self.output {
+            items.push(StaticItem::OpenQuote(has_eval));
+            items.extend(literal.map(StaticItem::Literal));
+            items.push(StaticItem::CloseQuote);
+            return;
+        }
+
+        self.extend(q::quote! {
             #receiver.append(#module::tokens::Item::OpenQuote(#has_eval));
             #stream
             #receiver.append(#module::tokens::Item::CloseQuote);
@@ -169,9 +242,16 @@ impl<'a> Encoder<'a> {
     pub(crate) fn encode_quoted(&mut self, s: syn::LitStr) {
         let Ctxt { receiver, module } = self.cx;
 
-        self.item_buffer.flush(&mut self.output);
+        self.flush();
+
+        if let Output::Static(items) = &mut self.output {
+            items.push(StaticItem::OpenQuote(false));
+            items.push(StaticItem::Literal(s));
+            items.push(StaticItem::CloseQuote);
+            return;
+        }
 
-        self.output.extend(q::quote! {
+        self.extend(q::quote! {
             #receiver.append(#module::tokens::Item::OpenQuote(false));
             #receiver.append(#module::tokens::ItemStr::Static(#s));
             #receiver.append(#module::tokens::Item::CloseQuote);
@@ -179,36 +259,84 @@ impl<'a> Encoder<'a> {
     }
 
     pub(crate) fn encode_control(&mut self, control: Control) {
+        self.flush();
+        self.control(control.span, control.kind);
+    }
+
+    /// Emit a whitespace control, with statements using the given span.
+    fn control(&mut self, span: Span, kind: ControlKind) {
         let Ctxt { receiver, .. } = self.cx;
 
-        self.item_buffer.flush(&mut self.output);
+        let stream = match &mut self.output {
+            Output::Stream(stream) => stream,
+            Output::Static(items) => {
+                items.push(match kind {
+                    ControlKind::Space => StaticItem::Space,
+                    ControlKind::SoftSpace => StaticItem::SoftSpace,
+                    ControlKind::Push => StaticItem::Push,
+                    ControlKind::Line => StaticItem::Line,
+                    ControlKind::Indent => StaticItem::Indentation(1),
+                    ControlKind::Unindent => StaticItem::Indentation(-1),
+                });
+
+                return;
+            }
+        };
 
-        match control.kind {
+        match kind {
             ControlKind::Space => {
-                self.output
-                    .extend(q::quote_spanned!(control.span => #receiver.space();));
+                stream.extend(q::quote_spanned!(span => #receiver.space();));
+            }
+            ControlKind::SoftSpace => {
+                stream.extend(q::quote_spanned!(span => #receiver.soft_space();));
             }
             ControlKind::Push => {
-                self.output
-                    .extend(q::quote_spanned!(control.span => #receiver.push();));
+                stream.extend(q::quote_spanned!(span => #receiver.push();));
             }
             ControlKind::Line => {
-                self.output
-                    .extend(q::quote_spanned!(control.span => #receiver.line();));
+                stream.extend(q::quote_spanned!(span => #receiver.line();));
+            }
+            ControlKind::Indent => {
+                stream.extend(q::quote_spanned!(span => #receiver.indent();));
+            }
+            ControlKind::Unindent => {
+                stream.extend(q::quote_spanned!(span => #receiver.unindent();));
             }
         }
     }
 
+    /// Flush buffered static content to the output.
+    fn flush(&mut self) {
+        match &mut self.output {
+            Output::Stream(stream) => {
+                self.item_buffer.flush(stream);
+            }
+            Output::Static(items) => {
+                items.extend(self.item_buffer.take().map(StaticItem::Literal));
+            }
+        }
+    }
+
+    /// Extend the output with statements.
+    ///
+    /// Content which needs statements is rejected before it's encoded when
+    /// only static content is allowed, so this does nothing for static output.
+    fn extend(&mut self, tokens: TokenStream) {
+        if let Output::Stream(stream) = &mut self.output {
+            stream.extend(tokens);
+        }
+    }
+
     pub(crate) fn encode_scope(&mut self, binding: Option<syn::Ident>, content: TokenStream) {
         let Ctxt { receiver, .. } = self.cx;
 
         if binding.is_some() {
-            self.item_buffer.flush(&mut self.output);
+            self.flush();
         }
 
         let binding = binding.map(|b| q::quote_spanned!(b.span() => let #b = &mut *#receiver;));
 
-        self.output.extend(q::quote! {{
+        self.extend(q::quote! {{
             #binding
             #content
         }});
@@ -218,20 +346,29 @@ impl<'a> Encoder<'a> {
     pub(crate) fn encode_eval_ident(&mut self, ident: syn::Ident) {
         let Ctxt { receiver, .. } = self.cx;
 
-        self.item_buffer.flush(&mut self.output);
-        self.output.extend(q::quote! {
+        self.flush();
+        self.extend(q::quote! {
             #receiver.append(#ident);
         });
     }
 
     /// Encode an evaluation of the given expression.
     pub(crate) fn encode_eval(&mut self, expr: syn::Expr) {
-        let Ctxt { receiver, .. } = self.cx;
+        let Ctxt { receiver, module } = self.cx;
 
-        self.item_buffer.flush(&mut self.output);
-        self.output.extend(q::quote! {
-            #receiver.append(#expr);
-        });
+        self.flush();
+
+        // NB: Closures are passed through `from_fn`, so that the type of their
+        // argument can be inferred.
+        if let syn::Expr::Closure(..) = &expr {
+            self.extend(q::quote! {
+                #receiver.append(#module::tokens::from_fn(#expr));
+            });
+        } else {
+            self.extend(q::quote! {
+                #receiver.append(#expr);
+            });
+        }
     }
 
     pub(crate) fn encode_repeat(
@@ -241,24 +378,25 @@ impl<'a> Encoder<'a> {
         join: Option<TokenStream>,
         stream: TokenStream,
     ) {
-        self.item_buffer.flush(&mut self.output);
+        self.flush();
 
         if let Some(join) = join {
-            self.output.extend(q::quote! {
+            self.extend(q::quote! {
                 {
-                    let mut __it = IntoIterator::into_iter(#expr).peekable();
-
-                    while let Some(#pattern) = __it.next() {
-                        #stream
+                    let mut __first = true;
 
-                        if __it.peek().is_some() {
+                    for #pattern in #expr {
+                        if !__first {
                             #join
                         }
+
+                        __first = false;
+                        #stream
                     }
                 }
             });
         } else {
-            self.output.extend(q::quote! {
+            self.extend(q::quote! {
                 for #pattern in #expr {
                     #stream
                 }
@@ -273,18 +411,18 @@ impl<'a> Encoder<'a> {
         then_branch: TokenStream,
         else_branch: Option<TokenStream>,
     ) {
-        self.item_buffer.flush(&mut self.output);
+        self.flush();
 
         let else_branch = else_branch.map(|stream| q::quote!(else { #stream }));
 
-        self.output.extend(q::quote! {
+        self.extend(q::quote! {
             if #condition { #then_branch } #else_branch
         });
     }
 
     /// Encode an if statement with an inner stream.
     pub(crate) fn encode_match(&mut self, condition: syn::Expr, arms: Vec<MatchArm>) {
-        self.item_buffer.flush(&mut self.output);
+        self.flush();
 
         let mut stream = TokenStream::new();
 
@@ -303,14 +441,14 @@ impl<'a> Encoder<'a> {
             match #condition { #stream }
         };
 
-        self.output.extend(m);
+        self.extend(m);
     }
 
     /// Encode a let statement
     pub(crate) fn encode_let(&mut self, name: syn::Pat, expr: syn::Expr) {
-        self.item_buffer.flush(&mut self.output);
+        self.flush();
 
-        self.output.extend(q::quote! {
+        self.extend(q::quote! {
             let #name = #expr;
         })
     }
@@ -353,8 +491,6 @@ impl<'a> Encoder<'a> {
 
     /// Finalize the encoder.
     fn finalize(&mut self) -> Result<()> {
-        let Ctxt { receiver, .. } = self.cx;
-
         // evaluate whitespace in case we have an explicit end span.
         while let Some(to) = self.span_end.take() {
             if let Some(from) = self.from() {
@@ -363,10 +499,10 @@ impl<'a> Encoder<'a> {
             }
         }
 
-        self.item_buffer.flush(&mut self.output);
+        self.flush();
 
         while self.indents.pop().is_some() {
-            self.output.extend(q::quote!(#receiver.unindent();));
+            self.control(Span::call_site(), ControlKind::Unindent);
         }
 
         Ok(())
@@ -380,8 +516,6 @@ impl<'a> Encoder<'a> {
         to: LineColumn,
         to_span: Option<Span>,
     ) -> Result<()> {
-        let Ctxt { receiver: r, .. } = self.cx;
-
         // Do nothing if empty span.
         if from == to {
             return Ok(());
@@ -391,8 +525,8 @@ impl<'a> Encoder<'a> {
         if from.line == to.line {
             // Same line, but next item doesn't match.
             if from.column < to.column {
-                self.item_buffer.flush(&mut self.output);
-                self.output.extend(q::quote!(#r.space();));
+                self.flush();
+                self.control(Span::call_site(), ControlKind::Space);
             }
 
             return Ok(());
@@ -400,7 +534,7 @@ impl<'a> Encoder<'a> {
 
         // Line changed. Determine whether to indent, unindent, or hard break the
         // line.
-        self.item_buffer.flush(&mut self.output);
+        self.flush();
 
         debug_assert!(from.line < to.line);
 
@@ -409,26 +543,26 @@ impl<'a> Encoder<'a> {
         if let Some(last_start_column) = self.last_start_column.take() {
             if last_start_column < to.column {
                 self.indents.push((last_start_column, to_span));
-                self.output.extend(q::quote!(#r.indent();));
+                self.control(Span::call_site(), ControlKind::Indent);
 
                 if line {
-                    self.output.extend(q::quote!(#r.line();));
+                    self.control(Span::call_site(), ControlKind::Line);
                 }
             } else if last_start_column > to.column {
                 while let Some((column, _)) = self.indents.pop() {
                     if column > to.column && !self.indents.is_empty() {
-                        self.output.extend(q::quote!(#r.unindent();));
+                        self.control(Span::call_site(), ControlKind::Unindent);
 
                         if line {
-                            self.output.extend(q::quote!(#r.line();));
+                            self.control(Span::call_site(), ControlKind::Line);
                         }
 
                         continue;
                     } else if column == to.column {
-                        self.output.extend(q::quote!(#r.unindent();));
+                        self.control(Span::call_site(), ControlKind::Unindent);
 
                         if line {
-                            self.output.extend(q::quote!(#r.line();));
+                            self.control(Span::call_site(), ControlKind::Line);
                         }
 
                         break;
@@ -437,9 +571,9 @@ impl<'a> Encoder<'a> {
                     return Err(indentation_error(to.column, column, to_span));
                 }
             } else if line {
-                self.output.extend(q::quote!(#r.line();));
+                self.control(Span::call_site(), ControlKind::Line);
             } else {
-                self.output.extend(q::quote!(#r.push();));
+                self.control(Span::call_site(), ControlKind::Push);
             }
         }
 
@@ -476,3 +610,20 @@ impl<'a> Encoder<'a> {
         }
     }
 }
+
+/// Describe content which can't be encoded statically, if that's what the
+/// given item is.
+fn dynamic_content(ast: &Ast) -> Option<&'static str> {
+    let what = match ast {
+        Ast::String { literal: None, .. } => "string interpolation",
+        Ast::Scope { .. } => "a scope",
+        Ast::EvalIdent { .. } | Ast::Eval { .. } => "interpolation",
+        Ast::Loop { .. } => "a `for` loop",
+        Ast::Condition { .. } => "an `if` condition",
+        Ast::Match { .. } => "a `match` expression",
+        Ast::Let { .. } => "a `let` binding",
+        _ => return None,
+    };
+
+    Some(what)
+}
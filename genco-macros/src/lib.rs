@@ -36,13 +36,16 @@ impl Default for Ctxt {
 
 mod ast;
 mod cursor;
+mod derive_format_into;
 mod encoder;
 mod fake;
 mod quote;
 mod quote_fn;
 mod quote_in;
+mod quote_literal;
 mod requirements;
 mod static_buffer;
+mod static_quote;
 mod string_parser;
 
 #[proc_macro]
@@ -61,16 +64,17 @@ pub fn quote(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
     let Ctxt { receiver, module } = &cx;
 
-    let gen = q::quote! {{
-        let mut #receiver = #module::tokens::Tokens::new();
-
-        {
-            let mut #receiver = &mut #receiver;
-            #output
-        }
+    // NB: The output only ever refers to the receiver as a `&mut Tokens<_>`, so
+    // it's bound once to the stream being built instead of being re-borrowed in
+    // a nested scope.
+    let tokens = syn::Ident::new("__genco_macros_tokens", Span::call_site());
 
+    let gen = q::quote! {{
+        let mut #tokens = #module::tokens::Tokens::new();
+        let #receiver = &mut #tokens;
+        #output
         #check
-        #receiver
+        #tokens
     }};
 
     gen.into()
@@ -82,8 +86,30 @@ pub fn quote_in(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     quote_in.stream.into()
 }
 
+#[proc_macro]
+pub fn static_quote(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let static_quote = syn::parse_macro_input!(input as static_quote::StaticQuote);
+    static_quote.stream.into()
+}
+
+#[proc_macro]
+pub fn quote_literal(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let quote_literal = syn::parse_macro_input!(input as quote_literal::QuoteLiteral);
+    quote_literal.stream.into()
+}
+
 #[proc_macro]
 pub fn quote_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let quote_fn = syn::parse_macro_input!(input as quote_fn::QuoteFn);
     quote_fn.stream.into()
 }
+
+#[proc_macro_derive(FormatInto, attributes(genco))]
+pub fn derive_format_into(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    match derive_format_into::expand(input) {
+        Ok(stream) => stream.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
@@ -8,6 +8,7 @@ use crate::encoder::Encoder;
 use crate::fake::Buf;
 use crate::fake::LineColumn;
 use crate::requirements::Requirements;
+use crate::static_quote::StaticItem;
 use crate::string_parser::StringParser;
 use crate::Ctxt;
 
@@ -83,13 +84,21 @@ impl<'a> Quote<'a> {
         encoder.into_output()
     }
 
+    /// Parse until end of stream, where only static content is allowed.
+    pub(crate) fn parse_static(mut self, input: ParseStream) -> Result<Vec<StaticItem>> {
+        let mut encoder = Encoder::new_static(self.cx, self.span_start, self.span_end);
+        self.parse_inner(&mut encoder, input, 0)?;
+        encoder.into_static_items()
+    }
+
     /// Parse `if <condition> { <quoted> } [else { <quoted> }]`.
-    fn parse_condition(&self, input: ParseStream) -> Result<(Requirements, Ast)> {
+    fn parse_condition(&self, input: ParseStream, ws: Option<Ws>) -> Result<(Requirements, Ast)> {
         input.parse::<Token![if]>()?;
         let condition = syn::Expr::parse_without_eager_brace(input)?;
 
         if input.peek(Token![=>]) {
-            input.parse::<Token![=>]>()?;
+            let arrow = input.parse::<Token![=>]>()?;
+            expect_no_ws(ws, arrow.span())?;
             let (req, then_branch) = Quote::new(self.cx).parse(input)?;
 
             return Ok((
@@ -105,18 +114,20 @@ impl<'a> Quote<'a> {
         let mut req = Requirements::default();
 
         let content;
-        syn::braced!(content in input);
+        let braces = syn::braced!(content in input);
 
-        let (r, then_branch) = Quote::new(self.cx).parse(&content)?;
+        let (r, then_branch) =
+            self.parse_body(&content, braces.span.span(), ws.unwrap_or(Ws::Trim))?;
         req.merge_with(r);
 
         let else_branch = if input.peek(Token![else]) {
             input.parse::<Token![else]>()?;
 
             let content;
-            syn::braced!(content in input);
+            let braces = syn::braced!(content in input);
 
-            let (r, else_branch) = Quote::new(self.cx).parse(&content)?;
+            let (r, else_branch) =
+                self.parse_body(&content, braces.span.span(), ws.unwrap_or(Ws::Trim))?;
             req.merge_with(r);
 
             Some(else_branch)
@@ -135,7 +146,7 @@ impl<'a> Quote<'a> {
     }
 
     /// Parse `for <expr> in <iter> [join (<quoted>)] => <quoted>`.
-    fn parse_loop(&self, input: ParseStream) -> Result<(Requirements, Ast)> {
+    fn parse_loop(&self, input: ParseStream, ws: Option<Ws>) -> Result<(Requirements, Ast)> {
         syn::custom_keyword!(join);
 
         let mut req = Requirements::default();
@@ -161,18 +172,16 @@ impl<'a> Quote<'a> {
             None
         };
 
-        let content;
-
-        let input = if input.peek(Token![=>]) {
-            input.parse::<Token![=>]>()?;
-            input
+        let (r, stream) = if input.peek(Token![=>]) {
+            let arrow = input.parse::<Token![=>]>()?;
+            expect_no_ws(ws, arrow.span())?;
+            Quote::new(self.cx).parse(input)?
         } else {
-            syn::braced!(content in input);
-            &content
+            let content;
+            let braces = syn::braced!(content in input);
+            self.parse_body(&content, braces.span.span(), ws.unwrap_or(Ws::Trim))?
         };
 
-        let parser = Quote::new(self.cx);
-        let (r, stream) = parser.parse(input)?;
         req.merge_with(r);
 
         let ast = Ast::Loop {
@@ -185,7 +194,7 @@ impl<'a> Quote<'a> {
         Ok((req, ast))
     }
 
-    fn parse_match(&self, input: ParseStream) -> Result<(Requirements, Ast)> {
+    fn parse_match(&self, input: ParseStream, ws: Option<Ws>) -> Result<(Requirements, Ast)> {
         input.parse::<Token![match]>()?;
         let condition = syn::Expr::parse_without_eager_brace(input)?;
 
@@ -196,7 +205,8 @@ impl<'a> Quote<'a> {
         let mut arms = Vec::new();
 
         while !body.is_empty() {
-            let attr = input.call(syn::Attribute::parse_outer)?;
+            let mut attr = body.call(syn::Attribute::parse_outer)?;
+            let arm_ws = take_ws(&mut attr)?;
             let pattern = syn::Pat::parse_multi_with_leading_vert(&body)?;
 
             let condition = if body.peek(Token![if]) {
@@ -207,22 +217,26 @@ impl<'a> Quote<'a> {
                 None
             };
 
-            body.parse::<Token![=>]>()?;
+            let arrow = body.parse::<Token![=>]>()?;
 
             let (r, block) = if body.peek(token::Brace) {
                 let block;
-                syn::braced!(block in body);
-
-                let parser = Quote::new(self.cx);
-                parser.parse(&block)?
+                let braces = syn::braced!(block in body);
+                self.parse_body(
+                    &block,
+                    braces.span.span(),
+                    arm_ws.or(ws).unwrap_or(Ws::Trim),
+                )?
             } else if body.peek(token::Paren) {
                 let block;
                 let paren = syn::parenthesized!(block in body);
-
-                Quote::new(self.cx)
-                    .with_span(paren.span.span())?
-                    .parse(&block)?
+                self.parse_body(
+                    &block,
+                    paren.span.span(),
+                    arm_ws.or(ws).unwrap_or(Ws::Preserve),
+                )?
             } else {
+                expect_no_ws(arm_ws, arrow.span())?;
                 let parser = Quote::new_until_comma(self.cx);
                 parser.parse(&body)?
             };
@@ -244,6 +258,20 @@ impl<'a> Quote<'a> {
         Ok((req, Ast::Match { condition, arms }))
     }
 
+    /// Parse the delimited body of a control flow statement with the given
+    /// whitespace sensitivity.
+    fn parse_body(
+        &self,
+        content: ParseStream,
+        span: Span,
+        ws: Ws,
+    ) -> Result<(Requirements, TokenStream)> {
+        match ws {
+            Ws::Preserve => Quote::new(self.cx).with_span(span)?.parse(content),
+            Ws::Trim => Quote::new(self.cx).parse(content),
+        }
+    }
+
     fn parse_let(&self, input: ParseStream) -> Result<(Requirements, Ast)> {
         input.parse::<Token![let]>()?;
 
@@ -302,18 +330,28 @@ impl<'a> Quote<'a> {
 
         let cursor = self.buf.join(start, outer.span.span())?;
 
+        let mut attr = scope.call(syn::Attribute::parse_outer)?;
+        let ws = take_ws(&mut attr)?;
+
+        if let Some(attr) = attr.first() {
+            return Err(syn::Error::new(attr.span(), "Unsupported attribute"));
+        }
+
         let ast = if scope.peek(Token![if]) {
-            let (req, ast) = self.parse_condition(&scope)?;
+            let (req, ast) = self.parse_condition(&scope, ws)?;
             encoder.requirements.merge_with(req);
             ast
         } else if scope.peek(Token![for]) {
-            let (req, ast) = self.parse_loop(&scope)?;
+            let (req, ast) = self.parse_loop(&scope, ws)?;
             encoder.requirements.merge_with(req);
             ast
         } else if scope.peek(Token![match]) {
-            let (req, ast) = self.parse_match(&scope)?;
+            let (req, ast) = self.parse_match(&scope, ws)?;
             encoder.requirements.merge_with(req);
             ast
+        } else if ws.is_some() {
+            return Err(scope
+                .error("`#[ws(..)]` is only supported on `if`, `for`, and `match` expressions"));
         } else if scope.peek(Token![let]) {
             let (req, ast) = self.parse_let(&scope)?;
             encoder.requirements.merge_with(req);
@@ -372,11 +410,19 @@ impl<'a> Quote<'a> {
 
                         let cursor = self.buf.join(start, end)?;
 
+                        let literal = if options.has_expr.get() {
+                            None
+                        } else {
+                            let literal = options.literal.into_inner();
+                            Some(syn::LitStr::new(&literal, cursor.span))
+                        };
+
                         encoder.encode(
                             cursor,
                             Ast::String {
                                 has_eval: options.has_eval.get(),
                                 stream,
+                                literal,
                             },
                         )?;
                     }
@@ -398,11 +444,23 @@ impl<'a> Quote<'a> {
                         let cursor = self.buf.join(start.span(), end.span())?;
                         encoder.encode(cursor, Ast::Control { control })?;
                     }
-                    (LiteralName::Ident(string), _) => {
-                        return Err(syn::Error::new(
-                            name.span(),
-                            format!("Unsupported function `{string}`, expected one of: str"),
-                        ));
+                    (LiteralName::Ident(string), content) => {
+                        let control = match Control::from_ident(name.span(), string) {
+                            Some(control) => control,
+                            None => {
+                                return Err(syn::Error::new(name.span(), format!("Unsupported function `{string}`, expected one of: str, indent, unindent, ~")));
+                            }
+                        };
+
+                        if let Some(content) = content {
+                            return Err(syn::Error::new(
+                                content.span(),
+                                format!("Control `{string}` does not expect an argument"),
+                            ));
+                        }
+
+                        let cursor = self.buf.join(start.span(), end.span())?;
+                        encoder.encode(cursor, Ast::Control { control })?;
                     }
                 }
 
@@ -491,6 +549,78 @@ impl<'a> Quote<'a> {
     }
 }
 
+/// Whitespace sensitivity of a control flow body, as declared through the
+/// `#[ws(preserve)]` and `#[ws(trim)]` attributes.
+#[derive(Debug, Clone, Copy)]
+enum Ws {
+    /// Leading and trailing whitespace in the body is preserved.
+    Preserve,
+    /// Leading and trailing whitespace in the body is trimmed.
+    Trim,
+}
+
+/// Remove and parse any `#[ws(..)]` attribute among the given attributes.
+fn take_ws(attrs: &mut Vec<syn::Attribute>) -> Result<Option<Ws>> {
+    let mut ws = None;
+    let mut error = None;
+
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("ws") {
+            return true;
+        }
+
+        let result = attr.parse_args_with(|input: ParseStream| {
+            let ident = input.parse::<syn::Ident>()?;
+
+            if ident == "preserve" {
+                Ok(Ws::Preserve)
+            } else if ident == "trim" {
+                Ok(Ws::Trim)
+            } else {
+                Err(syn::Error::new(
+                    ident.span(),
+                    "Unsupported whitespace mode, expected one of: preserve, trim",
+                ))
+            }
+        });
+
+        match result {
+            Ok(_) if ws.is_some() => {
+                error = Some(syn::Error::new(
+                    attr.span(),
+                    "Duplicate `#[ws(..)]` attribute",
+                ));
+            }
+            Ok(value) => {
+                ws = Some(value);
+            }
+            Err(e) => {
+                error = Some(e);
+            }
+        }
+
+        false
+    });
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(ws)
+}
+
+/// Error if a whitespace mode was specified for a body which isn't delimited.
+fn expect_no_ws(ws: Option<Ws>, span: Span) -> Result<()> {
+    if ws.is_some() {
+        return Err(syn::Error::new(
+            span,
+            "`#[ws(..)]` requires a delimited body, like `{ <quoted> }`",
+        ));
+    }
+
+    Ok(())
+}
+
 /// Parse an internal function of the form:
 ///
 /// ```text
@@ -514,6 +644,8 @@ pub(crate) fn parse_internal_function<'a>(
 
     let name = if function.peek(Token![const]) {
         Name::Const(function.parse()?)
+    } else if function.peek(Token![~]) {
+        Name::Tilde(function.parse()?)
     } else if function.peek(syn::LitChar) {
         let c = function.parse::<syn::LitChar>()?;
         Name::Char(c.value())
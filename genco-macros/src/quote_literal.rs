@@ -0,0 +1,147 @@
+use core::fmt::Write;
+
+use proc_macro2::{Span, TokenStream};
+use syn::parse::{Parse, ParseStream};
+use syn::Result;
+
+use crate::static_quote::StaticItem;
+use crate::Ctxt;
+
+/// Number of spaces used per level of indentation, which is what the default
+/// `fmt::Config` for Rust uses.
+const INDENTATION: usize = 4;
+
+pub(crate) struct QuoteLiteral {
+    pub(crate) stream: TokenStream,
+}
+
+impl Parse for QuoteLiteral {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let cx = Ctxt::default();
+        let items = crate::quote::Quote::new(&cx).parse_static(input)?;
+
+        let mut w = Writer::default();
+
+        for item in items {
+            match item {
+                StaticItem::Literal(s) => {
+                    if w.quoted {
+                        w.literal(&escape(&s.value()));
+                    } else {
+                        w.literal(&s.value());
+                    }
+                }
+                StaticItem::Space | StaticItem::SoftSpace => w.whitespace(Whitespace::Space),
+                StaticItem::Push => w.whitespace(Whitespace::Push),
+                StaticItem::Line => w.whitespace(Whitespace::Line),
+                StaticItem::Indentation(n) => {
+                    w.whitespace(Whitespace::Push);
+                    w.indent = w.indent.saturating_add(n).max(0);
+                }
+                StaticItem::OpenQuote(..) => {
+                    w.literal("\"");
+                    w.quoted = true;
+                }
+                StaticItem::CloseQuote => {
+                    w.quoted = false;
+                    w.literal("\"");
+                }
+            }
+        }
+
+        let s = syn::LitStr::new(&w.out, Span::call_site());
+        Ok(Self {
+            stream: q::quote!(#s),
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Whitespace {
+    None,
+    Space,
+    Push,
+    Line,
+}
+
+/// Writer which mirrors how a token stream of static items is formatted.
+///
+/// Leading and trailing whitespace is dropped, spaces and line breaks are
+/// collapsed the same way as they are in a token stream, and indentation can
+/// never go below zero.
+struct Writer {
+    out: String,
+    ws: Whitespace,
+    indent: i16,
+    quoted: bool,
+}
+
+impl Default for Writer {
+    fn default() -> Self {
+        Self {
+            out: String::new(),
+            ws: Whitespace::None,
+            indent: 0,
+            quoted: false,
+        }
+    }
+}
+
+impl Writer {
+    fn whitespace(&mut self, ws: Whitespace) {
+        self.ws = self.ws.max(ws);
+    }
+
+    fn literal(&mut self, s: &str) {
+        if self.out.is_empty() {
+            self.write_indentation();
+        } else {
+            match self.ws {
+                Whitespace::None => {}
+                Whitespace::Space => self.out.push(' '),
+                Whitespace::Push => {
+                    self.out.push('\n');
+                    self.write_indentation();
+                }
+                Whitespace::Line => {
+                    self.out.push_str("\n\n");
+                    self.write_indentation();
+                }
+            }
+        }
+
+        self.ws = Whitespace::None;
+        self.out.push_str(s);
+    }
+
+    fn write_indentation(&mut self) {
+        let n = self.indent as usize * INDENTATION;
+        self.out.extend(std::iter::repeat(' ').take(n));
+    }
+}
+
+/// Escape the content of a quoted string the same way as `Rust::write_quoted`
+/// in genco does.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\\' => out.push_str("\\\\"),
+            '\0' => out.push_str("\\0"),
+            '"' => out.push_str("\\\""),
+            c if !c.is_control() => out.push(c),
+            c if (c as u32) < 0x80 => {
+                write!(out, "\\x{:02x}", c as u32).unwrap();
+            }
+            c => {
+                write!(out, "\\u{{{:04x}}}", c as u32).unwrap();
+            }
+        }
+    }
+
+    out
+}
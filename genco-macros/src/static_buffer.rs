@@ -27,14 +27,22 @@ impl<'a> StaticBuffer<'a> {
         self.buffer.push_str(s);
     }
 
+    /// Take the buffered string if there is one.
+    pub(crate) fn take(&mut self) -> Option<syn::LitStr> {
+        if self.buffer.is_empty() {
+            return None;
+        }
+
+        let s = syn::LitStr::new(&self.buffer, Span::call_site());
+        self.buffer.clear();
+        Some(s)
+    }
+
     /// Flush the line buffer if necessary.
     pub(crate) fn flush(&mut self, tokens: &mut TokenStream) {
-        if !self.buffer.is_empty() {
+        if let Some(s) = self.take() {
             let Ctxt { receiver, module } = self.cx;
-
-            let s = syn::LitStr::new(&self.buffer, Span::call_site());
             tokens.extend(q::quote!(#receiver.append(#module::tokens::ItemStr::Static(#s));));
-            self.buffer.clear();
         }
     }
 }
@@ -0,0 +1,52 @@
+use proc_macro2::TokenStream;
+use syn::parse::{Parse, ParseStream};
+use syn::Result;
+
+use crate::Ctxt;
+
+pub(crate) struct StaticQuote {
+    pub(crate) stream: TokenStream,
+}
+
+impl Parse for StaticQuote {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let cx = Ctxt::default();
+        let items = crate::quote::Quote::new(&cx).parse_static(input)?;
+
+        let Ctxt { module, .. } = &cx;
+
+        let items = items.into_iter().map(|item| match item {
+            StaticItem::Literal(s) => {
+                q::quote!(#module::tokens::Item::Literal(#module::tokens::ItemStr::Static(#s)))
+            }
+            StaticItem::Space => q::quote!(#module::tokens::Item::Space),
+            StaticItem::SoftSpace => q::quote!(#module::tokens::Item::SoftSpace),
+            StaticItem::Push => q::quote!(#module::tokens::Item::Push),
+            StaticItem::Line => q::quote!(#module::tokens::Item::Line),
+            StaticItem::Indentation(n) => q::quote!(#module::tokens::Item::Indentation(#n)),
+            StaticItem::OpenQuote(has_eval) => {
+                q::quote!(#module::tokens::Item::OpenQuote(#has_eval))
+            }
+            StaticItem::CloseQuote => q::quote!(#module::tokens::Item::CloseQuote),
+        });
+
+        let stream = q::quote! {
+            #module::tokens::StaticTokens::new(&[#(#items,)*])
+        };
+
+        Ok(Self { stream })
+    }
+}
+
+/// A single item of purely static quoted content, as produced by the encoder
+/// when only static content is allowed.
+pub(crate) enum StaticItem {
+    Literal(syn::LitStr),
+    Space,
+    SoftSpace,
+    Push,
+    Line,
+    Indentation(i16),
+    OpenQuote(bool),
+    CloseQuote,
+}
@@ -20,6 +20,12 @@ use crate::Ctxt;
 pub(crate) struct Options {
     /// If the parsed string has any evaluation statements in it.
     pub(crate) has_eval: Cell<bool>,
+    /// If the parsed string has any expressions in it, so that its content
+    /// isn't static.
+    pub(crate) has_expr: Cell<bool>,
+    /// The static content of the string, as long as it doesn't have any
+    /// expressions.
+    pub(crate) literal: RefCell<String>,
 }
 
 fn adjust_start(start: LineColumn) -> LineColumn {
@@ -105,6 +111,7 @@ impl<'a> Encoder<'a> {
         });
 
         self.options.has_eval.set(true);
+        self.options.has_expr.set(true);
         Ok(())
     }
 
@@ -126,6 +133,7 @@ impl<'a> Encoder<'a> {
         });
 
         self.options.has_eval.set(true);
+        self.options.has_expr.set(true);
         Ok(())
     }
 
@@ -143,6 +151,8 @@ impl<'a> Encoder<'a> {
         self.stream.borrow_mut().extend(q::quote! {
             #receiver.append(#expr);
         });
+
+        self.options.has_expr.set(true);
         Ok(())
     }
 
@@ -174,6 +184,7 @@ impl<'a> Encoder<'a> {
         };
 
         self.count.set(self.count.get().wrapping_add(1));
+        self.options.literal.borrow_mut().push_str(&lit.value());
 
         self.stream.borrow_mut().extend(q::quote! {
             #receiver.append(#module::tokens::ItemStr::Static(#lit));
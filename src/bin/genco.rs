@@ -0,0 +1,253 @@
+//! Render token streams serialized as genco IR, or runtime templates, into
+//! source code.
+//!
+//! ```text
+//! genco <lang> [options] [input]
+//! genco <lang> [options] --template <path> [--context <path>]
+//! ```
+//!
+//! Reads IR as produced by `Tokens::to_ir` from `input`, or from stdin if no
+//! input is specified, and writes the formatted output to stdout.
+//!
+//! With `--template`, a template as parsed by `genco::template::parse` is
+//! rendered instead. The values it interpolates are read from a JSON object in
+//! the `--context` file, where strings are added as-is and numbers and
+//! booleans as they are displayed.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process;
+
+use genco::fmt;
+use genco::lang::Lang;
+use genco::prelude::*;
+use genco::template;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+const USAGE: &str = "\
+Usage: genco <lang> [options] [input]
+       genco <lang> [options] --template <path> [--context <path>]
+
+Render genco IR read from <input> (or stdin) as formatted source code, or
+render a template with values from a JSON object in the context file.
+
+Languages:
+    c, csharp, dart, go, java, js, julia, matlab, nix, php, python,
+    rust, swift, typescript, verilog, vhdl, xml
+
+Options:
+    -o, --output <path>  Write output to <path> instead of stdout.
+    --lang <lang>        The language to render, instead of <lang>.
+    --template <path>    Render the template at <path> (or `-` for stdin).
+    --context <path>     Read template values from the JSON object at
+                         <path>.
+    --fragment           Format as a fragment, without imports or a file
+                         prologue.
+    --indent <n>         Indent using <n> spaces.
+    --tabs               Indent using tabs.
+    -h, --help           Print this help.
+";
+
+type Result<T, E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
+
+/// The source of the tokens to render.
+enum Source {
+    Ir(String),
+    Template { template: String, context: Context },
+}
+
+/// Values to interpolate into a template.
+type Context = BTreeMap<String, Value>;
+
+struct Options {
+    output: Option<String>,
+    fragment: bool,
+    indentation: Option<fmt::Indentation>,
+}
+
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("genco: {error}");
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let mut args = env::args().skip(1);
+
+    let mut lang = None;
+    let mut input = None;
+    let mut template = None;
+    let mut context = None;
+
+    let mut options = Options {
+        output: None,
+        fragment: false,
+        indentation: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                print!("{USAGE}");
+                return Ok(());
+            }
+            "-o" | "--output" => {
+                options.output = Some(args.next().ok_or("missing argument to --output")?);
+            }
+            "--lang" => {
+                lang = Some(args.next().ok_or("missing argument to --lang")?);
+            }
+            "--template" => {
+                template = Some(args.next().ok_or("missing argument to --template")?);
+            }
+            "--context" => {
+                context = Some(args.next().ok_or("missing argument to --context")?);
+            }
+            "--fragment" => {
+                options.fragment = true;
+            }
+            "--indent" => {
+                let n = args.next().ok_or("missing argument to --indent")?;
+                let n = n.parse().map_err(|_| format!("bad indentation `{n}`"))?;
+                options.indentation = Some(fmt::Indentation::Space(n));
+            }
+            "--tabs" => {
+                options.indentation = Some(fmt::Indentation::Tab);
+            }
+            arg if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option `{arg}`\n\n{USAGE}").into());
+            }
+            _ if lang.is_none() => {
+                lang = Some(arg);
+            }
+            _ if input.is_none() => {
+                input = Some(arg);
+            }
+            _ => {
+                return Err(format!("unexpected argument `{arg}`").into());
+            }
+        }
+    }
+
+    let lang = lang.ok_or_else(|| format!("missing language\n\n{USAGE}"))?;
+
+    let source = match template {
+        Some(template) => {
+            if let Some(input) = input {
+                return Err(format!("unexpected argument `{input}` with --template").into());
+            }
+
+            let context = match context {
+                Some(path) => {
+                    let context = read(Some(&path))?;
+                    serde_json::from_str(&context).map_err(|e| format!("{path}: {e}"))?
+                }
+                None => Context::new(),
+            };
+
+            Source::Template {
+                template: read(Some(&template))?,
+                context,
+            }
+        }
+        None => {
+            if context.is_some() {
+                return Err("--context can only be used with --template".into());
+            }
+
+            Source::Ir(read(input.as_deref())?)
+        }
+    };
+
+    let output = match lang.as_str() {
+        "c" => render::<C>(&source, &options)?,
+        "csharp" => render::<Csharp>(&source, &options)?,
+        "dart" => render::<Dart>(&source, &options)?,
+        "go" => render::<Go>(&source, &options)?,
+        "java" => render::<Java>(&source, &options)?,
+        "js" => render::<JavaScript>(&source, &options)?,
+        "julia" => render::<Julia>(&source, &options)?,
+        "matlab" => render::<Matlab>(&source, &options)?,
+        "nix" => render::<Nix>(&source, &options)?,
+        "php" => render::<Php>(&source, &options)?,
+        "python" => render::<Python>(&source, &options)?,
+        "rust" => render::<Rust>(&source, &options)?,
+        "swift" => render::<Swift>(&source, &options)?,
+        "typescript" => render::<TypeScript>(&source, &options)?,
+        "verilog" => render::<Verilog>(&source, &options)?,
+        "vhdl" => render::<Vhdl>(&source, &options)?,
+        "xml" => render::<Xml>(&source, &options)?,
+        _ => return Err(format!("unsupported language `{lang}`").into()),
+    };
+
+    match &options.output {
+        Some(path) => fs::write(path, output).map_err(|e| format!("{path}: {e}"))?,
+        None => io::stdout().write_all(output.as_bytes())?,
+    }
+
+    Ok(())
+}
+
+/// Read the file at `path`, or stdin if `path` is `None` or `-`.
+fn read(path: Option<&str>) -> Result<String> {
+    match path {
+        None | Some("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => Ok(fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?),
+    }
+}
+
+/// Decode the given IR or render the given template, and format it as
+/// language `L`.
+fn render<L>(source: &Source, options: &Options) -> Result<String>
+where
+    L: Lang,
+    L::Config: Default,
+    L::Item: DeserializeOwned,
+{
+    let tokens = match source {
+        Source::Ir(ir) => genco::Tokens::<L>::from_ir(ir)?,
+        Source::Template { template, context } => {
+            let template = template::parse::<L>(template)?;
+            let mut values = Vec::new();
+
+            for (name, value) in context {
+                let value = match value {
+                    Value::String(string) => string.clone(),
+                    Value::Number(number) => number.to_string(),
+                    Value::Bool(boolean) => boolean.to_string(),
+                    _ => return Err(format!("unsupported value for `{name}` in context").into()),
+                };
+
+                values.push((name.as_str(), value));
+            }
+
+            template.render(values)?
+        }
+    };
+
+    let mut fmt = fmt::Config::from_lang::<L>();
+
+    if let Some(indentation) = options.indentation {
+        fmt = fmt.with_indentation(indentation);
+    }
+
+    let mut w = fmt::FmtWriter::new(String::new());
+    let config = L::Config::default();
+
+    if options.fragment {
+        let format = L::Format::default();
+        tokens.format(&mut w.as_formatter(&fmt), &config, &format)?;
+    } else {
+        tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    }
+
+    Ok(w.into_inner())
+}
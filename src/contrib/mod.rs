@@ -0,0 +1,81 @@
+//! Reusable high-level components built on top of genco.
+//!
+//! These are components which render the same definition into multiple
+//! languages. They're available when the `contrib` feature is enabled.
+//!
+//! ```
+//! use genco::prelude::*;
+//! use genco::contrib::StringEnum;
+//!
+//! let color = StringEnum::new("Color")
+//!     .variant("Red", "red")
+//!     .variant("Green", "green");
+//!
+//! let tokens: python::Tokens = quote!($(&color));
+//!
+//! assert_eq!(
+//!     vec![
+//!         "from enum import Enum",
+//!         "",
+//!         "class Color(Enum):",
+//!         "    Red = \"red\"",
+//!         "    Green = \"green\"",
+//!     ],
+//!     tokens.to_file_vec()?
+//! );
+//! # Ok::<_, genco::fmt::Error>(())
+//! ```
+
+// NB: Without any of the supported languages enabled, there's nothing to
+// render.
+#[cfg_attr(
+    not(any(
+        feature = "lang-java",
+        feature = "lang-python",
+        feature = "lang-rust",
+        feature = "lang-typescript"
+    )),
+    allow(unused_imports, dead_code)
+)]
+mod string_enum;
+pub use self::string_enum::StringEnum;
+
+use crate::tokens::ItemStr;
+
+/// Construct a [StringEnum] with the given name and variants, where each
+/// variant is a pair of its name and string value.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::contrib::enum_map;
+///
+/// let status = enum_map("Status", [("Active", "active"), ("Banned", "banned")]);
+///
+/// let tokens: typescript::Tokens = quote!($(status.with_conversions(false)));
+///
+/// assert_eq!(
+///     vec![
+///         "export enum Status {",
+///         "    Active = \"active\",",
+///         "    Banned = \"banned\",",
+///         "}",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn enum_map<N, I, V, S>(name: N, variants: I) -> StringEnum
+where
+    N: Into<ItemStr>,
+    I: IntoIterator<Item = (V, S)>,
+    V: Into<ItemStr>,
+    S: Into<ItemStr>,
+{
+    variants
+        .into_iter()
+        .fold(StringEnum::new(name), |e, (variant, value)| {
+            e.variant(variant, value)
+        })
+}
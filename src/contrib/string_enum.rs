@@ -0,0 +1,342 @@
+use alloc::vec::Vec;
+
+use crate as genco;
+#[cfg(feature = "lang-java")]
+use crate::lang::{java, Java};
+#[cfg(feature = "lang-python")]
+use crate::lang::{python, Python};
+#[cfg(feature = "lang-rust")]
+use crate::lang::{rust, Rust};
+#[cfg(feature = "lang-typescript")]
+use crate::lang::{typescript, TypeScript};
+use crate::quote_in;
+use crate::tokens::{quoted, FormatInto, ItemStr};
+use crate::Tokens;
+
+/// An enum where each variant maps to a string.
+///
+/// This renders an idiomatic enum for each supported language, together with
+/// the means to convert it to and from its string representation unless
+/// disabled through [StringEnum::with_conversions].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::contrib::StringEnum;
+///
+/// let color = StringEnum::new("Color")
+///     .variant("Red", "red")
+///     .variant("Green", "green");
+///
+/// let tokens: rust::Tokens = quote!($(&color));
+///
+/// assert_eq!(
+///     vec![
+///         "pub enum Color {",
+///         "    Red,",
+///         "    Green,",
+///         "}",
+///         "",
+///         "impl Color {",
+///         "    pub fn as_str(&self) -> &'static str {",
+///         "        match self {",
+///         "            Self::Red => \"red\",",
+///         "            Self::Green => \"green\",",
+///         "        }",
+///         "    }",
+///         "",
+///         "    pub fn parse(value: &str) -> Option<Self> {",
+///         "        match value {",
+///         "            \"red\" => Some(Self::Red),",
+///         "            \"green\" => Some(Self::Green),",
+///         "            _ => None,",
+///         "        }",
+///         "    }",
+///         "}",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+///
+/// let tokens: java::Tokens = quote!($(&color));
+///
+/// assert_eq!(
+///     vec![
+///         "public enum Color {",
+///         "    Red(\"red\"),",
+///         "    Green(\"green\");",
+///         "",
+///         "    private final String value;",
+///         "",
+///         "    Color(String value) {",
+///         "        this.value = value;",
+///         "    }",
+///         "",
+///         "    public String getValue() {",
+///         "        return this.value;",
+///         "    }",
+///         "",
+///         "    public static Color fromValue(String value) {",
+///         "        for (Color variant : values()) {",
+///         "            if (variant.value.equals(value)) {",
+///         "                return variant;",
+///         "            }",
+///         "        }",
+///         "",
+///         "        throw new IllegalArgumentException(value);",
+///         "    }",
+///         "}",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+///
+/// let tokens: typescript::Tokens = quote!($(&color));
+///
+/// assert_eq!(
+///     vec![
+///         "export enum Color {",
+///         "    Red = \"red\",",
+///         "    Green = \"green\",",
+///         "}",
+///         "",
+///         "export function parseColor(value: string): Color | undefined {",
+///         "    switch (value) {",
+///         "        case \"red\":",
+///         "            return Color.Red;",
+///         "        case \"green\":",
+///         "            return Color.Green;",
+///         "        default:",
+///         "            return undefined;",
+///         "    }",
+///         "}",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct StringEnum {
+    /// Name of the enum.
+    name: ItemStr,
+    /// Variants and their string values.
+    variants: Vec<(ItemStr, ItemStr)>,
+    /// Whether to render functions to convert to and from strings.
+    #[cfg_attr(
+        not(any(
+            feature = "lang-java",
+            feature = "lang-rust",
+            feature = "lang-typescript"
+        )),
+        allow(dead_code)
+    )]
+    conversions: bool,
+}
+
+impl StringEnum {
+    /// Construct a new enum with the given name and no variants.
+    pub fn new<N>(name: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        Self {
+            name: name.into(),
+            variants: Vec::new(),
+            conversions: true,
+        }
+    }
+
+    /// Add a variant which maps to the given string value.
+    pub fn variant<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<ItemStr>,
+        V: Into<ItemStr>,
+    {
+        self.variants.push((name.into(), value.into()));
+        self
+    }
+
+    /// Configure whether functions to convert the enum to and from strings
+    /// are rendered, which they are by default.
+    ///
+    /// Without them, languages where the string values are only used by the
+    /// conversions render a plain enum instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::contrib::StringEnum;
+    ///
+    /// let color = StringEnum::new("Color")
+    ///     .variant("Red", "red")
+    ///     .variant("Green", "green")
+    ///     .with_conversions(false);
+    ///
+    /// let tokens: rust::Tokens = quote!($(&color));
+    /// assert_eq!(vec!["pub enum Color {", "    Red,", "    Green,", "}"], tokens.to_file_vec()?);
+    ///
+    /// let tokens: java::Tokens = quote!($(&color));
+    /// assert_eq!(vec!["public enum Color {", "    Red,", "    Green;", "}"], tokens.to_file_vec()?);
+    ///
+    /// let tokens: typescript::Tokens = quote!($(&color));
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "export enum Color {",
+    ///         "    Red = \"red\",",
+    ///         "    Green = \"green\",",
+    ///         "}",
+    ///     ],
+    ///     tokens.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_conversions(self, conversions: bool) -> Self {
+        Self {
+            conversions,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "lang-rust")]
+impl FormatInto<Rust> for &StringEnum {
+    fn format_into(self, t: &mut rust::Tokens) {
+        let name = &self.name;
+
+        quote_in! { *t =>
+            pub enum $name {
+                $(for (variant, _) in &self.variants => $variant,$['\r'])
+            }
+        }
+
+        if !self.conversions {
+            return;
+        }
+
+        quote_in! { *t =>
+            $['\n']
+            impl $name {
+                pub fn as_str(&self) -> &'static str {
+                    match self {
+                        $(for (variant, value) in &self.variants => Self::$variant => $(quoted(value)),$['\r'])
+                    }
+                }
+
+                pub fn parse(value: &str) -> Option<Self> {
+                    match value {
+                        $(for (variant, value) in &self.variants => $(quoted(value)) => Some(Self::$variant),$['\r'])
+                        _ => None,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "lang-python")]
+impl FormatInto<Python> for &StringEnum {
+    fn format_into(self, t: &mut python::Tokens) {
+        let enum_ = python::import("enum", "Enum");
+
+        quote_in! { *t =>
+            class $(&self.name)($enum_):
+                $(for (variant, value) in &self.variants => $variant = $(quoted(value))$['\r'])
+        }
+    }
+}
+
+#[cfg(feature = "lang-java")]
+impl FormatInto<Java> for &StringEnum {
+    fn format_into(self, t: &mut java::Tokens) {
+        let name = &self.name;
+
+        if !self.conversions {
+            quote_in! { *t =>
+                public enum $name {
+                    $(for (variant, _) in &self.variants join (,$['\r']) => $variant);
+                }
+            }
+
+            return;
+        }
+
+        quote_in! { *t =>
+            public enum $name {
+                $(for (variant, value) in &self.variants join (,$['\r']) => $variant($(quoted(value))));
+
+                private final String value;
+
+                $name(String value) {
+                    this.value = value;
+                }
+
+                public String getValue() {
+                    return this.value;
+                }
+
+                public static $name fromValue(String value) {
+                    for ($name variant : values()) {
+                        if (variant.value.equals(value)) {
+                            return variant;
+                        }
+                    }
+
+                    throw new IllegalArgumentException(value);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "lang-typescript")]
+impl FormatInto<TypeScript> for &StringEnum {
+    fn format_into(self, t: &mut typescript::Tokens) {
+        let name = &self.name;
+
+        quote_in! { *t =>
+            export enum $name {
+                $(for (variant, value) in &self.variants => $variant = $(quoted(value)),$['\r'])
+            }
+        }
+
+        if !self.conversions {
+            return;
+        }
+
+        quote_in! { *t =>
+            $['\n']
+            export function parse$name(value: string): $name | undefined {
+                switch (value) {
+                    $(for (variant, value) in &self.variants =>
+                        case $(quoted(value)):
+                            return $name.$variant;$['\r']
+                    )
+                    default:
+                        return undefined;
+                }
+            }
+        }
+    }
+}
+
+macro_rules! owned {
+    ($($lang:ty => $feature:literal),*) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl FormatInto<$lang> for StringEnum {
+                #[inline]
+                fn format_into(self, t: &mut Tokens<$lang>) {
+                    (&self).format_into(t);
+                }
+            }
+        )*
+    };
+}
+
+owned! {
+    Rust => "lang-rust",
+    Python => "lang-python",
+    Java => "lang-java",
+    TypeScript => "lang-typescript"
+}
@@ -0,0 +1,500 @@
+//! Line-based diffing of formatted output.
+//!
+//! This is intended for generator test suites and helpers which write files,
+//! to report what changed between two versions of a file in a human-readable
+//! form. The lines being compared are typically produced by
+//! [Tokens::to_file_vec].
+//!
+//! # Examples
+//!
+//! ```
+//! use genco::diff;
+//! use genco::prelude::*;
+//!
+//! let old: rust::Tokens = quote! {
+//!     fn main() {
+//!         println!("Hello");
+//!     }
+//! };
+//!
+//! let new: rust::Tokens = quote! {
+//!     fn main() {
+//!         println!("Hello, World");
+//!     }
+//! };
+//!
+//! let old = old.to_file_vec()?;
+//! let new = new.to_file_vec()?;
+//!
+//! let ops = diff::lines(&old, &new);
+//!
+//! assert_eq!(
+//!     "@@ -1,3 +1,3 @@\n fn main() {\n-    println!(\"Hello\");\n+    println!(\"Hello, World\");\n }\n",
+//!     diff::unified(&ops).to_string()
+//! );
+//! # Ok::<_, genco::fmt::Error>(())
+//! ```
+//!
+//! [Tokens::to_file_vec]: crate::Tokens::to_file_vec
+
+use core::fmt;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A single operation in a line-based diff.
+///
+/// Produced by [lines()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DiffOp<'a> {
+    /// A line which is the same in both versions.
+    Equal(&'a str),
+    /// A line which is only in the old version.
+    Delete(&'a str),
+    /// A line which is only in the new version.
+    Insert(&'a str),
+}
+
+impl DiffOp<'_> {
+    /// Test if the operation is a change, as opposed to an equal line.
+    pub fn is_change(&self) -> bool {
+        !matches!(self, Self::Equal(..))
+    }
+}
+
+/// Compute the shortest sequence of operations which turns the `old` lines
+/// into the `new` lines.
+///
+/// Where there's a choice, deletions are ordered before insertions.
+///
+/// # Examples
+///
+/// ```
+/// use genco::diff::{self, DiffOp};
+///
+/// let ops = diff::lines(&["a", "b", "c"], &["a", "c", "d"]);
+///
+/// assert_eq!(
+///     vec![
+///         DiffOp::Equal("a"),
+///         DiffOp::Delete("b"),
+///         DiffOp::Equal("c"),
+///         DiffOp::Insert("d"),
+///     ],
+///     ops
+/// );
+/// ```
+pub fn lines<'a, S>(old: &'a [S], new: &'a [S]) -> Vec<DiffOp<'a>>
+where
+    S: AsRef<str>,
+{
+    let old = old.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+    let new = new.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+
+    edits(&old, &new, |a, b| a == b)
+        .into_iter()
+        .map(|edit| match edit {
+            Edit::Equal(n) => DiffOp::Equal(old[n]),
+            Edit::Delete(n) => DiffOp::Delete(old[n]),
+            Edit::Insert(n) => DiffOp::Insert(new[n]),
+        })
+        .collect()
+}
+
+/// An edit which turns one sequence into another, referring to elements by
+/// their index.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Edit {
+    /// Element at the given index in the old sequence is also in the new one.
+    Equal(usize),
+    /// Element at the given index in the old sequence is deleted.
+    Delete(usize),
+    /// Element at the given index in the new sequence is inserted.
+    Insert(usize),
+}
+
+/// Compute the shortest sequence of edits which turns `old` into `new`, where
+/// elements are compared with `eq`.
+pub(crate) fn edits<T, F>(old: &[T], new: &[T], eq: F) -> Vec<Edit>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    // Elements in common at the start and end don't need to go through the
+    // search below.
+    let prefix = old
+        .iter()
+        .zip(new.iter())
+        .take_while(|(a, b)| eq(a, b))
+        .count();
+
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| eq(a, b))
+        .count();
+
+    let mut edits = Vec::with_capacity(usize::max(old.len(), new.len()));
+    edits.extend((0..prefix).map(Edit::Equal));
+    myers(
+        &mut edits,
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+        prefix,
+        &eq,
+    );
+
+    edits.extend((old.len() - suffix..old.len()).map(Edit::Equal));
+    edits
+}
+
+/// Display the given operations as a unified diff, with three lines of context
+/// around each change.
+///
+/// Nothing is displayed if there are no changes.
+///
+/// # Examples
+///
+/// ```
+/// use genco::diff;
+///
+/// let old = ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10"];
+/// let new = ["1", "two", "3", "4", "5", "6", "7", "8", "9", "10", "11"];
+///
+/// let ops = diff::lines(&old, &new);
+///
+/// assert_eq!(
+///     vec![
+///         "@@ -1,5 +1,5 @@",
+///         " 1",
+///         "-2",
+///         "+two",
+///         " 3",
+///         " 4",
+///         " 5",
+///         "@@ -8,3 +8,4 @@",
+///         " 8",
+///         " 9",
+///         " 10",
+///         "+11",
+///     ],
+///     diff::unified(&ops).to_string().lines().collect::<Vec<_>>()
+/// );
+///
+/// assert_eq!(
+///     vec![
+///         "@@ -1,10 +1,11 @@",
+///         " 1",
+///         "-2",
+///         "+two",
+///     ],
+///     diff::unified(&ops).with_context(8).to_string().lines().take(4).collect::<Vec<_>>()
+/// );
+/// ```
+pub fn unified<'a>(ops: &'a [DiffOp<'a>]) -> Unified<'a> {
+    Unified { ops, context: 3 }
+}
+
+/// A unified diff which can be displayed.
+///
+/// Created through the [unified()] function.
+#[derive(Debug, Clone, Copy)]
+pub struct Unified<'a> {
+    ops: &'a [DiffOp<'a>],
+    context: usize,
+}
+
+impl Unified<'_> {
+    /// Configure the number of unchanged lines to display around each change.
+    pub fn with_context(self, context: usize) -> Self {
+        Self { context, ..self }
+    }
+}
+
+impl fmt::Display for Unified<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ops = self.ops;
+        let mut n = 0;
+        // Line numbers in the old and the new version at the start of `ops[n]`.
+        let mut old_line = 0;
+        let mut new_line = 0;
+
+        while let Some(first) = ops[n..].iter().position(DiffOp::is_change) {
+            let first = n + first;
+            let start = usize::max(first.saturating_sub(self.context), n);
+
+            // Extend the hunk for as long as the next change is close enough
+            // for the context of both to overlap.
+            let mut end = first + 1;
+
+            while let Some(next) = ops[end..].iter().position(DiffOp::is_change) {
+                if next > self.context * 2 {
+                    break;
+                }
+
+                end += next + 1;
+            }
+
+            let end = usize::min(end + self.context, ops.len());
+
+            for op in &ops[n..start] {
+                advance(op, &mut old_line, &mut new_line);
+            }
+
+            let hunk = &ops[start..end];
+            let old_len = hunk
+                .iter()
+                .filter(|op| !matches!(op, DiffOp::Insert(..)))
+                .count();
+            let new_len = hunk
+                .iter()
+                .filter(|op| !matches!(op, DiffOp::Delete(..)))
+                .count();
+
+            writeln!(
+                f,
+                "@@ -{} +{} @@",
+                Range(old_line, old_len),
+                Range(new_line, new_len)
+            )?;
+
+            for op in hunk {
+                match op {
+                    DiffOp::Equal(line) => writeln!(f, " {line}")?,
+                    DiffOp::Delete(line) => writeln!(f, "-{line}")?,
+                    DiffOp::Insert(line) => writeln!(f, "+{line}")?,
+                }
+
+                advance(op, &mut old_line, &mut new_line);
+            }
+
+            n = end;
+        }
+
+        return Ok(());
+
+        fn advance(op: &DiffOp<'_>, old_line: &mut usize, new_line: &mut usize) {
+            match op {
+                DiffOp::Equal(..) => {
+                    *old_line += 1;
+                    *new_line += 1;
+                }
+                DiffOp::Delete(..) => *old_line += 1,
+                DiffOp::Insert(..) => *new_line += 1,
+            }
+        }
+
+        /// The range of a hunk, where an empty range refers to the line
+        /// before it.
+        struct Range(usize, usize);
+
+        impl fmt::Display for Range {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let Range(start, len) = *self;
+
+                if len == 0 {
+                    write!(f, "{start},0")
+                } else {
+                    write!(f, "{},{len}", start + 1)
+                }
+            }
+        }
+    }
+}
+
+/// Find the shortest edit script between `a` and `b` using the linear space
+/// variant of Myers' algorithm, appending it to `ops` with indexes offset by
+/// `base`.
+///
+/// Each step finds the middle snake of an optimal path, and recurses on the
+/// parts before and after it. So memory use is linear in the size of the
+/// input, regardless of how many edits there are.
+fn myers<T, F>(ops: &mut Vec<Edit>, a: &[T], b: &[T], base: usize, eq: &F)
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let max = (a.len() + b.len() + 1) / 2 + 1;
+    let mut vf = V::new(max);
+    let mut vb = V::new(max);
+
+    let start = ops.len();
+    conquer(
+        ops,
+        a,
+        (0, a.len()),
+        b,
+        (0, b.len()),
+        base,
+        eq,
+        &mut vf,
+        &mut vb,
+    );
+
+    // NB: The recursion might interleave deletions and insertions within a
+    // run of changes, so each run is ordered with deletions first.
+    let mut n = start;
+
+    while n < ops.len() {
+        if let Edit::Equal(..) = ops[n] {
+            n += 1;
+            continue;
+        }
+
+        let end = ops[n..]
+            .iter()
+            .position(|edit| matches!(edit, Edit::Equal(..)))
+            .map_or(ops.len(), |len| n + len);
+
+        // Stable, so the order within deletions and insertions is kept.
+        ops[n..end].sort_by_key(|edit| matches!(edit, Edit::Insert(..)));
+        n = end;
+    }
+}
+
+/// Diagonals of the edit graph, indexed by `k` in `-max..=max`.
+struct V {
+    offset: isize,
+    data: Vec<usize>,
+}
+
+impl V {
+    fn new(max: usize) -> Self {
+        Self {
+            offset: max as isize + 1,
+            data: vec![0; 2 * max + 3],
+        }
+    }
+}
+
+impl core::ops::Index<isize> for V {
+    type Output = usize;
+
+    #[inline]
+    fn index(&self, k: isize) -> &usize {
+        &self.data[(k + self.offset) as usize]
+    }
+}
+
+impl core::ops::IndexMut<isize> for V {
+    #[inline]
+    fn index_mut(&mut self, k: isize) -> &mut usize {
+        &mut self.data[(k + self.offset) as usize]
+    }
+}
+
+/// Append the edits which turn `a[a_lo..a_hi]` into `b[b_lo..b_hi]`.
+#[allow(clippy::too_many_arguments)]
+fn conquer<T, F>(
+    ops: &mut Vec<Edit>,
+    a: &[T],
+    (mut a_lo, mut a_hi): (usize, usize),
+    b: &[T],
+    (mut b_lo, mut b_hi): (usize, usize),
+    base: usize,
+    eq: &F,
+    vf: &mut V,
+    vb: &mut V,
+) where
+    F: Fn(&T, &T) -> bool,
+{
+    while a_lo < a_hi && b_lo < b_hi && eq(&a[a_lo], &b[b_lo]) {
+        ops.push(Edit::Equal(base + a_lo));
+        a_lo += 1;
+        b_lo += 1;
+    }
+
+    let mut suffix = 0;
+
+    while a_lo < a_hi && b_lo < b_hi && eq(&a[a_hi - 1], &b[b_hi - 1]) {
+        a_hi -= 1;
+        b_hi -= 1;
+        suffix += 1;
+    }
+
+    if a_lo == a_hi {
+        ops.extend((b_lo..b_hi).map(|n| Edit::Insert(base + n)));
+    } else if b_lo == b_hi {
+        ops.extend((a_lo..a_hi).map(|n| Edit::Delete(base + n)));
+    } else {
+        let (x, y) = middle_snake(a, (a_lo, a_hi), b, (b_lo, b_hi), eq, vf, vb);
+        conquer(ops, a, (a_lo, x), b, (b_lo, y), base, eq, vf, vb);
+        conquer(ops, a, (x, a_hi), b, (y, b_hi), base, eq, vf, vb);
+    }
+
+    ops.extend((a_hi..a_hi + suffix).map(|n| Edit::Equal(base + n)));
+}
+
+/// Find a point on an optimal path through the edit graph of two non-empty
+/// ranges, which splits the problem roughly in half.
+fn middle_snake<T, F>(
+    a: &[T],
+    (a_lo, a_hi): (usize, usize),
+    b: &[T],
+    (b_lo, b_hi): (usize, usize),
+    eq: &F,
+    vf: &mut V,
+    vb: &mut V,
+) -> (usize, usize)
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let n = a_hi - a_lo;
+    let m = b_hi - b_lo;
+    let delta = n as isize - m as isize;
+    let odd = delta & 1 == 1;
+    let d_max = ((n + m + 1) / 2) as isize;
+
+    vf[1] = 0;
+    vb[1] = 0;
+
+    for d in 0..=d_max {
+        // Forward search, where `vf[k]` is how far along `a` the furthest
+        // reaching path on diagonal `k` is.
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x = if k == -d || (k != d && vf[k - 1] < vf[k + 1]) {
+                vf[k + 1]
+            } else {
+                vf[k - 1] + 1
+            };
+
+            let mut y = (x as isize - k) as usize;
+            let start = (x, y);
+
+            while x < n && y < m && eq(&a[a_lo + x], &b[b_lo + y]) {
+                x += 1;
+                y += 1;
+            }
+
+            vf[k] = x;
+
+            if odd && (k - delta).abs() < d && vf[k] + vb[delta - k] >= n {
+                return (a_lo + start.0, b_lo + start.1);
+            }
+        }
+
+        // Backward search, where `vb[k]` is how far back from the end of `a`
+        // the furthest reaching path on diagonal `k` is.
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x = if k == -d || (k != d && vb[k - 1] < vb[k + 1]) {
+                vb[k + 1]
+            } else {
+                vb[k - 1] + 1
+            };
+
+            let mut y = (x as isize - k) as usize;
+
+            while x < n && y < m && eq(&a[a_hi - x - 1], &b[b_hi - y - 1]) {
+                x += 1;
+                y += 1;
+            }
+
+            vb[k] = x;
+
+            if !odd && (k - delta).abs() <= d && vb[k] + vf[delta - k] >= n {
+                return (a_hi - x, b_hi - y);
+            }
+        }
+    }
+
+    unreachable!("there is always a path through the edit graph")
+}
@@ -0,0 +1,304 @@
+use core::future::poll_fn;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::io;
+
+use alloc::vec::Vec;
+
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::fmt;
+use crate::lang::Lang;
+use crate::Tokens;
+
+/// Helper struct to format a token stream to an underlying writer implementing
+/// [AsyncWrite][tokio::io::AsyncWrite].
+///
+/// When formatting through [write_file][Self::write_file], the output is
+/// written to the underlying writer in chunks as they fill up, so only about
+/// one [chunk][Self::with_chunk_size] is buffered as long as the writer keeps
+/// up. Whatever the writer isn't ready to accept while formatting is written
+/// once formatting has finished.
+///
+/// Output formatted through [as_formatter][Self::as_formatter] is collected in
+/// the buffer until the writer is [flushed][Self::flush].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+///
+/// # tokio::runtime::Builder::new_current_thread().build()?.block_on(async {
+/// let map = rust::import("std::collections", "HashMap");
+///
+/// let tokens: rust::Tokens = quote! {
+///     let mut m = $map::new();
+///     m.insert(1u32, 2u32);
+/// };
+///
+/// // Vec<u8> implements tokio::io::AsyncWrite
+/// let mut w = fmt::AsyncIoWriter::new(Vec::<u8>::new());
+///
+/// let fmt = fmt::Config::from_lang::<Rust>();
+/// w.write_file(&tokens, &fmt, &rust::Config::default()).await?;
+///
+/// let vector = w.into_inner();
+/// let string = std::str::from_utf8(&vector)?;
+///
+/// assert_eq!(
+///     "use std::collections::HashMap;\n\nlet mut m = HashMap::new();\nm.insert(1u32, 2u32);\n",
+///     string
+/// );
+/// # Ok::<_, anyhow::Error>(())
+/// # })?;
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+pub struct AsyncIoWriter<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    writer: W,
+    buf: Vec<u8>,
+    chunk_size: usize,
+    mappings: Vec<fmt::Mapping>,
+}
+
+impl<W> AsyncIoWriter<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Construct a new writer from the underlying writer.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            buf: Vec::new(),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            mappings: Vec::new(),
+        }
+    }
+
+    /// Set the size of the chunks written to the underlying writer while
+    /// formatting with [write_file][Self::write_file].
+    ///
+    /// Defaults to 8 KiB.
+    pub fn with_chunk_size(self, chunk_size: usize) -> Self {
+        Self { chunk_size, ..self }
+    }
+
+    /// The positions of tokens with an origin which have been written.
+    ///
+    /// See [Tokens::append_with_origin][crate::Tokens::append_with_origin].
+    pub fn mappings(&self) -> &[fmt::Mapping] {
+        &self.mappings
+    }
+
+    /// Convert into a formatter, which formats into the buffer of the writer.
+    ///
+    /// Anything formatted is written to the underlying writer once the writer
+    /// is [flushed][Self::flush].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build()?.block_on(async {
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    /// let mut w = fmt::AsyncIoWriter::new(Vec::<u8>::new());
+    ///
+    /// for n in 0..3 {
+    ///     let tokens: rust::Tokens = quote!(const N$n: u32 = $n;);
+    ///     tokens.format(&mut w.as_formatter(&fmt), &rust::Config::default(), &rust::Format::default())?;
+    ///     // Write each part as it's been formatted.
+    ///     w.flush().await?;
+    /// }
+    ///
+    /// assert_eq!(b"const N0: u32 = 0;const N1: u32 = 1;const N2: u32 = 2;", &w.into_inner()[..]);
+    /// # Ok::<_, anyhow::Error>(())
+    /// # })?;
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn as_formatter<'a>(&'a mut self, config: &'a fmt::Config) -> fmt::Formatter<'a> {
+        fmt::Formatter::new(self, config)
+    }
+
+    /// Format the token stream as a file like
+    /// [Tokens::format_file][crate::Tokens::format_file], and write it to the
+    /// underlying writer chunk by chunk as it's being formatted.
+    ///
+    /// A formatting error is reported as an [io::Error] of the
+    /// [Other][io::ErrorKind::Other] kind.
+    pub async fn write_file<L>(
+        &mut self,
+        tokens: &Tokens<L>,
+        fmt: &fmt::Config,
+        config: &L::Config,
+    ) -> io::Result<()>
+    where
+        L: Lang,
+    {
+        let mut pending = Some(tokens);
+
+        // NB: Formatting is synchronous, so it runs to completion in the first
+        // poll, writing chunks as they fill up using the context of the task.
+        poll_fn(|cx| {
+            let Some(tokens) = pending.take() else {
+                return Poll::Ready(Ok(()));
+            };
+
+            let mut w = ChunkWriter {
+                writer: &mut self.writer,
+                cx,
+                buf: &mut self.buf,
+                chunk_size: self.chunk_size,
+                mappings: &mut self.mappings,
+                error: None,
+            };
+
+            let result = tokens.format_file(&mut fmt::Formatter::new(&mut w, fmt), config);
+
+            if let Some(e) = w.error {
+                return Poll::Ready(Err(e));
+            }
+
+            Poll::Ready(result.map_err(|e| io::Error::new(io::ErrorKind::Other, e)))
+        })
+        .await?;
+
+        self.flush().await
+    }
+
+    /// Write everything which has been formatted to the underlying writer, and
+    /// flush it.
+    pub async fn flush(&mut self) -> io::Result<()> {
+        self.writer.write_all(&self.buf).await?;
+        self.buf.clear();
+        self.writer.flush().await
+    }
+
+    /// Convert into the inner writer.
+    ///
+    /// Anything which has been formatted but not [flushed][Self::flush] is
+    /// discarded.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W> core::fmt::Write for AsyncIoWriter<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    #[inline(always)]
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        self.buf
+            .extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.buf.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+impl<W> fmt::Write for AsyncIoWriter<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    #[inline(always)]
+    fn write_line(&mut self, config: &fmt::Config) -> fmt::Result {
+        self.buf.extend_from_slice(config.newline.as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_mapping(&mut self, mapping: fmt::Mapping) {
+        self.mappings.push(mapping);
+    }
+}
+
+const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Writer used while formatting a file, which writes to the underlying writer
+/// each time a chunk has filled up.
+struct ChunkWriter<'a, 'cx, W> {
+    writer: &'a mut W,
+    cx: &'a mut Context<'cx>,
+    buf: &'a mut Vec<u8>,
+    chunk_size: usize,
+    mappings: &'a mut Vec<fmt::Mapping>,
+    error: Option<io::Error>,
+}
+
+impl<W> ChunkWriter<'_, '_, W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Write as much of the buffer as the underlying writer accepts once a
+    /// chunk has filled up.
+    fn write_chunks(&mut self) -> core::fmt::Result {
+        if self.buf.len() < self.chunk_size {
+            return Ok(());
+        }
+
+        let mut written = 0;
+
+        while written < self.buf.len() {
+            match Pin::new(&mut *self.writer).poll_write(self.cx, &self.buf[written..]) {
+                Poll::Ready(Ok(0)) => {
+                    self.error = Some(io::ErrorKind::WriteZero.into());
+                    return Err(core::fmt::Error);
+                }
+                Poll::Ready(Ok(n)) => {
+                    written += n;
+                }
+                Poll::Ready(Err(e)) => {
+                    self.error = Some(e);
+                    return Err(core::fmt::Error);
+                }
+                // Keep buffering, what remains is written once formatting is
+                // done.
+                Poll::Pending => break,
+            }
+        }
+
+        self.buf.drain(..written);
+        Ok(())
+    }
+}
+
+impl<W> core::fmt::Write for ChunkWriter<'_, '_, W>
+where
+    W: AsyncWrite + Unpin,
+{
+    #[inline]
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        self.write_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.buf.extend_from_slice(s.as_bytes());
+        self.write_chunks()
+    }
+}
+
+impl<W> fmt::Write for ChunkWriter<'_, '_, W>
+where
+    W: AsyncWrite + Unpin,
+{
+    #[inline]
+    fn write_line(&mut self, config: &fmt::Config) -> fmt::Result {
+        self.buf.extend_from_slice(config.newline.as_bytes());
+        self.write_chunks()
+    }
+
+    #[inline]
+    fn write_mapping(&mut self, mapping: fmt::Mapping) {
+        self.mappings.push(mapping);
+    }
+}
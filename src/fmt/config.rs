@@ -1,4 +1,7 @@
+use alloc::vec::Vec;
+
 use crate::lang::Lang;
+use crate::tokens::ItemStr;
 
 /// Indentation configuration.
 ///
@@ -30,7 +33,8 @@ use crate::lang::Lang;
 /// };
 /// # Ok::<_, genco::fmt::Error>(())
 /// ```
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub enum Indentation {
     /// Each indentation is the given number of spaces.
     Space(usize),
@@ -45,6 +49,18 @@ pub struct Config {
     pub(super) indentation: Indentation,
     /// What to use as a newline.
     pub(super) newline: &'static str,
+    /// Error on suspicious dynamic literals outside of quoted strings.
+    pub(super) strict: bool,
+    /// Error on unindenting below zero.
+    pub(super) strict_indentation: bool,
+    /// Write a byte order mark at the start of the file.
+    pub(super) byte_order_mark: bool,
+    /// Lines to write at the start of the file.
+    pub(super) prefix_lines: Vec<ItemStr>,
+    /// The width after which groups are broken up over multiple lines.
+    pub(super) max_width: usize,
+    /// Levels of indentation to use for lines continued by a soft break.
+    pub(super) indent_continuation: i16,
 }
 
 impl Config {
@@ -57,6 +73,12 @@ impl Config {
         Self {
             indentation: L::default_indentation(),
             newline: "\n",
+            strict: false,
+            strict_indentation: false,
+            byte_order_mark: false,
+            prefix_lines: Vec::new(),
+            max_width: 100,
+            indent_continuation: clamp_levels(L::default_indent_continuation()),
         }
     }
 
@@ -72,4 +94,249 @@ impl Config {
     pub fn with_newline(self, newline: &'static str) -> Self {
         Self { newline, ..self }
     }
+
+    /// Enable strict mode, which causes formatting to fail if a dynamic string
+    /// containing newline or quote characters is found outside of a quoted
+    /// string.
+    ///
+    /// This helps catch bugs where untrusted input is accidentally interpolated
+    /// as raw code, rather than through [quoted()][crate::tokens::quoted].
+    ///
+    /// Only strings which were constructed at runtime, such as a [String], are
+    /// checked. Static strings, like the ones written as part of a template,
+    /// are left alone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let input = String::from("\"); drop_database(); \"");
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>().with_strict(true);
+    /// let config = rust::Config::default();
+    ///
+    /// let tokens: rust::Tokens = quote!(let name = $(quoted(&input)););
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq! {
+    ///     vec!["let name = \"\\\"); drop_database(); \\\"\";"],
+    ///     w.into_vec(),
+    /// };
+    ///
+    /// let tokens: rust::Tokens = quote!(let name = $(&input););
+    /// let mut w = fmt::VecWriter::new();
+    /// assert!(tokens.format_file(&mut w.as_formatter(&fmt), &config).is_err());
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_strict(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+
+    /// Cause formatting to fail if the token stream is unindented below zero.
+    ///
+    /// By default this is treated as no indentation, and reported as a
+    /// [Warning::UnindentedBelowZero]. Either way it usually indicates a bug
+    /// in code which builds token streams by hand. When formatting fails, the
+    /// index of the offending item can be found through
+    /// [Formatter::warnings].
+    ///
+    /// [Warning::UnindentedBelowZero]: crate::fmt::Warning::UnindentedBelowZero
+    /// [Formatter::warnings]: crate::fmt::Formatter::warnings
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt::{self, Warning};
+    ///
+    /// let mut tokens = rust::Tokens::new();
+    /// tokens.append("a");
+    /// tokens.unindent();
+    /// tokens.append("b");
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>().with_strict_indentation(true);
+    /// let mut w = fmt::VecWriter::new();
+    /// let mut out = w.as_formatter(&fmt);
+    ///
+    /// assert!(tokens.format_file(&mut out, &rust::Config::default()).is_err());
+    /// assert_eq!(&[Warning::UnindentedBelowZero { item: 1 }], out.warnings());
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_strict_indentation(self, strict_indentation: bool) -> Self {
+        Self {
+            strict_indentation,
+            ..self
+        }
+    }
+
+    /// Write a UTF-8 byte order mark (`U+FEFF`) at the very start of the file.
+    ///
+    /// This only applies when formatting files, like with
+    /// [Tokens::format_file][crate::Tokens::format_file].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let tokens: csharp::Tokens = quote!(class Foo {});
+    ///
+    /// let mut w = fmt::FmtWriter::new(String::new());
+    ///
+    /// let fmt = fmt::Config::from_lang::<Csharp>().with_byte_order_mark(true);
+    /// let config = csharp::Config::default();
+    ///
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!("\u{feff}class Foo {}\n", w.into_inner());
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_byte_order_mark(self, byte_order_mark: bool) -> Self {
+        Self {
+            byte_order_mark,
+            ..self
+        }
+    }
+
+    /// Add a line which is written at the start of the file, before anything
+    /// the language emits like imports or package declarations.
+    ///
+    /// This can be called multiple times to add multiple lines, and is useful
+    /// for things like shebangs. It only applies when formatting files, like
+    /// with [Tokens::format_file][crate::Tokens::format_file].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let sys = python::import_module("sys");
+    ///
+    /// let tokens: python::Tokens = quote!(print($sys.argv));
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    ///
+    /// let fmt = fmt::Config::from_lang::<Python>()
+    ///     .with_prefix_line("#!/usr/bin/env python3");
+    /// let config = python::Config::default();
+    ///
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "#!/usr/bin/env python3",
+    ///         "import sys",
+    ///         "",
+    ///         "print(sys.argv)",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_prefix_line<S>(mut self, line: S) -> Self
+    where
+        S: Into<ItemStr>,
+    {
+        self.prefix_lines.push(line.into());
+        self
+    }
+
+    /// Set the maximum width of a line, after which groups like method chains
+    /// are broken up over multiple lines. Defaults to `100`.
+    ///
+    /// Note that this only affects groups, lines are otherwise never broken
+    /// up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let calls = [quote!(setName("genco")), quote!(setVersion(17)), quote!(build())];
+    ///
+    /// let tokens: java::Tokens = quote! {
+    ///     var project = $(java::chain(quote!(Project.builder()), calls));
+    /// };
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    ///
+    /// let fmt = fmt::Config::from_lang::<Java>().with_max_width(40);
+    /// let config = java::Config::default();
+    ///
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "var project = Project.builder()",
+    ///         "    .setName(\"genco\")",
+    ///         "    .setVersion(17)",
+    ///         "    .build();",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_max_width(self, max_width: usize) -> Self {
+        Self { max_width, ..self }
+    }
+
+    /// Set the number of indentation levels used for lines which are
+    /// continued because a group was broken up, as opposed to lines in an
+    /// indented block. Defaults to what the language specifies through
+    /// [Lang::default_indent_continuation], which is one level unless
+    /// overridden.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let calls = [quote!(setName("genco")), quote!(build())];
+    ///
+    /// let tokens: java::Tokens = quote! {
+    ///     void build() {
+    ///         var project = $(java::chain(quote!(Project.builder()), calls));
+    ///     }
+    /// };
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    ///
+    /// let fmt = fmt::Config::from_lang::<Java>()
+    ///     .with_max_width(40)
+    ///     .with_indent_continuation(2);
+    /// let config = java::Config::default();
+    ///
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "void build() {",
+    ///         "    var project = Project.builder()",
+    ///         "            .setName(\"genco\")",
+    ///         "            .build();",
+    ///         "}",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_indent_continuation(self, levels: usize) -> Self {
+        Self {
+            indent_continuation: clamp_levels(levels),
+            ..self
+        }
+    }
+}
+
+/// Convert a number of indentation levels into the representation used by
+/// the formatter.
+fn clamp_levels(levels: usize) -> i16 {
+    usize::min(levels, i16::MAX as usize) as i16
 }
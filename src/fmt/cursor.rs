@@ -68,6 +68,7 @@ where
     L: Lang,
 {
     items: &'a [Item<L>],
+    index: usize,
 }
 
 impl<'a, L> Cursor<'a, L>
@@ -75,16 +76,52 @@ where
     L: Lang,
 {
     pub(super) fn new(items: &'a [Item<L>]) -> Self {
-        Self { items }
+        Self::with_index(items, 0)
+    }
+
+    /// Construct a cursor over items which start at the given index in the
+    /// token stream being formatted.
+    pub(super) fn with_index(items: &'a [Item<L>], index: usize) -> Self {
+        Self { items, index }
+    }
+
+    /// The index of the next item in the token stream being formatted.
+    pub(super) fn index(&self) -> usize {
+        self.index
     }
 
     /// Get the next item.
     pub(super) fn next(&mut self) -> Option<&Item<L>> {
         let (first, rest) = self.items.split_first()?;
         self.items = rest;
+        self.index += 1;
         Some(first)
     }
 
+    /// Get the items of the group which was just opened, up until its
+    /// matching [Item::CloseGroup].
+    pub(super) fn group(&self) -> &'a [Item<L>] {
+        let mut depth = 0usize;
+
+        for (n, item) in self.items.iter().enumerate() {
+            match item {
+                Item::OpenGroup | Item::OpenLimitedGroup(..) | Item::OpenNestedGroup => depth += 1,
+                Item::CloseGroup if depth == 0 => return &self.items[..n],
+                Item::CloseGroup => depth -= 1,
+                _ => {}
+            }
+        }
+
+        self.items
+    }
+
+    /// Skip over the given number of items.
+    pub(super) fn skip(&mut self, n: usize) {
+        let n = n.min(self.items.len());
+        self.items = &self.items[n..];
+        self.index += n;
+    }
+
     #[inline]
     pub(super) fn peek<P>(&self) -> bool
     where
@@ -1,7 +1,9 @@
+use alloc::vec::Vec;
+
 use crate::fmt;
 
 /// Helper struct to format a token stream to an underlying writer implementing
-/// [fmt::Write][std::fmt::Write].
+/// [fmt::Write][core::fmt::Write].
 ///
 /// # Examples
 ///
@@ -16,7 +18,7 @@ use crate::fmt;
 ///     m.insert(1u32, 2u32);
 /// };
 ///
-/// // Note: String implements std::fmt::Write
+/// // Note: String implements core::fmt::Write
 /// let mut w = fmt::FmtWriter::new(String::new());
 ///
 /// let fmt = fmt::Config::from_lang::<Rust>();
@@ -32,11 +34,48 @@ use crate::fmt;
 /// assert_eq!("let mut m = HashMap::new();\nm.insert(1u32, 2u32);", string);
 /// # Ok::<_, genco::fmt::Error>(())
 /// ```
+///
+/// Since this only depends on [core::fmt::Write], it can be used to write into
+/// custom sinks in `no_std` environments, like a fixed-size buffer:
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+///
+/// struct Buffer {
+///     data: [u8; 64],
+///     len: usize,
+/// }
+///
+/// impl core::fmt::Write for Buffer {
+///     fn write_str(&mut self, s: &str) -> core::fmt::Result {
+///         let end = self.len + s.len();
+///         let target = self.data.get_mut(self.len..end).ok_or(core::fmt::Error)?;
+///         target.copy_from_slice(s.as_bytes());
+///         self.len = end;
+///         Ok(())
+///     }
+/// }
+///
+/// let tokens: rust::Tokens = quote!(fn main() {});
+///
+/// let mut w = fmt::FmtWriter::new(Buffer { data: [0; 64], len: 0 });
+///
+/// let fmt = fmt::Config::from_lang::<Rust>();
+/// let config = rust::Config::default();
+///
+/// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+///
+/// let buffer = w.into_inner();
+/// assert_eq!(b"fn main() {}\n", &buffer.data[..buffer.len]);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
 pub struct FmtWriter<W>
 where
     W: core::fmt::Write,
 {
     writer: W,
+    mappings: Vec<fmt::Mapping>,
 }
 
 impl<W> FmtWriter<W>
@@ -45,7 +84,22 @@ where
 {
     /// Construct a new line writer from the underlying writer.
     pub fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            mappings: Vec::new(),
+        }
+    }
+
+    /// The positions of tokens with an origin which have been written.
+    ///
+    /// See [Tokens::append_with_origin][crate::Tokens::append_with_origin].
+    pub fn mappings(&self) -> &[fmt::Mapping] {
+        &self.mappings
+    }
+
+    /// Take the mappings which have been written.
+    pub(crate) fn take_mappings(&mut self) -> Vec<fmt::Mapping> {
+        core::mem::take(&mut self.mappings)
     }
 
     /// Convert into a formatter.
@@ -82,4 +136,9 @@ where
     fn write_line(&mut self, config: &fmt::Config) -> fmt::Result {
         self.writer.write_str(config.newline)
     }
+
+    #[inline]
+    fn write_mapping(&mut self, mapping: fmt::Mapping) {
+        self.mappings.push(mapping);
+    }
 }
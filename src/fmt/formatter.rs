@@ -1,12 +1,16 @@
+use core::any::Any;
 use core::mem;
 
+use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::vec::Vec;
 
 use crate::fmt;
 use crate::fmt::config::{Config, Indentation};
 use crate::fmt::cursor;
+use crate::fmt::Mapping;
 use crate::lang::Lang;
-use crate::tokens::Item;
+use crate::tokens::{Item, ItemStr};
 
 /// Buffer used as indentation source.
 static SPACES: &str = "                                                                                                    ";
@@ -14,11 +18,15 @@ static SPACES: &str = "
 static TABS: &str =
     "\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t";
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy)]
 enum Whitespace {
     Initial,
+    #[default]
     None,
     Push,
+    /// Like [Whitespace::Push], but ignoring any spaces which follow, like
+    /// after a line comment.
+    Comment,
     Line,
 }
 
@@ -30,19 +38,13 @@ impl Whitespace {
     fn into_indent(self) -> Option<usize> {
         match self {
             Self::Initial => Some(0),
-            Self::Push => Some(1),
+            Self::Push | Self::Comment => Some(1),
             Self::Line => Some(2),
             Self::None => None,
         }
     }
 }
 
-impl Default for Whitespace {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 /// Token stream formatter. Keeps track of everything we need to know in order
 /// to enforce genco's indentation and whitespace rules.
 pub struct Formatter<'a> {
@@ -60,6 +62,24 @@ pub struct Formatter<'a> {
     spaces: usize,
     /// Current indentation level.
     indent: i16,
+    /// The column of the last character written.
+    column: usize,
+    /// The number of tabs used to indent the current line, which are counted
+    /// as four columns each in `column`.
+    tabs: usize,
+    /// The number of lines written.
+    line_number: usize,
+    /// Origins which are recorded once the next non-whitespace is written.
+    origins: Vec<ItemStr>,
+    /// Format all groups on a single line, used when measuring them.
+    flat: bool,
+    /// Indentation styles which override the configured one, where the last
+    /// one is in effect.
+    indentations: Vec<Indentation>,
+    /// Warnings reported by the language while formatting.
+    warnings: Vec<fmt::Warning>,
+    /// Language items imported by the file, if they are being recorded.
+    imports: Option<Vec<Box<dyn Any>>>,
 }
 
 impl<'a> Formatter<'a> {
@@ -70,6 +90,14 @@ impl<'a> Formatter<'a> {
             line: Whitespace::Initial,
             spaces: 0usize,
             indent: 0i16,
+            column: 0,
+            tabs: 0,
+            line_number: 0,
+            origins: Vec::new(),
+            flat: false,
+            indentations: Vec::new(),
+            warnings: Vec::new(),
+            imports: None,
             config,
         }
     }
@@ -88,6 +116,81 @@ impl<'a> Formatter<'a> {
         self.format_cursor(&mut cursor, config, format, false)
     }
 
+    /// Report a decision made while formatting a file which the author of the
+    /// generator might want to act on.
+    ///
+    /// Warnings are collected by [Tokens::format_file_with_report].
+    ///
+    /// [Tokens::format_file_with_report]: crate::Tokens::format_file_with_report
+    pub fn warn(&mut self, warning: fmt::Warning) {
+        self.warnings.push(warning);
+    }
+
+    /// Warnings which have been reported so far.
+    ///
+    /// This can be used to find out what went wrong when formatting fails,
+    /// like which item was unindented below zero with
+    /// [Config::with_strict_indentation].
+    pub fn warnings(&self) -> &[fmt::Warning] {
+        &self.warnings
+    }
+
+    /// Take all warnings which have been reported so far.
+    pub(crate) fn take_warnings(&mut self) -> Vec<fmt::Warning> {
+        mem::take(&mut self.warnings)
+    }
+
+    /// Record that the file which is being formatted imports the given
+    /// language item.
+    ///
+    /// Languages call this for every item which ends up being imported, after
+    /// conflicts have been resolved, so that it's included in the imports
+    /// reported by [Tokens::format_file_with_report].
+    ///
+    /// [Tokens::format_file_with_report]: crate::Tokens::format_file_with_report
+    pub fn record_import<L>(&mut self, item: &L::Item)
+    where
+        L: Lang,
+    {
+        if let Some(imports) = &mut self.imports {
+            imports.push(Box::new(item.clone()));
+        }
+    }
+
+    /// Start recording imports through [Formatter::record_import].
+    pub(crate) fn record_imports(&mut self) {
+        self.imports = Some(Vec::new());
+    }
+
+    /// Stop recording imports and take the ones which have been recorded.
+    pub(crate) fn take_imports<L>(&mut self) -> Vec<L::Item>
+    where
+        L: Lang,
+    {
+        let imports = self.imports.take().unwrap_or_default();
+
+        imports
+            .into_iter()
+            .filter_map(|item| item.downcast::<L::Item>().ok())
+            .map(|item| *item)
+            .collect()
+    }
+
+    /// Write the configured prologue, at the start of a file.
+    pub(crate) fn write_prologue(&mut self) -> fmt::Result {
+        if self.config.byte_order_mark {
+            self.write.write_char('\u{feff}')?;
+        }
+
+        for line in &self.config.prefix_lines {
+            self.write.write_str(line)?;
+            self.write.write_line(self.config)?;
+            self.line_number += 1;
+        }
+
+        Ok(())
+    }
+
     /// Forcibly write a line ending, at the end of a file.
     ///
     /// This will also reset any whitespace we have pending.
@@ -98,28 +201,101 @@ impl<'a> Formatter<'a> {
         Ok(())
     }
 
+    /// Write the given string pinned to the first column of its own line,
+    /// regardless of the current indentation.
+    ///
+    /// This is intended for language items such as preprocessor directives,
+    /// which must start at the beginning of a line. Anything following it is
+    /// moved to the next line.
+    pub fn write_pinned(&mut self, s: &str) -> fmt::Result {
+        if matches!(self.line, Whitespace::None) && self.column > 0 {
+            self.line = Whitespace::Push;
+        }
+
+        self.spaces = 0;
+
+        if let Some(lines) = mem::take(&mut self.line).into_indent() {
+            for _ in 0..lines {
+                self.write_line()?;
+            }
+        }
+
+        self.flush_origins();
+        self.write.write_str(s)?;
+        self.column += s.chars().count();
+        self.line = Whitespace::Push;
+        Ok(())
+    }
+
     /// Write the given string.
     fn write_str(&mut self, s: &str) -> fmt::Result {
         if !s.is_empty() {
             self.flush_whitespace()?;
+            self.flush_origins();
             self.write.write_str(s)?;
+
+            match s.rfind('\n') {
+                Some(n) => {
+                    self.column = s[n + 1..].chars().count();
+                    self.tabs = 0;
+                    self.line_number += s.matches('\n').count();
+                }
+                None => self.column += s.chars().count(),
+            }
         }
 
         Ok(())
     }
 
-    fn push(&mut self) {
+    /// Write a line ending.
+    fn write_line(&mut self) -> fmt::Result {
+        self.write.write_line(self.config)?;
+        self.column = 0;
+        self.tabs = 0;
+        self.line_number += 1;
+        Ok(())
+    }
+
+    /// The current column in characters, as opposed to `column` which counts
+    /// tabs as four columns.
+    fn output_column(&self) -> usize {
+        self.column - self.tabs * 3
+    }
+
+    /// Record the current position for all origins which are pending.
+    fn flush_origins(&mut self) {
+        if self.origins.is_empty() {
+            return;
+        }
+
+        let column = self.output_column();
+
+        for origin in self.origins.drain(..) {
+            self.write
+                .write_mapping(Mapping::new(self.line_number, column, origin));
+        }
+    }
+
+    /// Push a new line, unless one is already pending or nothing has been
+    /// written yet.
+    ///
+    /// Like all whitespace, this is only realized once something else is
+    /// written.
+    pub fn push(&mut self) {
         self.line = match self.line {
             Whitespace::Initial => return,
-            Whitespace::Line => return,
+            Whitespace::Line | Whitespace::Comment => return,
             _ => Whitespace::Push,
         };
 
         self.spaces = 0;
     }
 
-    /// Push a new line.
-    fn line(&mut self) {
+    /// Push an empty line, unless nothing has been written yet.
+    ///
+    /// Like all whitespace, this is only realized once something else is
+    /// written.
+    pub fn line(&mut self) {
         self.line = match self.line {
             Whitespace::Initial => return,
             _ => Whitespace::Line,
@@ -129,10 +305,85 @@ impl<'a> Formatter<'a> {
     }
 
     /// Push a space.
-    fn space(&mut self) {
+    ///
+    /// Like all whitespace, this is only realized once something else is
+    /// written.
+    pub fn space(&mut self) {
+        if let Whitespace::Comment = self.line {
+            return;
+        }
+
         self.spaces += 1;
     }
 
+    /// Push a single space, unless one is already pending or nothing has
+    /// been written on the current line yet.
+    ///
+    /// Like all whitespace, this is only realized once something else is
+    /// written.
+    pub fn soft_space(&mut self) {
+        if !matches!(self.line, Whitespace::None) || self.column == 0 {
+            return;
+        }
+
+        self.spaces = usize::max(self.spaces, 1);
+    }
+
+    /// End a line comment by pushing a new line, ignoring any spaces which
+    /// follow it on the same line.
+    pub(crate) fn end_line_comment(&mut self) {
+        self.line = match self.line {
+            Whitespace::Line => return,
+            _ => Whitespace::Comment,
+        };
+
+        self.spaces = 0;
+    }
+
+    /// Indent everything written through the returned guard by one level on
+    /// a new line, and move to a new line at the previous level of
+    /// indentation once it is dropped.
+    ///
+    /// This allows custom [LangItem][crate::lang::LangItem] implementations
+    /// to produce multi-line output where indentation is always balanced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::fmt::Write as _;
+    ///
+    /// use genco::fmt;
+    ///
+    /// let config = fmt::Config::from_lang::<genco::lang::Rust>();
+    /// let mut w = fmt::FmtWriter::new(String::new());
+    ///
+    /// {
+    ///     let mut out = w.as_formatter(&config);
+    ///     out.write_str("fn main() {")?;
+    ///
+    ///     {
+    ///         let mut out = out.indent_scope();
+    ///         out.write_str("let a = 1;")?;
+    ///         out.line();
+    ///         out.write_str("let b =")?;
+    ///         out.space();
+    ///         out.write_str("2;")?;
+    ///     }
+    ///
+    ///     out.write_str("}")?;
+    /// }
+    ///
+    /// assert_eq!(
+    ///     "fn main() {\n    let a = 1;\n\n    let b = 2;\n}",
+    ///     w.into_inner()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn indent_scope(&mut self) -> IndentGuard<'_, 'a> {
+        self.indentation(1);
+        IndentGuard { formatter: self }
+    }
+
     /// Increase indentation level.
     fn indentation(&mut self, n: i16) {
         self.push();
@@ -151,13 +402,23 @@ impl<'a> Formatter<'a> {
         L: Lang,
     {
         use crate::lang::LangItem as _;
+        use core::fmt::Write as _;
 
         let mut buf = String::new();
+        let mut mappings = Vec::new();
         let mut stack = smallvec::SmallVec::<[Frame; 4]>::new();
+        // Groups which didn't fit on a single line, and whether they have
+        // indented the current line.
+        let mut groups = smallvec::SmallVec::<[bool; 4]>::new();
+
+        // Configurations produced by the overrides currently in effect.
+        let mut configs = Vec::<L::Config>::new();
 
         stack.push(Frame::default());
 
         while let (Some(item), Some(head)) = (cursor.next(), stack.last_mut()) {
+            let config = configs.last().unwrap_or(config);
+
             let Frame {
                 in_quote,
                 has_eval,
@@ -165,15 +426,140 @@ impl<'a> Formatter<'a> {
             } = head;
 
             match item {
-                Item::Register(..) => (),
+                Item::Register(..) | Item::Declare(..) | Item::Anchor(..) | Item::ImportsHere => (),
+                Item::Origin(origin) => {
+                    self.origins.push(origin.clone());
+                }
                 Item::Indentation(0) => (),
                 Item::Literal(literal) => {
                     if *in_quote {
-                        L::write_quoted(self, literal)?;
+                        if *has_eval {
+                            L::write_quoted_eval(self, literal)?;
+                        } else {
+                            L::write_quoted(self, literal)?;
+                        }
                     } else {
+                        if self.config.strict && is_suspicious(literal) {
+                            return Err(core::fmt::Error);
+                        }
+
                         self.write_str(literal)?;
                     }
                 }
+                Item::Display(value) => {
+                    // NB: The value is written into the same buffer which is
+                    // used for groups, so that no string is allocated for it.
+                    write!(buf, "{value}")?;
+
+                    if *in_quote {
+                        if *has_eval {
+                            L::write_quoted_eval(self, &buf)?;
+                        } else {
+                            L::write_quoted(self, &buf)?;
+                        }
+                    } else {
+                        if self.config.strict && is_suspicious_str(&buf) {
+                            return Err(core::fmt::Error);
+                        }
+
+                        self.write_str(&buf)?;
+                    }
+
+                    buf.clear();
+                }
+                Item::Ident(kind, ident) => {
+                    let ident = match L::ident_case(config, *kind) {
+                        Some(case) => ItemStr::from(case.convert(ident)),
+                        None => ident.clone(),
+                    };
+
+                    let ident = L::sanitize_ident(ident);
+
+                    if *in_quote {
+                        L::write_quoted(self, &ident)?;
+                    } else {
+                        self.write_str(&ident)?;
+                    }
+                }
+                Item::OpenIndentation(indentation) => {
+                    self.indentations.push(*indentation);
+                }
+                Item::CloseIndentation => {
+                    self.indentations.pop();
+                }
+                Item::OpenConfig(config_override) => {
+                    let config = config_override.apply(config);
+                    configs.push(config);
+                }
+                Item::CloseConfig => {
+                    configs.pop();
+                }
+                Item::OpenGroup
+                | Item::OpenLimitedGroup(..)
+                | Item::OpenNestedGroup
+                | Item::CloseGroup
+                | Item::SoftBreak
+                | Item::SoftIndentation(..)
+                | Item::TrailingSeparator(..)
+                    if self.flat => {}
+                // NB: Any group which is being formatted here is broken.
+                Item::OpenNestedGroup if !groups.is_empty() => {
+                    groups.push(false);
+                }
+                Item::OpenGroup | Item::OpenLimitedGroup(..) | Item::OpenNestedGroup => {
+                    let limit = match item {
+                        Item::OpenLimitedGroup(width) => *width,
+                        _ => usize::MAX,
+                    };
+
+                    let group = cursor.group();
+                    let group_cursor = cursor::Cursor::with_index(group, cursor.index());
+
+                    if self.group_fits(
+                        group_cursor,
+                        &mut buf,
+                        &mut mappings,
+                        config,
+                        format,
+                        limit,
+                    )? {
+                        self.write_group(&buf, &mut mappings)?;
+                        cursor.skip(group.len() + 1);
+                    } else {
+                        groups.push(false);
+                    }
+
+                    buf.clear();
+                    mappings.clear();
+                }
+                Item::SoftBreak => {
+                    if let Some(indented) = groups.last_mut() {
+                        if !mem::replace(indented, true) {
+                            self.indent += self.config.indent_continuation;
+                        }
+
+                        self.push();
+                    }
+                }
+                Item::CloseGroup => {
+                    if groups.pop() == Some(true) {
+                        self.indent -= self.config.indent_continuation;
+                    }
+                }
+                Item::SoftIndentation(n) => {
+                    self.indentation(*n);
+
+                    // NB: The line break takes the place of any spaces which
+                    // follow it, since they only separate tokens on one line.
+                    if let Whitespace::Push = self.line {
+                        self.line = Whitespace::Comment;
+                    }
+                }
+                Item::TrailingSeparator(separator) => {
+                    if L::style(config).trailing_commas() {
+                        self.write_str(separator)?;
+                    }
+                }
                 Item::OpenQuote(e) if !*in_quote => {
                     *has_eval = *e;
                     *in_quote = true;
@@ -185,7 +571,13 @@ impl<'a> Formatter<'a> {
                 // Evaluating quotes are not supported.
                 Item::OpenQuote(false) if *in_quote => {
                     self.quoted_quote(cursor, &mut buf, config, format)?;
-                    L::write_quoted(self, &buf)?;
+
+                    if *has_eval {
+                        L::write_quoted_eval(self, &buf)?;
+                    } else {
+                        L::write_quoted(self, &buf)?;
+                    }
+
                     buf.clear();
                 }
                 Item::CloseQuote if end_on_close_quote => {
@@ -198,6 +590,9 @@ impl<'a> Formatter<'a> {
                 Item::Lang(_, lang) => {
                     lang.format(self, config, format)?;
                 }
+                Item::Comment(kind, text) => {
+                    L::write_comment(self, *kind, text)?;
+                }
                 // whitespace below
                 Item::Push => {
                     self.push();
@@ -208,8 +603,21 @@ impl<'a> Formatter<'a> {
                 Item::Space => {
                     self.space();
                 }
+                Item::SoftSpace => {
+                    self.soft_space();
+                }
                 Item::Indentation(n) => {
                     self.indentation(*n);
+
+                    if *n < 0 && self.indent < 0 {
+                        let item = cursor.index() - 1;
+                        trace!(item, indent = self.indent, "unindented below zero");
+                        self.warn(fmt::Warning::UnindentedBelowZero { item });
+
+                        if self.config.strict_indentation {
+                            return Err(core::fmt::Error);
+                        }
+                    }
                 }
                 Item::OpenEval if *in_quote => {
                     if cursor.peek::<cursor::Literal>() && cursor.peek1::<cursor::CloseEval>() {
@@ -275,17 +683,21 @@ impl<'a> Formatter<'a> {
         let mut spaces = mem::take(&mut self.spaces);
 
         if let Some(lines) = mem::take(&mut self.line).into_indent() {
+            trace!(lines, indent = self.indent, spaces, "flush whitespace");
+
             for _ in 0..lines {
-                self.write.write_line(self.config)?;
+                self.write_line()?;
             }
 
             let level = i16::max(self.indent, 0) as usize;
 
-            match self.config.indentation {
+            match self.indentation_style() {
                 Indentation::Space(n) => {
                     spaces += level * n;
                 }
                 Indentation::Tab => {
+                    self.column += self.indent_width(level);
+                    self.tabs = level;
                     let mut tabs = level;
 
                     while tabs > 0 {
@@ -300,11 +712,130 @@ impl<'a> Formatter<'a> {
         while spaces > 0 {
             let len = usize::min(spaces, SPACES.len());
             self.write.write_str(&SPACES[0..len])?;
+            self.column += len;
             spaces -= len;
         }
 
         Ok(())
     }
+
+    /// The indentation style currently in effect.
+    fn indentation_style(&self) -> Indentation {
+        self.indentations
+            .last()
+            .copied()
+            .unwrap_or(self.config.indentation)
+    }
+
+    /// The width of the given indentation level, where tabs are counted as
+    /// four columns.
+    fn indent_width(&self, level: usize) -> usize {
+        match self.indentation_style() {
+            Indentation::Space(n) => level * n,
+            Indentation::Tab => level * 4,
+        }
+    }
+
+    /// Write a group which has been formatted flat, with the origins recorded
+    /// in it.
+    fn write_group(&mut self, buf: &str, mappings: &mut Vec<Mapping>) -> fmt::Result {
+        if buf.is_empty() {
+            return Ok(());
+        }
+
+        self.flush_whitespace()?;
+        self.flush_origins();
+
+        let column = self.output_column();
+
+        for mapping in mappings.drain(..) {
+            self.write
+                .write_mapping(mapping.offset(self.line_number, column));
+        }
+
+        self.write_str(buf)
+    }
+
+    /// Test if the given group fits on the current line when formatted flat,
+    /// and is no wider than `limit`.
+    ///
+    /// If it does, the formatted group is written to `buf` and the positions
+    /// of any origins in it relative to the start of `buf` to `mappings`.
+    fn group_fits<L>(
+        &mut self,
+        mut group: cursor::Cursor<'_, L>,
+        buf: &mut String,
+        mappings: &mut Vec<Mapping>,
+        config: &L::Config,
+        format: &L::Format,
+        limit: usize,
+    ) -> fmt::Result<bool>
+    where
+        L: Lang,
+    {
+        use crate::fmt::FmtWriter;
+
+        let mut w = FmtWriter::new(&mut *buf);
+        let out = &mut Formatter::new(&mut w, self.config);
+        out.flat = true;
+        out.indent = self.indent;
+        // The group continues the current line.
+        out.line = Whitespace::None;
+        out.format_cursor(&mut group, config, format, false)?;
+        // Origins at the end of the group are recorded at the end of it.
+        out.flush_origins();
+        let warnings = out.take_warnings();
+        mappings.extend(w.take_mappings());
+
+        if buf.contains('\n') {
+            return Ok(false);
+        }
+
+        let start = match self.line {
+            Whitespace::None => self.column,
+            _ => self.indent_width(i16::max(self.indent, 0) as usize),
+        };
+
+        let len = buf.chars().count();
+        let width = start + self.spaces + len;
+
+        if width > self.config.max_width || len > limit {
+            return Ok(false);
+        }
+
+        // NB: The group is written as it was formatted here, so keep the
+        // warnings reported while doing so.
+        self.warnings.extend(warnings);
+        Ok(true)
+    }
+}
+
+/// A guard which keeps a [Formatter] indented by one level until dropped.
+///
+/// This is created through [Formatter::indent_scope] and dereferences to the
+/// underlying formatter.
+pub struct IndentGuard<'f, 'a> {
+    formatter: &'f mut Formatter<'a>,
+}
+
+impl<'a> core::ops::Deref for IndentGuard<'_, 'a> {
+    type Target = Formatter<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        self.formatter
+    }
+}
+
+impl core::ops::DerefMut for IndentGuard<'_, '_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.formatter
+    }
+}
+
+impl Drop for IndentGuard<'_, '_> {
+    fn drop(&mut self) {
+        self.formatter.indentation(-1);
+    }
 }
 
 impl core::fmt::Write for Formatter<'_> {
@@ -327,3 +858,18 @@ impl core::fmt::Debug for Formatter<'_> {
             .finish()
     }
 }
+
+/// Test if the given literal is a dynamic string containing characters which
+/// are likely to break out of the surrounding code.
+fn is_suspicious(literal: &ItemStr) -> bool {
+    match literal {
+        ItemStr::Box(s) => is_suspicious_str(s),
+        ItemStr::Static(..) => false,
+    }
+}
+
+/// Test if a string which was constructed at runtime contains characters
+/// which might break out of the surrounding code.
+fn is_suspicious_str(s: &str) -> bool {
+    s.contains(['\n', '\r', '"', '\''])
+}
@@ -1,5 +1,7 @@
 use std::io;
 
+use alloc::vec::Vec;
+
 use crate::fmt;
 
 /// Helper struct to format a token stream to an underlying writer implementing
@@ -39,6 +41,7 @@ where
     W: io::Write,
 {
     writer: W,
+    mappings: Vec<fmt::Mapping>,
 }
 
 impl<W> IoWriter<W>
@@ -47,7 +50,17 @@ where
 {
     /// Construct a new line writer from the underlying writer.
     pub fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            mappings: Vec::new(),
+        }
+    }
+
+    /// The positions of tokens with an origin which have been written.
+    ///
+    /// See [Tokens::append_with_origin][crate::Tokens::append_with_origin].
+    pub fn mappings(&self) -> &[fmt::Mapping] {
+        &self.mappings
     }
 
     /// Convert into a formatter.
@@ -90,4 +103,9 @@ where
             .write_all(config.newline.as_bytes())
             .map_err(|_| core::fmt::Error)
     }
+
+    #[inline]
+    fn write_mapping(&mut self, mapping: fmt::Mapping) {
+        self.mappings.push(mapping);
+    }
 }
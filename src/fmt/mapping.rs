@@ -0,0 +1,51 @@
+use crate::tokens::ItemStr;
+
+/// The position in the formatted output where tokens with an origin start.
+///
+/// Mappings are recorded for tokens added through
+/// [Tokens::append_with_origin], and can be retrieved from the writer after
+/// formatting, like through [VecWriter::mappings].
+///
+/// [Tokens::append_with_origin]: crate::Tokens::append_with_origin
+/// [VecWriter::mappings]: crate::fmt::VecWriter::mappings
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mapping {
+    line: usize,
+    column: usize,
+    origin: ItemStr,
+}
+
+impl Mapping {
+    pub(crate) fn new(line: usize, column: usize, origin: ItemStr) -> Self {
+        Self {
+            line,
+            column,
+            origin,
+        }
+    }
+
+    /// Offset a mapping recorded relative to some output which is written at
+    /// the given position.
+    pub(crate) fn offset(self, line: usize, column: usize) -> Self {
+        Self {
+            line: self.line + line,
+            column: self.column + column,
+            ..self
+        }
+    }
+
+    /// The zero-based line in the output.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The zero-based column in the output, counted in characters.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The origin marker the tokens were added with.
+    pub fn origin(&self) -> &str {
+        &self.origin
+    }
+}
@@ -7,9 +7,11 @@
 //!
 //! * [fmt::VecWriter][VecWriter] - To write result into a vector.
 //! * [fmt::FmtWriter][FmtWriter] - To write the result into something
-//!   implementing [fmt::Write][std::fmt::Write].
+//!   implementing [fmt::Write][core::fmt::Write].
 //! * [fmt::IoWriter][IoWriter]- To write the result into something implementing
 //!   [io::Write][std::io::Write].
+//! * `fmt::AsyncIoWriter` - To write the result into something implementing
+//!   tokio's `AsyncWrite`, if the `tokio` feature is enabled.
 //!
 //! # Examples
 //!
@@ -46,27 +48,91 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Determinism
+//!
+//! Formatting is deterministic. The same token stream always produces
+//! byte-identical output, regardless of the platform or how many times it's
+//! formatted. Language backends only use ordered collections when collecting
+//! things like imports, so their order in the output never depends on hashing.
+//!
+//! The [is_deterministic] helper can be used in tests to check that a
+//! generator upholds the same guarantee.
 
+#[cfg(feature = "tokio")]
+mod async_io_writer;
 mod config;
 mod cursor;
 mod fmt_writer;
 mod formatter;
 #[cfg(feature = "std")]
 mod io_writer;
+mod mapping;
+mod report;
 mod vec_writer;
+mod warning;
 
+#[cfg(feature = "tokio")]
+pub use self::async_io_writer::AsyncIoWriter;
 pub use self::config::{Config, Indentation};
 pub use self::fmt_writer::FmtWriter;
-pub use self::formatter::Formatter;
+pub use self::formatter::{Formatter, IndentGuard};
 #[cfg(feature = "std")]
 pub use self::io_writer::IoWriter;
+pub use self::mapping::Mapping;
+pub use self::report::FormatReport;
 pub use self::vec_writer::VecWriter;
+pub use self::warning::{Conflict, Warning};
+
+use crate::lang::Lang;
+use crate::Tokens;
 
 /// Result type for the `fmt` module.
 pub type Result<T = ()> = core::result::Result<T, core::fmt::Error>;
 /// Error for the `fmt` module.
 pub type Error = core::fmt::Error;
 
+/// Build a token stream twice using the given function and test that both
+/// format into identical files.
+///
+/// This is useful in tests to catch generators which rely on something
+/// nondeterministic, like iterating over a `HashMap`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+///
+/// let deterministic = fmt::is_deterministic(|| -> java::Tokens {
+///     let list = java::import("java.util", "List");
+///     let map = java::import("java.util", "Map");
+///     quote!($list $map)
+/// })?;
+///
+/// assert!(deterministic);
+///
+/// let mut n = 0;
+///
+/// let deterministic = fmt::is_deterministic(|| -> java::Tokens {
+///     n += 1;
+///     quote!($n)
+/// })?;
+///
+/// assert!(!deterministic);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn is_deterministic<L, F>(mut build: F) -> Result<bool>
+where
+    L: Lang,
+    L::Config: Default,
+    F: FnMut() -> Tokens<L>,
+{
+    let a = build().to_file_string()?;
+    let b = build().to_file_string()?;
+    Ok(a == b)
+}
+
 /// Trait that defines a line writer.
 pub(crate) trait Write: core::fmt::Write {
     /// Implement for writing a line.
@@ -77,4 +143,8 @@ pub(crate) trait Write: core::fmt::Write {
     fn write_trailing_line(&mut self, config: &Config) -> Result {
         self.write_line(config)
     }
+
+    /// Implement for recording the position of tokens with an origin.
+    #[inline]
+    fn write_mapping(&mut self, _: Mapping) {}
 }
@@ -0,0 +1,108 @@
+use alloc::vec::Vec;
+
+use crate::fmt::Warning;
+use crate::lang::Lang;
+
+/// A report of what was written when formatting a file.
+///
+/// This is returned by [Tokens::format_file_with_report].
+///
+/// [Tokens::format_file_with_report]: crate::Tokens::format_file_with_report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatReport<L>
+where
+    L: Lang,
+{
+    imports: Vec<L::Item>,
+    warnings: Vec<Warning>,
+}
+
+impl<L> FormatReport<L>
+where
+    L: Lang,
+{
+    pub(crate) fn new(imports: Vec<L::Item>, warnings: Vec<Warning>) -> Self {
+        Self { imports, warnings }
+    }
+
+    /// The distinct language items which were imported by the file, in
+    /// sorted order.
+    ///
+    /// These are the items which the language wrote imports for, including
+    /// the ones covered by a wildcard import. Items which are used through
+    /// their qualified name since they conflict with another import, or which
+    /// don't need to be imported, like ones from the same package as the
+    /// file, are not included. Neither are items of languages which don't
+    /// write imports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let a = java::import("java.util", "List");
+    /// let b = java::import("java.awt", "List");
+    /// let c = java::import("com.example", "Foo");
+    ///
+    /// let tokens: java::Tokens = quote!($(&a) $(&b) $(&c));
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// let fmt = fmt::Config::from_lang::<Java>();
+    /// let config = java::Config::default().with_package("com.example");
+    /// let report = tokens.format_file_with_report(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "package com.example;",
+    ///         "",
+    ///         "import java.awt.List;",
+    ///         "",
+    ///         "java.util.List List Foo",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// assert_eq!(&[b], report.imports());
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn imports(&self) -> &[L::Item] {
+        &self.imports
+    }
+
+    /// Warnings reported by the language while formatting the file, like
+    /// imports which had to be qualified since their names conflict.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt::{self, Conflict, Warning};
+    ///
+    /// let a = java::import("java.util", "List");
+    /// let b = java::import("java.awt", "List");
+    ///
+    /// let tokens: java::Tokens = quote!($a $b);
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// let fmt = fmt::Config::from_lang::<Java>();
+    /// let report = tokens.format_file_with_report(&mut w.as_formatter(&fmt), &java::Config::default())?;
+    ///
+    /// assert_eq!(
+    ///     &[Warning::Qualified {
+    ///         module: String::from("java.util"),
+    ///         name: String::from("List"),
+    ///         conflict: Conflict::Module(String::from("java.awt")),
+    ///     }],
+    ///     report.warnings()
+    /// );
+    ///
+    /// assert_eq!(
+    ///     "`List` in `java.util` is not imported since the name is imported from `java.awt`, using its qualified name",
+    ///     report.warnings()[0].to_string()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+}
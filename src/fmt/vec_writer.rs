@@ -18,7 +18,6 @@ use crate::fmt;
 ///     m.insert(1u32, 2u32);
 /// };
 ///
-/// // Note: String implements std::fmt::Write
 /// let mut w = fmt::VecWriter::new();
 ///
 /// let fmt = fmt::Config::from_lang::<Rust>();
@@ -43,7 +42,11 @@ use crate::fmt;
 #[derive(Default)]
 pub struct VecWriter {
     line_buffer: String,
+    /// Lines past `len` are left over from an earlier run, and are reused
+    /// instead of allocating new ones.
     target: Vec<String>,
+    len: usize,
+    mappings: Vec<fmt::Mapping>,
 }
 
 impl VecWriter {
@@ -52,16 +55,81 @@ impl VecWriter {
         Self::default()
     }
 
+    /// Construct a new writer which reuses the allocations of the lines in
+    /// the given vector.
+    ///
+    /// Formatting into a vector from a previous run avoids allocating a new
+    /// string for each line, as long as the lines it contains are large
+    /// enough.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     let a = 1;
+    ///     let b = 2;
+    /// };
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    /// let lines = vec![String::from("old line"); 4];
+    ///
+    /// let mut w = fmt::VecWriter::from_vec(lines);
+    /// tokens.format(&mut w.as_formatter(&fmt), &rust::Config::default(), &rust::Format::default())?;
+    ///
+    /// assert_eq!(vec!["let a = 1;", "let b = 2;"], w.into_vec());
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn from_vec(mut lines: Vec<String>) -> Self {
+        let mut line_buffer = lines.pop().unwrap_or_default();
+        line_buffer.clear();
+
+        Self {
+            line_buffer,
+            target: lines,
+            len: 0,
+            mappings: Vec::new(),
+        }
+    }
+
     /// Convert into a formatter.
     pub fn as_formatter<'a>(&'a mut self, config: &'a fmt::Config) -> fmt::Formatter<'a> {
         fmt::Formatter::new(self, config)
     }
 
+    /// The positions of tokens with an origin which have been written.
+    ///
+    /// See [Tokens::append_with_origin][crate::Tokens::append_with_origin].
+    pub fn mappings(&self) -> &[fmt::Mapping] {
+        &self.mappings
+    }
+
     /// Convert into a vector.
     pub fn into_vec(mut self) -> Vec<String> {
-        self.target.push(self.line_buffer);
+        self.flush_line();
+        self.target.truncate(self.len);
         self.target
     }
+
+    /// Move the current line into the target vector.
+    #[inline(always)]
+    fn flush_line(&mut self) {
+        match self.target.get_mut(self.len) {
+            Some(line) => {
+                // Write the next line straight into the reused string.
+                core::mem::swap(line, &mut self.line_buffer);
+                self.line_buffer.clear();
+            }
+            None => {
+                self.target.push(self.line_buffer.clone());
+                self.line_buffer.clear();
+            }
+        }
+
+        self.len += 1;
+    }
 }
 
 impl core::fmt::Write for VecWriter {
@@ -79,8 +147,7 @@ impl core::fmt::Write for VecWriter {
 impl fmt::Write for VecWriter {
     #[inline(always)]
     fn write_line(&mut self, _: &fmt::Config) -> fmt::Result {
-        self.target.push(self.line_buffer.clone());
-        self.line_buffer.clear();
+        self.flush_line();
         Ok(())
     }
 
@@ -88,4 +155,9 @@ impl fmt::Write for VecWriter {
     fn write_trailing_line(&mut self, _: &fmt::Config) -> fmt::Result {
         Ok(())
     }
+
+    #[inline]
+    fn write_mapping(&mut self, mapping: fmt::Mapping) {
+        self.mappings.push(mapping);
+    }
 }
@@ -0,0 +1,95 @@
+use core::fmt;
+
+use alloc::string::String;
+
+/// A decision made by a language backend while formatting a file, which the
+/// author of a generator might want to act on.
+///
+/// Warnings are collected in a [FormatReport][super::FormatReport].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Warning {
+    /// An item isn't imported since its name conflicts with another import,
+    /// so it's referred to through its qualified name instead.
+    Qualified {
+        /// The module the item belongs to.
+        module: String,
+        /// The name of the item.
+        name: String,
+        /// What the name conflicts with.
+        conflict: Conflict,
+    },
+    /// An item is imported under an alias since its name conflicts with
+    /// another import.
+    Aliased {
+        /// The module the item belongs to.
+        module: String,
+        /// The name of the item.
+        name: String,
+        /// The alias the item is imported as.
+        alias: String,
+        /// What the name conflicts with.
+        conflict: Conflict,
+    },
+    /// The token stream was unindented below zero, which is treated as no
+    /// indentation.
+    ///
+    /// See [Config::with_strict_indentation][super::Config::with_strict_indentation].
+    UnindentedBelowZero {
+        /// The index of the item in the token stream being formatted which
+        /// unindented it.
+        item: usize,
+    },
+}
+
+/// What the name of an import conflicts with.
+///
+/// See [Warning].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Conflict {
+    /// The name is imported from the given module.
+    Module(String),
+    /// The name is used as the alias of another import.
+    Alias,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Qualified {
+                module,
+                name,
+                conflict,
+            } => {
+                write!(
+                    f,
+                    "`{name}` in `{module}` is not imported since {conflict}, using its qualified name"
+                )
+            }
+            Self::Aliased {
+                module,
+                name,
+                alias,
+                conflict,
+            } => {
+                write!(
+                    f,
+                    "`{name}` in `{module}` is imported as `{alias}` since {conflict}"
+                )
+            }
+            Self::UnindentedBelowZero { item } => {
+                write!(f, "item {item} unindents below zero")
+            }
+        }
+    }
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Module(module) => write!(f, "the name is imported from `{module}`"),
+            Self::Alias => write!(f, "the name is used as an alias"),
+        }
+    }
+}
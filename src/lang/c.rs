@@ -30,7 +30,7 @@ impl_lang! {
         ) -> fmt::Result {
             let mut header = Tokens::new();
 
-            Self::imports(&mut header, tokens);
+            Self::imports(&mut header, out, tokens);
             let format = Format::default();
             header.format(out, config, &format)?;
             tokens.format(out, config, &format)?;
@@ -51,6 +51,7 @@ impl_lang! {
 ///
 /// Created using the [include()] function.
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub struct Import {
     /// Path to included file.
     path: ItemStr,
@@ -65,14 +66,16 @@ pub struct Import {
 pub struct Format {}
 
 /// Config data for C.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Config {}
 
 impl C {
-    fn imports(out: &mut Tokens, tokens: &Tokens) {
+    fn imports(out: &mut Tokens, formatter: &mut fmt::Formatter<'_>, tokens: &Tokens) {
         let mut includes = BTreeSet::new();
 
         for include in tokens.walk_imports() {
+            trace!(path = %include.path, system = include.system, "collecting include");
+            formatter.record_import::<Self>(include);
             includes.insert((&include.path, include.system));
         }
 
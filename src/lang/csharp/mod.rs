@@ -16,19 +16,43 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # String Interpolation in C#
+//!
+//! Strings which evaluate expressions are written as interpolated strings
+//! (`$"..."`), where literal braces are escaped by doubling them. Strings
+//! without evaluations are written as regular strings.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: csharp::Tokens = quote!($[str](Hello $(name) $[const]("{braces}")));
+//! assert_eq!("$\"Hello {name} {{braces}}\"", toks.to_string()?);
+//!
+//! let toks: csharp::Tokens = quote!($[str](Hello $[const]("{braces}")));
+//! assert_eq!("\"Hello {braces}\"", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
 
 mod block_comment;
 mod comment;
 
+use core::cmp::Ordering;
 use core::fmt::Write as _;
 
 use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use relative_path::RelativePath;
 
 use crate as genco;
 use crate::fmt;
 use crate::quote_in;
-use crate::tokens::ItemStr;
+use crate::tokens::{Case, IdentKind, ItemStr, Naming};
 
 pub use self::block_comment::BlockComment;
 pub use self::comment::Comment;
@@ -36,6 +60,8 @@ pub use self::comment::Comment;
 /// Tokens container specialization for C#.
 pub type Tokens = crate::Tokens<Csharp>;
 
+impl crate::lang::LangSupportsEval for Csharp {}
+
 impl_lang! {
     /// Language specialization for C#.
     pub Csharp {
@@ -43,11 +69,61 @@ impl_lang! {
         type Format = Format;
         type Item = Import;
 
+        fn ident_case(config: &Self::Config, kind: IdentKind) -> Option<Case> {
+            config.naming.get(kind)
+        }
+
+        fn sanitize_ident(ident: ItemStr) -> ItemStr {
+            // Keywords can be used as identifiers if prefixed with `@`.
+            if KEYWORDS.contains(&&*ident) {
+                return format!("@{ident}").into();
+            }
+
+            ident
+        }
+
+        fn open_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            has_eval: bool,
+        ) -> fmt::Result {
+            if has_eval {
+                out.write_str("$\"")?;
+            } else {
+                out.write_char('"')?;
+            }
+
+            Ok(())
+        }
+
+        fn start_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_char('{')?;
+            Ok(())
+        }
+
+        fn end_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_char('}')?;
+            Ok(())
+        }
+
         fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
             // From: https://csharpindepth.com/articles/Strings
             super::c_family_write_quoted(out, input)
         }
 
+        fn write_quoted_eval(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            super::c_family_write_quoted_braces(out, input)
+        }
+
         fn format_file(
             tokens: &Tokens,
             out: &mut fmt::Formatter<'_>,
@@ -57,19 +133,31 @@ impl_lang! {
 
             let mut format = Format::default();
 
-            Self::imports(&mut file, tokens, config, &mut format.imported_names);
+            Self::imports(&mut file, out, tokens, config, &mut format);
+
+            match &config.namespace {
+                Some(namespace) if config.file_scoped_namespace => {
+                    quote_in! { file =>
+                        namespace $namespace;
 
-            if let Some(namespace) = &config.namespace {
-                quote_in! { file =>
-                    namespace $namespace {
                         $tokens
                     }
+
+                    file.format(out, config, &format)?;
                 }
+                Some(namespace) => {
+                    quote_in! { file =>
+                        namespace $namespace {
+                            $tokens
+                        }
+                    }
 
-                file.format(out, config, &format)?;
-            } else {
-                file.format(out, config, &format)?;
-                tokens.format(out, config, &format)?;
+                    file.format(out, config, &format)?;
+                }
+                None => {
+                    file.format(out, config, &format)?;
+                    tokens.format(out, config, &format)?;
+                }
             }
 
             Ok(())
@@ -78,6 +166,22 @@ impl_lang! {
 
     Import {
         fn format(&self, out: &mut fmt::Formatter<'_>, config: &Config, format: &Format) -> fmt::Result {
+            if let Some(alias) = &self.alias {
+                if let Some((namespace, name)) = format.aliases.get(&**alias) {
+                    if *namespace == *self.namespace && *name == self.nested_name() {
+                        out.write_str(alias)?;
+                        return write_arguments(out, config, format, &self.arguments);
+                    }
+                }
+
+                // The alias conflicts with another alias, so the name has to
+                // be qualified.
+                out.write_str(&self.namespace)?;
+                out.write_str(SEP)?;
+                out.write_str(&self.nested_name())?;
+                return write_arguments(out, config, format, &self.arguments);
+            }
+
             {
                 let qualified = self.qualified || is_qualified(config, format, &self.namespace, &self.name);
 
@@ -89,9 +193,46 @@ impl_lang! {
 
             out.write_str(&self.name)?;
 
-            return Ok(());
+            for segment in &self.path {
+                out.write_str(SEP)?;
+                out.write_str(segment)?;
+            }
+
+            return write_arguments(out, config, format, &self.arguments);
+
+            fn write_arguments(
+                out: &mut fmt::Formatter<'_>,
+                config: &Config,
+                format: &Format,
+                arguments: &[CsharpType],
+            ) -> fmt::Result {
+                if arguments.is_empty() {
+                    return Ok(());
+                }
+
+                out.write_char('<')?;
+
+                for (n, argument) in arguments.iter().enumerate() {
+                    if n > 0 {
+                        out.write_str(", ")?;
+                    }
+
+                    match argument {
+                        CsharpType::Import(import) => import.format(out, config, format)?,
+                        CsharpType::Primitive(name) => out.write_str(name)?,
+                    }
+                }
+
+                out.write_char('>')?;
+                Ok(())
+            }
 
             fn is_qualified(config: &Config, format: &Format, namespace: &str, name: &str) -> bool {
+                // Name is shadowed by an alias.
+                if format.aliases.contains_key(name) {
+                    return true;
+                }
+
                 // Name is in current namespace. No need to qualify.
                 if let Some(config) = &config.namespace {
                     if &**config == namespace {
@@ -109,12 +250,99 @@ impl_lang! {
                 false
             }
         }
+
+        fn dependencies(&self) -> &[Import] {
+            &self.dependencies
+        }
     }
 }
 
 /// Separator between types and modules in C#.
 const SEP: &str = ".";
 
+/// Reserved keywords in C#.
+///
+/// See: <https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/keywords/>
+const KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
+
 /// State using during formatting of C# language items.
 #[derive(Debug, Default)]
 pub struct Format {
@@ -123,15 +351,28 @@ pub struct Format {
     ///
     /// A missing name means that it has to be used in a qualified manner.
     imported_names: BTreeMap<String, String>,
+    /// Aliases which have been declared, and the namespace and name they
+    /// refer to.
+    aliases: BTreeMap<String, (String, String)>,
 }
 
 /// Config data for Csharp formatting.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Config {
     /// namespace to use.
     namespace: Option<ItemStr>,
+    /// Write the namespace as a file-scoped namespace declaration.
+    file_scoped_namespace: bool,
+    /// Namespaces which are imported with `global using` directives.
+    global_usings: BTreeSet<ItemStr>,
+    /// Order using directives without regard to case.
+    case_insensitive_usings: bool,
+    /// Naming conventions to apply to identifiers.
+    naming: Naming,
 }
 
+impl_naming!(Config);
+
 impl Config {
     /// Set the namespace name to build.
     pub fn with_namespace<N>(self, namespace: N) -> Self
@@ -140,14 +381,178 @@ impl Config {
     {
         Self {
             namespace: Some(namespace.into()),
+            ..self
+        }
+    }
+
+    /// Write the namespace as a file-scoped namespace declaration (C# 10), like
+    /// `namespace Foo.Bar;`, instead of a block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let list = csharp::import("System.Collections.Generic", "List");
+    ///
+    /// let toks: csharp::Tokens = quote! {
+    ///     public class Users {
+    ///         $list<string> names;
+    ///     }
+    /// };
+    ///
+    /// let config = csharp::Config::default()
+    ///     .with_namespace("Acme.Models")
+    ///     .with_file_scoped_namespace(true);
+    ///
+    /// let fmt = fmt::Config::from_lang::<Csharp>();
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "using System.Collections.Generic;",
+    ///         "",
+    ///         "namespace Acme.Models;",
+    ///         "",
+    ///         "public class Users {",
+    ///         "    List<string> names;",
+    ///         "}",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_file_scoped_namespace(self, file_scoped_namespace: bool) -> Self {
+        Self {
+            file_scoped_namespace,
+            ..self
         }
     }
+
+    /// Import the given namespace with a `global using` directive (C# 10),
+    /// which applies to every file in the project, if it's used.
+    ///
+    /// Global using directives are written in a section of their own before
+    /// any other using directives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let list = csharp::import("System.Collections.Generic", "List");
+    /// let path = csharp::import("System.IO", "Path");
+    /// let task = csharp::import("System.Threading.Tasks", "Task");
+    /// let json = csharp::import("System.Text.Json", "JsonSerializer").with_alias("Json");
+    ///
+    /// let toks: csharp::Tokens = quote! {
+    ///     $list<$path> paths;
+    ///     $task<$json> task;
+    /// };
+    ///
+    /// let config = csharp::Config::default()
+    ///     .with_global_using("System.Collections.Generic")
+    ///     .with_global_using("System.Threading.Tasks")
+    ///     .with_global_using("System.Linq");
+    ///
+    /// let fmt = fmt::Config::from_lang::<Csharp>();
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "global using System.Collections.Generic;",
+    ///         "global using System.Threading.Tasks;",
+    ///         "",
+    ///         "using System.IO;",
+    ///         "using Json = System.Text.Json.JsonSerializer;",
+    ///         "",
+    ///         "List<Path> paths;",
+    ///         "Task<Json> task;",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_global_using<N>(mut self, namespace: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        self.global_usings.insert(namespace.into());
+        self
+    }
+
+    /// Order using directives without regard to case, which is what tools
+    /// like `dotnet format` expect.
+    ///
+    /// Namespaces are compared by their lowercase characters, which doesn't
+    /// depend on locale. Namespaces which only differ by case are ordered
+    /// byte-wise. By default using directives are ordered byte-wise, so
+    /// uppercase letters come before lowercase ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let a = csharp::import("Zeta.Models", "User");
+    /// let b = csharp::import("alpha.Models", "Group");
+    ///
+    /// let toks: csharp::Tokens = quote!($a $b);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "using Zeta.Models;",
+    ///         "using alpha.Models;",
+    ///         "",
+    ///         "User Group",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    ///
+    /// let config = csharp::Config::default().with_case_insensitive_usings(true);
+    ///
+    /// let fmt = fmt::Config::from_lang::<Csharp>();
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "using alpha.Models;",
+    ///         "using Zeta.Models;",
+    ///         "",
+    ///         "User Group",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_case_insensitive_usings(self, case_insensitive_usings: bool) -> Self {
+        Self {
+            case_insensitive_usings,
+            ..self
+        }
+    }
+}
+
+impl crate::lang::LangConfig for Config {
+    fn with_namespace<N>(self, namespace: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        self.with_namespace(namespace)
+    }
 }
 
 /// The import of a C# type `using System.IO;`.
 ///
 /// Created through the [import()] function.
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub struct Import {
     /// namespace of the class.
     namespace: ItemStr,
@@ -155,9 +560,171 @@ pub struct Import {
     name: ItemStr,
     /// Use as qualified type.
     qualified: bool,
+    /// Alias to declare and use for the type.
+    alias: Option<ItemStr>,
+    /// Path to a class nested in the imported class.
+    path: Vec<ItemStr>,
+    /// Generic arguments of the type.
+    arguments: Vec<CsharpType>,
+    /// Imports among the generic arguments.
+    dependencies: Vec<Import>,
 }
 
 impl Import {
+    /// Declare and use an alias for the type with an alias directive like
+    /// `using Alias = Namespace.Name;`.
+    ///
+    /// If the alias is already used for another type, that type is used
+    /// qualified instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let project = csharp::import("PC.MyCompany", "Project").with_alias("Project");
+    /// let other = csharp::import("Other.Company", "Project").with_alias("Project");
+    /// let list = csharp::import("System.Collections.Generic", "List");
+    /// let io_path = csharp::import("System.IO", "Path").with_alias("IoPath");
+    ///
+    /// let toks: csharp::Tokens = quote! {
+    ///     $list<$project> projects;
+    ///     $other other;
+    ///     string path = $io_path.Combine("a", "b");
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "using System.Collections.Generic;",
+    ///         "using IoPath = System.IO.Path;",
+    ///         "using Project = Other.Company.Project;",
+    ///         "",
+    ///         "List<PC.MyCompany.Project> projects;",
+    ///         "Project other;",
+    ///         "string path = IoPath.Combine(\"a\", \"b\");",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_alias<A>(self, alias: A) -> Self
+    where
+        A: Into<ItemStr>,
+    {
+        Self {
+            alias: Some(alias.into()),
+            ..self
+        }
+    }
+
+    /// Refer to a class nested in the imported class, like `Outer.Inner`.
+    ///
+    /// Only the outer class is imported, and the nested class is always used
+    /// through it. This can be called multiple times to refer to classes which
+    /// are nested deeper.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let entry = csharp::import("Foo.Bar", "Outer").path("Entry");
+    /// let key = csharp::import("Foo.Bar", "Outer").path("Entry").path("Key");
+    /// let other = csharp::import("Foo.Baz", "Outer").path("Entry");
+    /// let list = csharp::import("System.Collections.Generic", "List");
+    ///
+    /// let toks: csharp::Tokens = quote! {
+    ///     $list<$entry> entries;
+    ///     $key key;
+    ///     $other<int> other;
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "using Foo.Bar;",
+    ///         "using System.Collections.Generic;",
+    ///         "",
+    ///         "List<Outer.Entry> entries;",
+    ///         "Outer.Entry.Key key;",
+    ///         "Foo.Baz.Outer.Entry<int> other;",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn path<P>(mut self, segment: P) -> Self
+    where
+        P: Into<ItemStr>,
+    {
+        self.path.push(segment.into());
+        self
+    }
+
+    /// Add generic arguments to the type, which can be other imports or
+    /// primitive types.
+    ///
+    /// Imports used as arguments are imported along with the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let dictionary = csharp::import("System.Collections.Generic", "Dictionary")
+    ///     .with_arguments(vec!["string", "int"]);
+    /// let item = csharp::import("Foo.Bar", "Item");
+    /// let list = csharp::import("System.Collections.Generic", "List");
+    ///
+    /// let lists = list.clone().with_arguments(vec![dictionary]);
+    /// let items = list.with_arguments(vec![item]);
+    ///
+    /// let toks: csharp::Tokens = quote! {
+    ///     $lists lists;
+    ///     $items items;
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "using Foo.Bar;",
+    ///         "using System.Collections.Generic;",
+    ///         "",
+    ///         "List<Dictionary<string, int>> lists;",
+    ///         "List<Item> items;",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_arguments<A>(mut self, arguments: Vec<A>) -> Self
+    where
+        A: Into<CsharpType>,
+    {
+        for argument in arguments {
+            let argument = argument.into();
+
+            if let CsharpType::Import(import) = &argument {
+                self.dependencies.push(import.clone());
+            }
+
+            self.arguments.push(argument);
+        }
+
+        self
+    }
+
+    /// The name of the imported class, including the path to any nested
+    /// class.
+    fn nested_name(&self) -> String {
+        let mut name = self.name.to_string();
+
+        for segment in &self.path {
+            name.push_str(SEP);
+            name.push_str(segment);
+        }
+
+        name
+    }
+
     /// Make this type into a qualified type that is always used with a
     /// namespace.
     pub fn qualified(self) -> Self {
@@ -171,48 +738,145 @@ impl Import {
 impl Csharp {
     fn imports(
         out: &mut Tokens,
+        formatter: &mut fmt::Formatter<'_>,
         tokens: &Tokens,
         config: &Config,
-        imported_names: &mut BTreeMap<String, String>,
+        format: &mut Format,
     ) {
-        let mut modules = BTreeSet::new();
+        let mut modules = BTreeMap::<_, Vec<_>>::new();
+        let mut aliases = BTreeMap::<_, Vec<_>>::new();
 
         for import in tokens.walk_imports() {
-            modules.insert((&*import.namespace, &*import.name));
+            match &import.alias {
+                Some(alias) => {
+                    aliases
+                        .entry((&**alias, &*import.namespace, import.nested_name()))
+                        .or_default()
+                        .push(import);
+                }
+                None => {
+                    modules
+                        .entry((&*import.namespace, &*import.name))
+                        .or_default()
+                        .push(import);
+                }
+            }
         }
 
-        if modules.is_empty() {
+        if modules.is_empty() && aliases.is_empty() {
             return;
         }
 
-        let mut imported = BTreeSet::new();
+        for ((alias, namespace, name), imports) in aliases {
+            if format.aliases.contains_key(alias) {
+                trace!(alias, namespace, name, "skipping alias, already declared");
+                continue;
+            }
+
+            for import in imports {
+                formatter.record_import::<Self>(import);
+            }
+
+            format
+                .aliases
+                .insert(alias.to_string(), (namespace.to_string(), name));
+        }
+
+        let imported_names = &mut format.imported_names;
 
-        for (namespace, name) in modules {
+        let mut usings = BTreeSet::new();
+
+        for ((namespace, name), imports) in modules {
             if Some(namespace) == config.namespace.as_deref() {
+                trace!(namespace, name, "skipping import, same namespace as file");
+                continue;
+            }
+
+            if format.aliases.contains_key(name) {
+                trace!(namespace, name, "skipping import, name is an alias");
+
+                formatter.warn(fmt::Warning::Qualified {
+                    module: namespace.to_string(),
+                    name: name.to_string(),
+                    conflict: fmt::Conflict::Alias,
+                });
+
                 continue;
             }
 
             match imported_names.get(name) {
                 // already imported...
-                Some(existing) if existing == namespace => continue,
+                Some(existing) if existing == namespace => {
+                    for import in imports {
+                        formatter.record_import::<Self>(import);
+                    }
+
+                    continue;
+                }
                 // already imported, as something else...
-                Some(_) => continue,
+                Some(existing) => {
+                    trace!(namespace, name, "skipping import, name already imported");
+
+                    formatter.warn(fmt::Warning::Qualified {
+                        module: namespace.to_string(),
+                        name: name.to_string(),
+                        conflict: fmt::Conflict::Module(existing.clone()),
+                    });
+
+                    continue;
+                }
                 _ => {}
             }
 
-            if !imported.contains(namespace) {
-                quote_in!(*out => using $namespace;);
-                out.push();
-                imported.insert(namespace);
+            for import in imports {
+                formatter.record_import::<Self>(import);
             }
 
+            usings.insert(namespace);
             imported_names.insert(name.to_string(), namespace.to_string());
         }
 
+        let (mut global, mut usings): (Vec<_>, Vec<_>) = usings
+            .into_iter()
+            .partition(|namespace| config.global_usings.iter().any(|g| &**g == *namespace));
+
+        if config.case_insensitive_usings {
+            global.sort_by(|a, b| cmp_case_insensitive(a, b));
+            usings.sort_by(|a, b| cmp_case_insensitive(a, b));
+        }
+
+        for namespace in &global {
+            quote_in!(*out => global using $(*namespace););
+            out.push();
+        }
+
+        // NB: Global using directives have to come before all other using
+        // directives.
+        if !global.is_empty() {
+            out.line();
+        }
+
+        for namespace in usings {
+            quote_in!(*out => using $namespace;);
+            out.push();
+        }
+
+        for (alias, (namespace, name)) in &format.aliases {
+            quote_in!(*out => using $alias = $namespace.$name;);
+            out.push();
+        }
+
         out.line();
     }
 }
 
+/// Compare two namespaces by their lowercase characters, falling back to
+/// byte-wise ordering so that the order is total.
+fn cmp_case_insensitive(a: &str, b: &str) -> Ordering {
+    let lower = |s: &str| s.chars().flat_map(char::to_lowercase).collect::<Vec<_>>();
+    lower(a).cmp(&lower(b)).then_with(|| a.cmp(b))
+}
+
 /// The import of a C# type `using System.IO;`.
 ///
 /// # Examples
@@ -251,6 +915,44 @@ where
         namespace: namespace.into(),
         name: name.into(),
         qualified: false,
+        alias: None,
+        path: Vec::new(),
+        arguments: Vec::new(),
+        dependencies: Vec::new(),
+    }
+}
+
+/// A type used as a generic argument through [Import::with_arguments].
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+pub enum CsharpType {
+    /// An imported type.
+    Import(Import),
+    /// A primitive type, like `int` or `string`.
+    Primitive(ItemStr),
+}
+
+impl From<Import> for CsharpType {
+    fn from(import: Import) -> Self {
+        Self::Import(import)
+    }
+}
+
+impl From<&Import> for CsharpType {
+    fn from(import: &Import) -> Self {
+        Self::Import(import.clone())
+    }
+}
+
+impl From<&'static str> for CsharpType {
+    fn from(name: &'static str) -> Self {
+        Self::Primitive(ItemStr::Static(name))
+    }
+}
+
+impl From<ItemStr> for CsharpType {
+    fn from(name: ItemStr) -> Self {
+        Self::Primitive(name)
     }
 }
 
@@ -313,3 +1015,67 @@ where
 {
     Comment(comment)
 }
+
+/// Derive the namespace of a source file from its path relative to the root of
+/// a project, in the same way as the default namespace of new files in a
+/// project is derived.
+///
+/// Each directory the file is in is appended to the root namespace. Dots in
+/// directory names separate namespaces, characters which can't be used in
+/// identifiers are replaced with `_`, and names which start with a digit are
+/// prefixed with `_`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// assert_eq!("Acme.App", &*csharp::namespace_from_path("Acme.App", "Program.cs"));
+/// assert_eq!("Acme.App.Models.Users", &*csharp::namespace_from_path("Acme.App", "Models/Users/User.cs"));
+/// assert_eq!("Acme.App.Data.V2._2024_Q1", &*csharp::namespace_from_path("Acme.App", "Data.V2/2024-Q1/Report.cs"));
+///
+/// let config = csharp::Config::default()
+///     .with_namespace(csharp::namespace_from_path("Acme.App", "Models/User.cs"))
+///     .with_file_scoped_namespace(true);
+/// ```
+pub fn namespace_from_path<R, P>(root: R, path: P) -> ItemStr
+where
+    R: Into<ItemStr>,
+    P: AsRef<RelativePath>,
+{
+    let mut namespace = String::from(&*root.into());
+
+    let path = path.as_ref().normalize();
+
+    let components = path
+        .parent()
+        .into_iter()
+        .flat_map(|parent| parent.components())
+        .filter_map(|c| match c {
+            relative_path::Component::Normal(name) => Some(name),
+            _ => None,
+        });
+
+    for name in components
+        .flat_map(|c| c.split('.'))
+        .filter(|n| !n.is_empty())
+    {
+        if !namespace.is_empty() {
+            namespace.push_str(SEP);
+        }
+
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            namespace.push('_');
+        }
+
+        for c in name.chars() {
+            if c.is_alphanumeric() || c == '_' {
+                namespace.push(c);
+            } else {
+                namespace.push('_');
+            }
+        }
+    }
+
+    namespace.into()
+}
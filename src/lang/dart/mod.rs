@@ -38,12 +38,13 @@ pub use self::doc_comment::DocComment;
 
 use core::fmt::Write as _;
 
-use alloc::collections::BTreeSet;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
 
 use crate as genco;
 use crate::fmt;
 use crate::quote_in;
-use crate::tokens::{quoted, ItemStr};
+use crate::tokens::{quoted, Case, IdentKind, ItemStr, Naming};
 
 const SEP: &str = ".";
 /// dart:core package.
@@ -59,7 +60,11 @@ impl_lang! {
     pub Dart {
         type Config = Config;
         type Format = Format;
-        type Item = Import;
+        type Item = Any;
+
+        fn ident_case(config: &Self::Config, kind: IdentKind) -> Option<Case> {
+            config.naming.get(kind)
+        }
 
         fn string_eval_literal(
             out: &mut fmt::Formatter<'_>,
@@ -134,10 +139,31 @@ impl_lang! {
             out: &mut fmt::Formatter<'_>,
             config: &Self::Config,
         ) -> fmt::Result {
-            let mut imports: Tokens = Tokens::new();
-            Self::imports(&mut imports, tokens, config);
+            let mut header: Tokens = Tokens::new();
+
+            if let Some(library) = &config.library {
+                quote_in!(header => library $library;);
+                header.line();
+            }
+
+            if let Some(part_of) = &config.part_of {
+                quote_in!(header => part of $(quoted(part_of)););
+                header.line();
+            }
+
+            Self::imports(&mut header, out, tokens, config);
+
+            if !config.parts.is_empty() {
+                for part in &config.parts {
+                    quote_in!(header => part $(quoted(part)););
+                    header.push();
+                }
+
+                header.line();
+            }
+
             let format = Format::default();
-            imports.format(out, config, &format)?;
+            header.format(out, config, &format)?;
             tokens.format(out, config, &format)?;
             Ok(())
         }
@@ -154,6 +180,12 @@ impl_lang! {
             Ok(())
         }
     }
+
+    Export {
+        fn format(&self, _: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            Ok(())
+        }
+    }
 }
 
 /// Format state for Dart.
@@ -161,13 +193,124 @@ impl_lang! {
 pub struct Format {}
 
 /// Config data for Dart formatting.
-#[derive(Debug, Default)]
-pub struct Config {}
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    /// Name of the library declared by the file.
+    library: Option<ItemStr>,
+    /// The library the file is a part of.
+    part_of: Option<ItemStr>,
+    /// Files which are parts of this library.
+    parts: Vec<ItemStr>,
+    /// Naming conventions to apply to identifiers.
+    naming: Naming,
+}
+
+impl_naming!(Config);
+
+impl Config {
+    /// Declare the library of the generated file, like `library foo;`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let list = dart::import("dart:collection", "LinkedList");
+    ///
+    /// let toks: dart::Tokens = quote! {
+    ///     final items = $list();
+    /// };
+    ///
+    /// let config = dart::Config::default()
+    ///     .with_library("models")
+    ///     .with_part("models.g.dart");
+    ///
+    /// let fmt = fmt::Config::from_lang::<Dart>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "library models;",
+    ///         "",
+    ///         "import \"dart:collection\";",
+    ///         "",
+    ///         "part \"models.g.dart\";",
+    ///         "",
+    ///         "final items = LinkedList();",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_library<N>(self, library: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        Self {
+            library: Some(library.into()),
+            ..self
+        }
+    }
+
+    /// Declare that the generated file is a part of another file, like
+    /// `part of "models.dart";`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let toks: dart::Tokens = quote! {
+    ///     class Generated {}
+    /// };
+    ///
+    /// let config = dart::Config::default().with_part_of("models.dart");
+    /// let fmt = fmt::Config::from_lang::<Dart>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "part of \"models.dart\";",
+    ///         "",
+    ///         "class Generated {}",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_part_of<P>(self, part_of: P) -> Self
+    where
+        P: Into<ItemStr>,
+    {
+        Self {
+            part_of: Some(part_of.into()),
+            ..self
+        }
+    }
+
+    /// Add a part to the generated file, like `part "models.g.dart";`.
+    ///
+    /// Parts are rendered after imports, in the order they were added.
+    pub fn with_part<P>(mut self, part: P) -> Self
+    where
+        P: Into<ItemStr>,
+    {
+        self.parts.push(part.into());
+        self
+    }
+}
 
 /// The import of a Dart type `import "dart:math";`.
 ///
 /// Created through the [import()] function.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub struct Import {
     /// Path to import.
     path: ItemStr,
@@ -175,6 +318,8 @@ pub struct Import {
     name: ItemStr,
     /// Alias of module.
     alias: Option<ItemStr>,
+    /// If the import is deferred.
+    deferred: bool,
 }
 
 impl Import {
@@ -185,30 +330,161 @@ impl Import {
             ..self
         }
     }
+
+    /// Import the library lazily using `deferred as`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let chart = dart::import("package:app/chart.dart", "Chart").deferred_as("chart");
+    ///
+    /// let toks = quote! {
+    ///     await chart.loadLibrary();
+    ///     $chart();
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import \"package:app/chart.dart\" deferred as chart;",
+    ///         "",
+    ///         "await chart.loadLibrary();",
+    ///         "chart.Chart();",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn deferred_as(self, alias: impl Into<ItemStr>) -> Import {
+        Self {
+            alias: Some(alias.into()),
+            deferred: true,
+            ..self
+        }
+    }
+}
+
+/// The export of a Dart library `export "src/models.dart" show A, B;`.
+///
+/// Exports don't render anything where they are used, but are collected into
+/// the preamble of the file after imports.
+///
+/// Created through the [export()] function.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+pub struct Export {
+    /// Path to export.
+    path: ItemStr,
+    /// Names to show, or empty if all names are exported.
+    show: Vec<ItemStr>,
+}
+
+#[cfg(feature = "ir")]
+impl serde::Serialize for Any {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        enum AnyRef<'a> {
+            Import(&'a Import),
+            Export(&'a Export),
+        }
+
+        match self {
+            Any::Import(import) => AnyRef::Import(import),
+            Any::Export(export) => AnyRef::Export(export),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "ir")]
+impl<'de> serde::Deserialize<'de> for Any {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        enum AnyOwned {
+            Import(Import),
+            Export(Export),
+        }
+
+        Ok(match AnyOwned::deserialize(deserializer)? {
+            AnyOwned::Import(import) => Any::Import(import),
+            AnyOwned::Export(export) => Any::Export(export),
+        })
+    }
 }
 
 impl Dart {
     /// Resolve all imports.
-    fn imports(out: &mut Tokens, input: &Tokens, _: &Config) {
+    fn imports(out: &mut Tokens, formatter: &mut fmt::Formatter<'_>, input: &Tokens, _: &Config) {
         let mut modules = BTreeSet::new();
+        // Exported paths, with `None` indicating that everything is exported.
+        let mut exports = BTreeMap::<_, Option<BTreeSet<_>>>::new();
 
-        for import in input.walk_imports() {
-            if &*import.path == DART_CORE {
-                continue;
-            }
+        for item in input.walk_imports() {
+            match item {
+                Any::Import(import) => {
+                    if &*import.path == DART_CORE {
+                        trace!(path = %import.path, "skipping import, implicitly imported");
+                        continue;
+                    }
+
+                    trace!(path = %import.path, alias = ?import.alias, "collecting import");
+                    formatter.record_import::<Self>(item);
+                    modules.insert((import.path.clone(), import.alias.clone(), import.deferred));
+                }
+                Any::Export(export) => {
+                    trace!(path = %export.path, "collecting export");
+
+                    let show = exports
+                        .entry(export.path.clone())
+                        .or_insert_with(|| Some(BTreeSet::new()));
 
-            modules.insert((import.path.clone(), import.alias.clone()));
+                    match show {
+                        Some(names) if !export.show.is_empty() => {
+                            names.extend(export.show.iter().cloned());
+                        }
+                        _ => *show = None,
+                    }
+                }
+            }
         }
 
-        if modules.is_empty() {
+        if modules.is_empty() && exports.is_empty() {
             return;
         }
 
-        for (name, alias) in modules {
-            if let Some(alias) = alias {
-                quote_in!(*out => import $(quoted(name)) as $alias;);
-            } else {
-                quote_in!(*out => import $(quoted(name)););
+        for (name, alias, deferred) in modules {
+            match alias {
+                Some(alias) if deferred => {
+                    quote_in!(*out => import $(quoted(name)) deferred as $alias;);
+                }
+                Some(alias) => {
+                    quote_in!(*out => import $(quoted(name)) as $alias;);
+                }
+                None => {
+                    quote_in!(*out => import $(quoted(name)););
+                }
+            }
+
+            out.push();
+        }
+
+        for (path, show) in exports {
+            match show {
+                Some(names) => {
+                    quote_in!(*out => export $(quoted(path)) show $(for n in names join (, ) => $n););
+                }
+                None => {
+                    quote_in!(*out => export $(quoted(path)););
+                }
             }
 
             out.push();
@@ -260,6 +536,49 @@ where
         path: path.into(),
         alias: None,
         name: name.into(),
+        deferred: false,
+    }
+}
+
+/// The export of a Dart library `export "src/models.dart" show A, B;`.
+///
+/// Exports are collected into the preamble of the file after imports. If
+/// `show` is empty, all names in the library are exported.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let a = dart::import("package:http/http.dart", "A");
+///
+/// let toks = quote! {
+///     $(dart::export("src/models.dart", ["User", "Group"]))
+///     $(dart::export("src/utils.dart", None::<&str>))
+///     final client = $a();
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"package:http/http.dart\";",
+///         "export \"src/models.dart\" show Group, User;",
+///         "export \"src/utils.dart\";",
+///         "",
+///         "final client = A();",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn export<P, S>(path: P, show: S) -> Export
+where
+    P: Into<ItemStr>,
+    S: IntoIterator,
+    S::Item: Into<ItemStr>,
+{
+    Export {
+        path: path.into(),
+        show: show.into_iter().map(Into::into).collect(),
     }
 }
 
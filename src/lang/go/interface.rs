@@ -0,0 +1,111 @@
+use alloc::vec::Vec;
+
+use crate::lang::Go;
+use crate::tokens::{self, FormatInto, ItemStr};
+
+use super::Tokens;
+
+/// An interface definition.
+///
+/// This struct is created by the [interface][super::interface()] function.
+#[derive(Debug, Clone)]
+pub struct Interface {
+    name: ItemStr,
+    doc: Vec<ItemStr>,
+    members: Vec<Method>,
+}
+
+impl Interface {
+    pub(super) fn new(name: ItemStr) -> Self {
+        Self {
+            name,
+            doc: Vec::new(),
+            members: Vec::new(),
+        }
+    }
+
+    /// Add a line of documentation to the interface.
+    pub fn with_doc<D>(mut self, doc: D) -> Self
+    where
+        D: Into<ItemStr>,
+    {
+        self.doc.push(doc.into());
+        self
+    }
+
+    /// Add a method to the interface.
+    pub fn with_method(mut self, method: Method) -> Self {
+        self.members.push(method);
+        self
+    }
+
+    /// Add an embedded interface or type constraint.
+    pub fn with_embedded<T>(self, ty: T) -> Self
+    where
+        T: FormatInto<Go>,
+    {
+        self.with_method(Method::new(ty))
+    }
+}
+
+impl FormatInto<Go> for Interface {
+    fn format_into(self, tokens: &mut Tokens) {
+        super::doc_comment(tokens, &self.doc);
+        tokens.push();
+
+        tokens.append(tokens::static_literal("type"));
+        tokens.space();
+        tokens.append(self.name);
+        tokens.space();
+
+        if self.members.is_empty() {
+            tokens.append(tokens::static_literal("interface{}"));
+            return;
+        }
+
+        tokens.append(tokens::static_literal("interface {"));
+        tokens.indent();
+
+        for member in self.members {
+            super::doc_comment(tokens, &member.doc);
+            tokens.push();
+            tokens.append(member.signature);
+        }
+
+        tokens.unindent();
+        tokens.append(tokens::static_literal("}"));
+    }
+}
+
+/// A method in an interface.
+///
+/// This struct is created by the [method][super::method()] function.
+#[derive(Debug, Clone)]
+pub struct Method {
+    signature: Tokens,
+    doc: Vec<ItemStr>,
+}
+
+impl Method {
+    pub(super) fn new<T>(signature: T) -> Self
+    where
+        T: FormatInto<Go>,
+    {
+        let mut tokens = Tokens::new();
+        tokens.append(signature);
+
+        Self {
+            signature: tokens,
+            doc: Vec::new(),
+        }
+    }
+
+    /// Add a line of documentation to the method.
+    pub fn with_doc<D>(mut self, doc: D) -> Self
+    where
+        D: Into<ItemStr>,
+    {
+        self.doc.push(doc.into());
+        self
+    }
+}
@@ -0,0 +1,372 @@
+//! Specialization for Go code generation.
+//!
+//! # Examples
+//!
+//! Basic example:
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: js::Tokens = quote! {
+//!     function foo(v) {
+//!         return v + ", World";
+//!     }
+//!
+//!     foo("Hello");
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "function foo(v) {",
+//!         "    return v + \", World\";",
+//!         "}",
+//!         "",
+//!         "foo(\"Hello\");",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! String quoting in JavaScript:
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: go::Tokens = quote!("start π 😊 \n \x7f end");
+//! assert_eq!("\"start \\u03c0 \\U0001f60a \\n \\x7f end\"", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
+
+mod interface;
+mod struct_def;
+
+pub use self::interface::{Interface, Method};
+pub use self::struct_def::{Field, Struct};
+
+use core::fmt::Write as _;
+
+use alloc::collections::BTreeSet;
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::{self, quoted, Case, FormatInto, IdentKind, ItemStr, Naming};
+
+const MODULE_SEP: &str = "/";
+const SEP: &str = ".";
+
+/// Tokens container specialization for Go.
+pub type Tokens = crate::Tokens<Go>;
+
+impl_lang! {
+    /// Language specialization for Go.
+    pub Go {
+        type Config = Config;
+        type Format = Format;
+        type Item = Import;
+
+        fn ident_case(config: &Self::Config, kind: IdentKind) -> Option<Case> {
+            config.naming.get(kind)
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // From: https://golang.org/src/strconv/quote.go
+            super::c_family_write_quoted(out, input)
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+
+            if let Some(package) = &config.package {
+                quote_in!(header => package $package);
+                header.line();
+            }
+
+            Self::imports(&mut header, out, tokens);
+            let format = Format::default();
+            header.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            if let Some(module) = self.module.rsplit(MODULE_SEP).next() {
+                out.write_str(module)?;
+                out.write_str(SEP)?;
+            }
+
+            out.write_str(&self.name)?;
+            Ok(())
+        }
+    }
+}
+
+/// The import of a Go type `import "foo/bar"`.
+///
+/// Created using the [import()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+pub struct Import {
+    /// Module of the imported name.
+    module: ItemStr,
+    /// Name imported.
+    name: ItemStr,
+}
+
+/// Format for Go.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Config data for Go.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    package: Option<ItemStr>,
+    naming: Naming,
+}
+
+impl_naming!(Config);
+
+impl Config {
+    /// Configure the specified package.
+    pub fn with_package<P: Into<ItemStr>>(self, package: P) -> Self {
+        Self {
+            package: Some(package.into()),
+            ..self
+        }
+    }
+}
+
+impl crate::lang::LangConfig for Config {
+    fn with_namespace<N>(self, namespace: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        self.with_package(namespace)
+    }
+}
+
+impl Go {
+    fn imports(out: &mut Tokens, formatter: &mut fmt::Formatter<'_>, tokens: &Tokens) {
+        let mut modules = BTreeSet::new();
+
+        for import in tokens.walk_imports() {
+            trace!(module = %import.module, name = %import.name, "collecting import");
+            formatter.record_import::<Self>(import);
+            modules.insert(&import.module);
+        }
+
+        if modules.is_empty() {
+            return;
+        }
+
+        for module in modules {
+            quote_in!(*out => import $(quoted(module)));
+            out.push();
+        }
+
+        out.line();
+    }
+}
+
+/// The import of a Go type `import "foo/bar"`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let ty = go::import("foo/bar", "Debug");
+///
+/// let toks = quote! {
+///     $ty
+/// };
+///
+/// assert_eq!(
+///     vec![
+///        "import \"foo/bar\"",
+///        "",
+///        "bar.Debug",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<M, N>(module: M, name: N) -> Import
+where
+    M: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        module: module.into(),
+        name: name.into(),
+    }
+}
+
+/// Define a struct, with fields aligned like gofmt does.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let mutex = go::import("sync", "Mutex");
+/// let time = go::import("time", "Time");
+///
+/// let user = go::struct_def("User")
+///     .with_doc("User is a registered user.")
+///     .with_embedded(mutex)
+///     .with_field(go::field("ID", "int64").with_tag("json", "id"))
+///     .with_field(go::field("Name", "string").with_tag("json", "name,omitempty"))
+///     .with_field(go::field("CreatedAt", time))
+///     .with_field(go::field("Admin", "bool").with_doc("Admin users can do anything."));
+///
+/// let toks = quote! {
+///     $user
+///
+///     $(go::struct_def("Empty"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"sync\"",
+///         "import \"time\"",
+///         "",
+///         "// User is a registered user.",
+///         "type User struct {",
+///         "    sync.Mutex",
+///         "    ID        int64  `json:\"id\"`",
+///         "    Name      string `json:\"name,omitempty\"`",
+///         "    CreatedAt time.Time",
+///         "    // Admin users can do anything.",
+///         "    Admin bool",
+///         "}",
+///         "",
+///         "type Empty struct{}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn struct_def<N>(name: N) -> Struct
+where
+    N: Into<ItemStr>,
+{
+    Struct::new(name.into())
+}
+
+/// Define a named field for a struct defined through [struct_def()].
+///
+/// Embedded fields can be constructed through [Field::embedded].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let reader = go::import("io", "Reader");
+///
+/// let toks = quote! {
+///     $(go::struct_def("Request")
+///         .with_field(go::Field::embedded(reader).with_tag("json", "-"))
+///         .with_field(go::field("Path", "string").with_tag("json", "path").with_tag("db", "path")))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"io\"",
+///         "",
+///         "type Request struct {",
+///         "    io.Reader `json:\"-\"`",
+///         "    Path      string `json:\"path\" db:\"path\"`",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn field<N, T>(name: N, ty: T) -> Field
+where
+    N: Into<ItemStr>,
+    T: FormatInto<Go>,
+{
+    Field::new(Some(name.into()), ty)
+}
+
+/// Define an interface.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let reader = go::import("io", "Reader");
+/// let context = go::import("context", "Context");
+///
+/// let store = go::interface("Store")
+///     .with_doc("Store stores blobs.")
+///     .with_embedded(reader)
+///     .with_method(go::method(quote!(Put(ctx $context, key string, data []byte) error)))
+///     .with_method(go::method(quote!(Len() int)).with_doc("Len returns the number of blobs."));
+///
+/// let toks = quote! {
+///     $store
+///
+///     $(go::interface("Any"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import \"context\"",
+///         "import \"io\"",
+///         "",
+///         "// Store stores blobs.",
+///         "type Store interface {",
+///         "    io.Reader",
+///         "    Put(ctx context.Context, key string, data []byte) error",
+///         "    // Len returns the number of blobs.",
+///         "    Len() int",
+///         "}",
+///         "",
+///         "type Any interface{}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn interface<N>(name: N) -> Interface
+where
+    N: Into<ItemStr>,
+{
+    Interface::new(name.into())
+}
+
+/// Define a method for an interface defined through [interface()].
+///
+/// See [interface()] for an example.
+pub fn method<T>(signature: T) -> Method
+where
+    T: FormatInto<Go>,
+{
+    Method::new(signature)
+}
+
+/// Write the given lines of documentation as `//` comments.
+fn doc_comment(tokens: &mut Tokens, doc: &[ItemStr]) {
+    for line in doc {
+        tokens.push();
+        tokens.append(tokens::static_literal("//"));
+        tokens.space();
+        tokens.append(line.clone());
+    }
+}
@@ -0,0 +1,289 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::lang::Go;
+use crate::tokens::{self, FormatInto, ItemStr};
+
+use super::Tokens;
+
+/// A struct definition.
+///
+/// This struct is created by the [struct_def][super::struct_def()] function.
+#[derive(Debug, Clone)]
+pub struct Struct {
+    name: ItemStr,
+    doc: Vec<ItemStr>,
+    fields: Vec<Field>,
+}
+
+impl Struct {
+    pub(super) fn new(name: ItemStr) -> Self {
+        Self {
+            name,
+            doc: Vec::new(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Add a line of documentation to the struct.
+    pub fn with_doc<D>(mut self, doc: D) -> Self
+    where
+        D: Into<ItemStr>,
+    {
+        self.doc.push(doc.into());
+        self
+    }
+
+    /// Add a field to the struct.
+    pub fn with_field(mut self, field: Field) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Add an embedded field to the struct.
+    ///
+    /// Use [Field::embedded] to construct an embedded field which has a tag or
+    /// documentation.
+    pub fn with_embedded<T>(self, ty: T) -> Self
+    where
+        T: FormatInto<Go>,
+    {
+        self.with_field(Field::embedded(ty))
+    }
+}
+
+impl FormatInto<Go> for Struct {
+    fn format_into(self, tokens: &mut Tokens) {
+        super::doc_comment(tokens, &self.doc);
+        tokens.push();
+
+        tokens.append(tokens::static_literal("type"));
+        tokens.space();
+        tokens.append(self.name);
+        tokens.space();
+
+        if self.fields.is_empty() {
+            tokens.append(tokens::static_literal("struct{}"));
+            return;
+        }
+
+        tokens.append(tokens::static_literal("struct {"));
+        tokens.indent();
+
+        let mut lines = Vec::new();
+
+        for field in self.fields {
+            for doc in &field.doc {
+                lines.push(Line::Comment(doc.clone()));
+            }
+
+            let mut cells = Vec::new();
+
+            if let Some(name) = field.name {
+                cells.push(Cell::literal(name));
+            }
+
+            cells.push(Cell::tokens(field.ty));
+
+            if !field.tags.is_empty() {
+                cells.push(Cell::tokens(tag(field.tags)));
+            }
+
+            lines.push(Line::Cells(cells));
+        }
+
+        let mut widths = Vec::new();
+        widths.resize_with(lines.len(), Vec::new);
+        align(&lines, &mut widths, 0, 0, lines.len());
+
+        for (line, widths) in lines.into_iter().zip(widths) {
+            tokens.push();
+
+            match line {
+                Line::Comment(doc) => {
+                    super::doc_comment(tokens, &[doc]);
+                }
+                Line::Cells(cells) => {
+                    let last = cells.len() - 1;
+
+                    for (n, cell) in cells.into_iter().enumerate() {
+                        let width = cell.width;
+                        tokens.append(cell.tokens);
+
+                        if n < last {
+                            let padding = widths.get(n).copied().unwrap_or(width) - width + 1;
+                            tokens.append(ItemStr::from(" ".repeat(padding)));
+                        }
+                    }
+                }
+            }
+        }
+
+        tokens.unindent();
+        tokens.append(tokens::static_literal("}"));
+    }
+}
+
+/// A field in a struct.
+///
+/// This struct is created by the [field][super::field()] function.
+#[derive(Debug, Clone)]
+pub struct Field {
+    name: Option<ItemStr>,
+    ty: Tokens,
+    tags: Vec<(ItemStr, ItemStr)>,
+    doc: Vec<ItemStr>,
+}
+
+impl Field {
+    pub(super) fn new<T>(name: Option<ItemStr>, ty: T) -> Self
+    where
+        T: FormatInto<Go>,
+    {
+        let mut tokens = Tokens::new();
+        tokens.append(ty);
+
+        Self {
+            name,
+            ty: tokens,
+            tags: Vec::new(),
+            doc: Vec::new(),
+        }
+    }
+
+    /// Construct an embedded field of the given type.
+    pub fn embedded<T>(ty: T) -> Self
+    where
+        T: FormatInto<Go>,
+    {
+        Self::new(None, ty)
+    }
+
+    /// Add a tag to the field, like `json:"name"`.
+    ///
+    /// Tags are written in the order they were added.
+    pub fn with_tag<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<ItemStr>,
+        V: Into<ItemStr>,
+    {
+        self.tags.push((key.into(), value.into()));
+        self
+    }
+
+    /// Add a line of documentation to the field.
+    pub fn with_doc<D>(mut self, doc: D) -> Self
+    where
+        D: Into<ItemStr>,
+    {
+        self.doc.push(doc.into());
+        self
+    }
+}
+
+/// A line in a struct body.
+enum Line {
+    Comment(ItemStr),
+    Cells(Vec<Cell>),
+}
+
+/// A cell which is aligned with the cells of the same column.
+struct Cell {
+    tokens: Tokens,
+    width: usize,
+}
+
+impl Cell {
+    fn literal(s: ItemStr) -> Self {
+        let width = s.chars().count();
+        let mut tokens = Tokens::new();
+        tokens.append(s);
+        Self { tokens, width }
+    }
+
+    fn tokens(tokens: Tokens) -> Self {
+        // NB: Rendered names don't depend on configuration, so the width can
+        // be measured up front.
+        let width = tokens
+            .to_string()
+            .map(|s| s.chars().count())
+            .unwrap_or_default();
+
+        Self { tokens, width }
+    }
+}
+
+/// Build the tag of a field.
+fn tag(tags: Vec<(ItemStr, ItemStr)>) -> Tokens {
+    let mut tokens = Tokens::new();
+    tokens.append(tokens::static_literal("`"));
+
+    for (n, (key, value)) in tags.into_iter().enumerate() {
+        if n > 0 {
+            tokens.append(tokens::static_literal(" "));
+        }
+
+        tokens.append(key);
+        tokens.append(tokens::static_literal(":\""));
+        tokens.append(escape_tag_value(value));
+        tokens.append(tokens::static_literal("\""));
+    }
+
+    tokens.append(tokens::static_literal("`"));
+    tokens
+}
+
+fn escape_tag_value(value: ItemStr) -> ItemStr {
+    if !value.contains(['"', '\\']) {
+        return value;
+    }
+
+    let mut out = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        if matches!(c, '"' | '\\') {
+            out.push('\\');
+        }
+
+        out.push(c);
+    }
+
+    out.into()
+}
+
+/// Compute the width of each column like gofmt does, which aligns a column
+/// over consecutive lines where the column is followed by another cell.
+///
+/// This is a simplified version of the algorithm used by Go's text/tabwriter.
+fn align(lines: &[Line], widths: &mut [Vec<usize>], column: usize, start: usize, end: usize) {
+    let mut n = start;
+
+    while n < end {
+        if !has_column(&lines[n], column) {
+            n += 1;
+            continue;
+        }
+
+        let block = n;
+        let mut width = 0;
+
+        while n < end && has_column(&lines[n], column) {
+            if let Line::Cells(cells) = &lines[n] {
+                width = usize::max(width, cells[column].width);
+            }
+
+            n += 1;
+        }
+
+        for widths in &mut widths[block..n] {
+            widths.push(width);
+        }
+
+        align(lines, widths, column + 1, block, n);
+    }
+
+    /// Test if the given column is followed by another cell on the line.
+    fn has_column(line: &Line, column: usize) -> bool {
+        matches!(line, Line::Cells(cells) if column + 1 < cells.len())
+    }
+}
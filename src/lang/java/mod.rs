@@ -18,14 +18,18 @@
 mod block_comment;
 pub use self::block_comment::BlockComment;
 
+mod try_with;
+pub use self::try_with::TryWith;
+
 use core::fmt::Write as _;
 
-use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 use crate as genco;
 use crate::fmt;
-use crate::tokens::ItemStr;
+use crate::tokens::{self, Case, FormatInto, IdentKind, ItemStr, Naming};
 use crate::{quote, quote_in};
 
 /// Tokens container specialized for Java.
@@ -38,6 +42,10 @@ impl_lang! {
         type Format = Format;
         type Item = Import;
 
+        fn ident_case(config: &Self::Config, kind: IdentKind) -> Option<Case> {
+            config.naming.get(kind)
+        }
+
         fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
             // From: https://docs.oracle.com/javase/tutorial/java/data/characters.html
 
@@ -77,7 +85,7 @@ impl_lang! {
             }
 
             let mut format = Format::default();
-            Self::imports(&mut header, tokens, config, &mut format.imported);
+            Self::imports(&mut header, out, tokens, config, &mut format);
             header.format(out, config, &format)?;
             tokens.format(out, config, &format)?;
             Ok(())
@@ -86,11 +94,22 @@ impl_lang! {
 
     Import {
         fn format(&self, out: &mut fmt::Formatter<'_>, config: &Config, format: &Format) -> fmt::Result {
-            let file_package = config.package.as_ref().map(|p| p.as_ref());
-            let imported = format.imported.get(self.name.as_ref()).map(String::as_str);
             let pkg = Some(self.package.as_ref());
 
-            if &*self.package != JAVA_LANG && imported != pkg && file_package != pkg {
+            let qualified = match self.kind {
+                Kind::Class => {
+                    let file_package = config.package.as_ref().map(|p| p.as_ref());
+                    let imported = format.imported.get(self.name.as_ref()).map(String::as_str);
+                    &*self.package != JAVA_LANG && imported != pkg && file_package != pkg
+                }
+                Kind::Static => {
+                    let imported = format.imported_static.get(self.name.as_ref()).map(String::as_str);
+                    imported != pkg
+                }
+                Kind::All => return Ok(()),
+            };
+
+            if qualified {
                 out.write_str(self.package.as_ref())?;
                 out.write_str(SEP)?;
             }
@@ -109,15 +128,25 @@ const SEP: &str = ".";
 pub struct Format {
     /// Types which has been imported into the local namespace.
     imported: BTreeMap<String, String>,
+    /// Static members which have been imported into the local namespace, and
+    /// the classes they belong to.
+    imported_static: BTreeMap<String, String>,
 }
 
 /// Configuration for Java.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Config {
     /// Package to use.
     package: Option<ItemStr>,
+    /// Number of imports from a single package after which they are collapsed
+    /// into a wildcard import.
+    wildcard_threshold: Option<usize>,
+    /// Naming conventions to apply to identifiers.
+    naming: Naming,
 }
 
+impl_naming!(Config);
+
 impl Config {
     /// Configure package to use for the file generated.
     ///
@@ -154,57 +183,232 @@ impl Config {
     {
         Self {
             package: Some(package.into()),
+            ..self
+        }
+    }
+
+    /// Collapse imports into a wildcard import like `import java.util.*;` when
+    /// more than `threshold` names are imported from the same package.
+    ///
+    /// Packages are only collapsed if that results in a single wildcard import
+    /// in the file, counting the ones added through [import_all]. Otherwise a
+    /// name like `List` could be ambiguous between `java.util.*` and
+    /// `java.awt.*`, so names are imported explicitly instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let list = java::import("java.util", "List");
+    /// let map = java::import("java.util", "Map");
+    /// let optional = java::import("java.util", "Optional");
+    /// let file = java::import("java.io", "File");
+    ///
+    /// let toks = quote!($list $map $optional $file);
+    ///
+    /// let config = java::Config::default().with_wildcard_threshold(2);
+    /// let fmt = fmt::Config::from_lang::<Java>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    ///
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import java.io.File;",
+    ///         "import java.util.*;",
+    ///         "",
+    ///         "List Map Optional File",
+    ///     ],
+    ///     w.into_vec(),
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_wildcard_threshold(self, threshold: usize) -> Self {
+        Self {
+            wildcard_threshold: Some(threshold),
+            ..self
         }
     }
 }
 
+impl crate::lang::LangConfig for Config {
+    fn with_namespace<N>(self, namespace: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        self.with_package(namespace)
+    }
+}
+
 /// The import of a Java type `import java.util.Optional;`.
 ///
 /// Created through the [import()] function.
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub struct Import {
-    /// Package of the class.
+    /// Package of the class, or the class of a static member.
     package: ItemStr,
-    /// Name  of class.
+    /// Name  of class, or of the static member.
     name: ItemStr,
+    /// How the import is brought into scope.
+    #[cfg_attr(feature = "ir", serde(default))]
+    kind: Kind,
+}
+
+/// How an [Import] is brought into scope.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+enum Kind {
+    /// A class imported by name.
+    #[default]
+    Class,
+    /// A static member of a class, imported with `import static`.
+    Static,
+    /// Every class in a package, imported on demand with `.*`.
+    All,
 }
 
 impl Java {
     fn imports(
         out: &mut Tokens,
+        formatter: &mut fmt::Formatter<'_>,
         tokens: &Tokens,
         config: &Config,
-        imported: &mut BTreeMap<String, String>,
+        format: &mut Format,
     ) {
-        let mut modules = BTreeSet::new();
+        let mut modules = BTreeMap::new();
+        let mut statics = BTreeMap::new();
+        let mut wildcards = BTreeMap::new();
 
         let file_package = config.package.as_ref().map(|p| p.as_ref());
 
         for import in tokens.walk_imports() {
-            modules.insert((import.package.clone(), import.name.clone()));
+            let key = (import.package.clone(), import.name.clone());
+
+            match import.kind {
+                Kind::Class => {
+                    modules.insert(key, import);
+                }
+                Kind::Static => {
+                    statics.insert(key, import);
+                }
+                Kind::All => {
+                    wildcards.insert(import.package.clone(), import);
+                }
+            }
         }
 
-        if modules.is_empty() {
+        if modules.is_empty() && statics.is_empty() && wildcards.is_empty() {
             return;
         }
 
-        for (package, name) in modules {
-            if imported.contains_key(&*name) {
+        // NB: Static members are in a namespace of their own, so they never
+        // conflict with classes.
+        let imported_static = &mut format.imported_static;
+
+        for ((class, name), import) in statics {
+            if let Some(existing) = imported_static.get(&*name) {
+                trace!(%class, %name, "skipping static import, name already imported");
+
+                formatter.warn(fmt::Warning::Qualified {
+                    module: class.to_string(),
+                    name: name.to_string(),
+                    conflict: fmt::Conflict::Module(existing.clone()),
+                });
+
+                continue;
+            }
+
+            imported_static.insert(name.to_string(), class.to_string());
+            formatter.record_import::<Self>(import);
+            out.append(quote!(import static $class$(SEP)$name;));
+            out.push();
+        }
+
+        if !imported_static.is_empty() {
+            out.line();
+        }
+
+        let imported = &mut format.imported;
+        let mut packages = BTreeMap::<_, Vec<_>>::new();
+
+        for (package, import) in &wildcards {
+            if &**package == JAVA_LANG || Some(&**package) == file_package {
+                trace!(%package, "skipping wildcard import, implicitly imported");
+                continue;
+            }
+
+            formatter.record_import::<Self>(*import);
+            packages.entry(package.clone()).or_default();
+        }
+
+        for ((package, name), import) in modules {
+            if let Some(existing) = imported.get(&*name) {
+                trace!(%package, %name, "skipping import, name already imported");
+
+                formatter.warn(fmt::Warning::Qualified {
+                    module: package.to_string(),
+                    name: name.to_string(),
+                    conflict: fmt::Conflict::Module(existing.clone()),
+                });
+
                 continue;
             }
 
             if &*package == JAVA_LANG {
+                trace!(%package, %name, "skipping import, implicitly imported");
                 continue;
             }
 
             if Some(&*package) == file_package {
+                trace!(%package, %name, "skipping import, same package as file");
                 continue;
             }
 
-            out.append(quote!(import $(package.clone())$(SEP)$(name.clone());));
-            out.push();
-
             imported.insert(name.to_string(), package.to_string());
+            formatter.record_import::<Self>(import);
+            packages.entry(package).or_default().push(name);
+        }
+
+        let collapse = |package: &ItemStr, names: &[ItemStr]| {
+            !wildcards.contains_key(package)
+                && matches!(config.wildcard_threshold, Some(threshold) if names.len() > threshold)
+        };
+
+        // Two on-demand imports could both provide a name we use, in which
+        // case the name would be ambiguous. So packages are only collapsed if
+        // that results in the only on-demand import in the file.
+        let on_demand = packages
+            .iter()
+            .filter(|(package, names)| wildcards.contains_key(*package) || collapse(package, names))
+            .count();
+
+        for (package, names) in packages {
+            // Names from the package are covered by the wildcard import.
+            if wildcards.contains_key(&package) {
+                out.append(quote!(import $(package.clone())$(SEP)*;));
+                out.push();
+                continue;
+            }
+
+            if collapse(&package, &names) {
+                if on_demand == 1 {
+                    trace!(%package, names = names.len(), "collapsing into wildcard import");
+                    out.append(quote!(import $(package.clone())$(SEP)*;));
+                    out.push();
+                    continue;
+                }
+
+                trace!(%package, on_demand, "not collapsing, wildcard imports could be ambiguous");
+            }
+
+            for name in names {
+                out.append(quote!(import $(package.clone())$(SEP)$name;));
+                out.push();
+            }
         }
 
         out.line();
@@ -245,6 +449,106 @@ where
     Import {
         package: package.into(),
         name: name.into(),
+        kind: Kind::Class,
+    }
+}
+
+/// The static import of a member of a Java class, like
+/// `import static java.util.Objects.requireNonNull;`.
+///
+/// Static members are in a namespace of their own, so they never conflict with
+/// imported classes which have the same name. If two static members with the
+/// same name are used, the one which sorts last is referred to through its
+/// class.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let require_non_null = java::static_import("java.util.Objects", "requireNonNull");
+/// let max = java::static_import("java.lang.Math", "max");
+/// let other_max = java::static_import("java.lang.Integer", "max");
+/// let objects = java::import("java.util", "Objects");
+///
+/// let toks = quote! {
+///     $require_non_null(a);
+///     $max(a, b);
+///     $other_max(a, b);
+///     $objects.hash(a);
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import static java.lang.Integer.max;",
+///         "import static java.util.Objects.requireNonNull;",
+///         "",
+///         "import java.util.Objects;",
+///         "",
+///         "requireNonNull(a);",
+///         "java.lang.Math.max(a, b);",
+///         "max(a, b);",
+///         "Objects.hash(a);",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn static_import<C, N>(class: C, name: N) -> Import
+where
+    C: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        package: class.into(),
+        name: name.into(),
+        kind: Kind::Static,
+    }
+}
+
+/// The on-demand import of every class in a package, like
+/// `import java.util.*;`.
+///
+/// This doesn't write anything when formatted, so it's typically
+/// [registered][crate::tokens::register()]. Classes from the package which are
+/// used in the same file are covered by it, instead of being imported one by
+/// one.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let list = java::import("java.util", "List");
+/// let map = java::import("java.util", "Map");
+/// let file = java::import("java.io", "File");
+///
+/// let toks = quote! {
+///     $(register(java::import_all("java.util")))
+///     $list<$(&file)> files;
+///     $map<String, $file> index;
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import java.io.File;",
+///         "import java.util.*;",
+///         "",
+///         "List<File> files;",
+///         "Map<String, File> index;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import_all<P>(package: P) -> Import
+where
+    P: Into<ItemStr>,
+{
+    Import {
+        package: package.into(),
+        name: ItemStr::Static("*"),
+        kind: Kind::All,
     }
 }
 
@@ -283,3 +587,94 @@ where
 {
     BlockComment(comment)
 }
+
+/// Format a try-with-resources statement, with optional catch clauses.
+///
+/// Each catch clause is a tuple of the exception type, the name of the
+/// exception variable, and the body of the handler.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let reader = java::import("java.io", "BufferedReader");
+/// let file_reader = java::import("java.io", "FileReader");
+/// let io_exception = java::import("java.io", "IOException");
+///
+/// let toks = quote! {
+///     $(java::try_with(
+///         [quote!($(&reader) in = new $(&reader)(new $(&file_reader)(path)))],
+///         quote!(return in.readLine();),
+///         [(&io_exception, "e", quote!(throw new RuntimeException(e);))],
+///     ))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import java.io.BufferedReader;",
+///         "import java.io.FileReader;",
+///         "import java.io.IOException;",
+///         "",
+///         "try (BufferedReader in = new BufferedReader(new FileReader(path))) {",
+///         "    return in.readLine();",
+///         "} catch (IOException e) {",
+///         "    throw new RuntimeException(e);",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn try_with<R, B, C>(resources: R, body: B, catches: C) -> TryWith<R, B, C> {
+    TryWith(resources, body, catches)
+}
+
+/// Format a chain of method calls on a receiver, like when using a builder.
+///
+/// This is a Java-specialized alias for [tokens::chain()][crate::tokens::chain()].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let user = java::import("com.example", "User");
+///
+/// let short = [quote!(setName("John")), quote!(build())];
+///
+/// let long = [
+///     quote!(setName("John-John Tedro")),
+///     quote!(setEmail("udoprog@tedro.se")),
+///     quote!(setHomepage("https://github.com/udoprog")),
+///     quote!(build()),
+/// ];
+///
+/// let toks = quote! {
+///     $(&user) a = $(java::chain(quote!($(&user).builder()), short));
+///     $(&user) b = $(java::chain(quote!($(&user).builder()), long));
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import com.example.User;",
+///         "",
+///         "User a = User.builder().setName(\"John\").build();",
+///         "User b = User.builder()",
+///         "    .setName(\"John-John Tedro\")",
+///         "    .setEmail(\"udoprog@tedro.se\")",
+///         "    .setHomepage(\"https://github.com/udoprog\")",
+///         "    .build();",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn chain<R, C>(receiver: R, calls: C) -> tokens::Chain<R, C>
+where
+    R: FormatInto<Java>,
+    C: IntoIterator,
+    C::Item: FormatInto<Java>,
+{
+    tokens::chain(receiver, calls)
+}
@@ -0,0 +1,34 @@
+use crate as genco;
+use crate::lang::Java;
+use crate::quote_in;
+use crate::tokens::{FormatInto, ItemStr};
+use crate::Tokens;
+
+/// Format a try-with-resources statement.
+///
+/// This struct is created by the [try_with][super::try_with()] function.
+pub struct TryWith<R, B, C>(pub(super) R, pub(super) B, pub(super) C);
+
+impl<R, B, C, E, N, H> FormatInto<Java> for TryWith<R, B, C>
+where
+    R: IntoIterator,
+    R::Item: FormatInto<Java>,
+    B: FormatInto<Java>,
+    C: IntoIterator<Item = (E, N, H)>,
+    E: FormatInto<Java>,
+    N: Into<ItemStr>,
+    H: FormatInto<Java>,
+{
+    fn format_into(self, tokens: &mut Tokens<Java>) {
+        let TryWith(resources, body, catches) = self;
+
+        quote_in! { *tokens =>
+            try ($(for r in resources join (; ) => $r)) {
+                $body
+            }$(for (exception, name, handler) in catches =>
+                $[' ']catch ($exception $(name.into())) {
+                    $handler
+                })
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::lang::js::Import;
+use crate::lang::JavaScript;
+use crate::tokens::{self, FormatInto, ItemStr};
+use crate::Tokens;
+
+/// A type used in a [JsDoc] annotation.
+///
+/// Imports are converted into type references like `import("module").Name`
+/// through [Import::into_type_ref].
+#[derive(Debug, Clone)]
+pub struct JsDocType(Tokens<JavaScript>);
+
+impl From<&str> for JsDocType {
+    fn from(value: &str) -> Self {
+        Self::from(ItemStr::from(value))
+    }
+}
+
+impl From<String> for JsDocType {
+    fn from(value: String) -> Self {
+        Self::from(ItemStr::from(value))
+    }
+}
+
+impl From<ItemStr> for JsDocType {
+    fn from(value: ItemStr) -> Self {
+        let mut tokens = Tokens::new();
+        tokens.append(value);
+        Self(tokens)
+    }
+}
+
+impl From<Import> for JsDocType {
+    fn from(value: Import) -> Self {
+        let mut tokens = Tokens::new();
+        tokens.append(value.into_type_ref());
+        Self(tokens)
+    }
+}
+
+impl From<&Import> for JsDocType {
+    fn from(value: &Import) -> Self {
+        Self::from(value.clone())
+    }
+}
+
+/// Composite types, like `Promise<User>`. Imports used in the tokens are
+/// imported as usual, so they should be converted using
+/// [Import::into_type_ref] first.
+impl From<Tokens<JavaScript>> for JsDocType {
+    fn from(value: Tokens<JavaScript>) -> Self {
+        Self(value)
+    }
+}
+
+/// Format a JSDoc comment, starting with `/**`, and ending in `*/`.
+///
+/// This struct is created by the [jsdoc][super::jsdoc()] function.
+#[derive(Debug, Clone)]
+pub struct JsDoc {
+    pub(super) typedefs: Vec<(ItemStr, JsDocType)>,
+    pub(super) params: Vec<(ItemStr, JsDocType)>,
+    pub(super) returns: Option<JsDocType>,
+}
+
+impl JsDoc {
+    /// Add a `@typedef {Type} Name` to the comment.
+    ///
+    /// This is typically used to give a local name to an imported type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let user = js::import(js::Module::Path("models.js".into()), "User");
+    ///
+    /// let toks = quote! {
+    ///     $(js::jsdoc(None::<(&str, &str)>, None::<&str>).with_typedef("User", &user))
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "/**",
+    ///         " * @typedef {import(\"models.js\").User} User",
+    ///         " */",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_typedef<N, T>(mut self, name: N, ty: T) -> Self
+    where
+        N: Into<ItemStr>,
+        T: Into<JsDocType>,
+    {
+        self.typedefs.push((name.into(), ty.into()));
+        self
+    }
+}
+
+impl FormatInto<JavaScript> for JsDoc {
+    fn format_into(self, tokens: &mut Tokens<JavaScript>) {
+        if self.typedefs.is_empty() && self.params.is_empty() && self.returns.is_none() {
+            return;
+        }
+
+        tokens.push();
+        tokens.append(tokens::static_literal("/**"));
+        tokens.push();
+
+        for (name, ty) in self.typedefs {
+            line(tokens, "@typedef", ty, Some(name));
+        }
+
+        for (name, ty) in self.params {
+            line(tokens, "@param", ty, Some(name));
+        }
+
+        if let Some(ty) = self.returns {
+            line(tokens, "@returns", ty, None);
+        }
+
+        tokens.space();
+        tokens.append(tokens::static_literal("*/"));
+
+        fn line(
+            tokens: &mut Tokens<JavaScript>,
+            tag: &'static str,
+            ty: JsDocType,
+            name: Option<ItemStr>,
+        ) {
+            tokens.space();
+            tokens.append(tokens::static_literal("*"));
+            tokens.space();
+            tokens.append(tokens::static_literal(tag));
+            tokens.space();
+            tokens.append(tokens::static_literal("{"));
+            tokens.append(ty.0);
+            tokens.append(tokens::static_literal("}"));
+
+            if let Some(name) = name {
+                tokens.space();
+                tokens.append(name);
+            }
+
+            tokens.push();
+        }
+    }
+}
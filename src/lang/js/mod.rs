@@ -49,13 +49,18 @@
 //! # }
 //! ```
 
+mod jsdoc;
+pub use self::jsdoc::{JsDoc, JsDocType};
+
 use core::fmt::Write as _;
 
 use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::string::String;
+use alloc::vec::Vec;
 
 use crate::fmt;
-use crate::tokens::ItemStr;
+use crate::lang::Style;
+use crate::tokens::{Case, IdentKind, ItemStr, Naming};
 
 use relative_path::{RelativePath, RelativePathBuf};
 
@@ -71,6 +76,14 @@ impl_lang! {
         type Format = Format;
         type Item = Import;
 
+        fn ident_case(config: &Self::Config, kind: IdentKind) -> Option<Case> {
+            config.naming.get(kind)
+        }
+
+        fn style(config: &Self::Config) -> Style {
+            config.style
+        }
+
         /// Start a string quote.
         fn open_quote(
             out: &mut fmt::Formatter<'_>,
@@ -163,7 +176,7 @@ impl_lang! {
             config: &Self::Config,
         ) -> fmt::Result {
             let mut imports = Tokens::new();
-            Self::imports(&mut imports, tokens, config);
+            Self::imports(&mut imports, out, tokens, config);
             let format = Format::default();
             imports.format(out, config, &format)?;
             tokens.format(out, config, &format)?;
@@ -172,7 +185,32 @@ impl_lang! {
     }
 
     Import {
-        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+        fn format(&self, out: &mut fmt::Formatter<'_>, config: &Config, _: &Format) -> fmt::Result {
+            if self.type_ref {
+                out.write_str("import(\"")?;
+
+                match (config.module_path.as_deref(), &self.module) {
+                    (_, Module::Global(from)) => <JavaScript as crate::lang::Lang>::write_quoted(out, from)?,
+                    (None, Module::Path(path)) => <JavaScript as crate::lang::Lang>::write_quoted(out, path.as_str())?,
+                    (Some(module_path), Module::Path(path)) => {
+                        <JavaScript as crate::lang::Lang>::write_quoted(out, module_path.relative(path).as_str())?
+                    }
+                }
+
+                out.write_str("\")")?;
+
+                match self.kind {
+                    ImportKind::Named => {
+                        out.write_char('.')?;
+                        out.write_str(&self.name)?;
+                    }
+                    ImportKind::Default => out.write_str(".default")?,
+                    ImportKind::Wildcard => (),
+                }
+
+                return Ok(());
+            }
+
             let name = match self.kind {
                 ImportKind::Named => self.alias.as_ref().unwrap_or(&self.name),
                 _ => &self.name,
@@ -188,11 +226,15 @@ impl_lang! {
 pub struct Format {}
 
 /// Configuration for JavaScript.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Config {
     module_path: Option<RelativePathBuf>,
+    naming: Naming,
+    style: Style,
 }
 
+impl_naming!(Config);
+
 impl Config {
     /// Configure the path to the current module being renderer.
     ///
@@ -243,12 +285,27 @@ impl Config {
     {
         Self {
             module_path: Some(module_path.into()),
+            ..self
+        }
+    }
+
+    /// Write a trailing comma after the last item of lists which are broken
+    /// onto multiple lines, like prettier does.
+    ///
+    /// See [separated_with_style].
+    ///
+    /// [separated_with_style]: crate::tokens::separated_with_style()
+    pub fn with_trailing_commas(self, trailing_commas: bool) -> Self {
+        Self {
+            style: self.style.with_trailing_commas(trailing_commas),
+            ..self
         }
     }
 }
 
 /// Internal type to determine the kind of import used.
 #[derive(Debug, Clone, Copy, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 enum ImportKind {
     Named,
     Default,
@@ -259,6 +316,7 @@ enum ImportKind {
 ///
 /// Created through the [import()] function.
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub struct Import {
     /// The kind of the import.
     kind: ImportKind,
@@ -274,6 +332,9 @@ pub struct Import {
     /// import {<name> as <alias>} from <module>
     /// ```
     alias: Option<ItemStr>,
+    /// If the import is only used as a type reference, like
+    /// `import("module").Name`, in which case it's not imported.
+    type_ref: bool,
 }
 
 impl Import {
@@ -379,10 +440,45 @@ impl Import {
             ..self
         }
     }
+
+    /// Convert into a type reference like `import("module").Name`, as used in
+    /// JSDoc type annotations.
+    ///
+    /// Type references are not added to the imports of the file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let user = js::import(js::Module::Path("models.js".into()), "User");
+    /// let react = js::import("react", "React").into_default();
+    ///
+    /// let toks = quote! {
+    ///     $(user.into_type_ref())
+    ///     $(react.into_type_ref())
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import(\"models.js\").User",
+    ///         "import(\"react\").default",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn into_type_ref(self) -> Self {
+        Self {
+            type_ref: true,
+            ..self
+        }
+    }
 }
 
 /// A module being imported.
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub enum Module {
     /// A module imported from a specific path.
     ///
@@ -413,7 +509,12 @@ impl From<ItemStr> for Module {
 
 impl JavaScript {
     /// Translate imports into the necessary tokens.
-    fn imports(out: &mut Tokens, tokens: &Tokens, config: &Config) {
+    fn imports(
+        out: &mut Tokens,
+        formatter: &mut fmt::Formatter<'_>,
+        tokens: &Tokens,
+        config: &Config,
+    ) {
         use crate as genco;
         use crate::prelude::*;
 
@@ -421,6 +522,14 @@ impl JavaScript {
         let mut wildcards = BTreeSet::new();
 
         for import in tokens.walk_imports() {
+            if import.type_ref {
+                trace!(name = %import.name, "skipping import, only used as a type");
+                continue;
+            }
+
+            trace!(module = ?import.module, name = %import.name, kind = ?import.kind, "collecting import");
+            formatter.record_import::<Self>(import);
+
             match import.kind {
                 ImportKind::Named => {
                     let module = modules.entry(&import.module).or_default();
@@ -560,5 +669,114 @@ where
         module: module.into(),
         name: name.into(),
         alias: None,
+        type_ref: false,
+    }
+}
+
+/// Format a JSDoc comment annotating the parameters and return type of a
+/// function.
+///
+/// Types can be anything which converts into a [JsDocType], like a string or
+/// an [Import]. Imports are rendered as type references like
+/// `import("module").Name`, and are not added to the imports of the file.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let user = js::import(js::Module::Path("models.js".into()), "User");
+///
+/// let toks = quote! {
+///     $(js::jsdoc([("user", js::JsDocType::from(&user)), ("count", "number".into())], ["boolean"]))
+///     export function check(user, count) {
+///         return count > 0;
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "/**",
+///         " * @param {import(\"models.js\").User} user",
+///         " * @param {number} count",
+///         " * @returns {boolean}",
+///         " */",
+///         "export function check(user, count) {",
+///         "    return count > 0;",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn jsdoc<P, N, T, R>(params: P, returns: R) -> JsDoc
+where
+    P: IntoIterator<Item = (N, T)>,
+    N: Into<ItemStr>,
+    T: Into<JsDocType>,
+    R: IntoIterator,
+    R::Item: Into<JsDocType>,
+{
+    JsDoc {
+        typedefs: Vec::new(),
+        params: params
+            .into_iter()
+            .map(|(name, ty)| (name.into(), ty.into()))
+            .collect(),
+        returns: returns.into_iter().next().map(Into::into),
     }
 }
+
+/// Render an index file like an `index.js`, which re-exports the symbols
+/// declared in the given modules.
+///
+/// Modules without any declarations have everything re-exported. The
+/// declarations of a module are typically collected through
+/// [Tokens::declarations][crate::Tokens::declarations].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let index = js::index([
+///     ("./user.js", vec!["User", "UserId"]),
+///     ("./util.js", vec![]),
+/// ]);
+///
+/// assert_eq!(
+///     vec![
+///         "export {User, UserId} from \"./user.js\";",
+///         "export * from \"./util.js\";",
+///     ],
+///     index.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn index<I, M, D>(modules: I) -> Tokens
+where
+    I: IntoIterator<Item = (M, D)>,
+    M: Into<ItemStr>,
+    D: IntoIterator,
+    D::Item: Into<ItemStr>,
+{
+    use crate as genco;
+    use crate::prelude::*;
+
+    let mut t = Tokens::new();
+
+    for (module, declarations) in modules {
+        let module = module.into();
+        let mut declarations = declarations.into_iter().map(Into::into).peekable();
+
+        if declarations.peek().is_none() {
+            quote_in!(t => export * from $(quoted(module)););
+        } else {
+            quote_in!(t => export {$(for d in declarations join (, ) => $d)} from $(quoted(module)););
+        }
+
+        t.push();
+    }
+
+    t
+}
@@ -0,0 +1,65 @@
+use alloc::string::String;
+
+use crate::lang::Julia;
+use crate::tokens::{static_literal, FormatInto, ItemStr};
+use crate::Tokens;
+
+/// Format a triple-quoted docstring.
+///
+/// This struct is created by the [docstring][super::docstring()] function.
+pub struct Docstring<T>(pub(super) T);
+
+impl<T> FormatInto<Julia> for Docstring<T>
+where
+    T: IntoIterator,
+    T::Item: Into<ItemStr>,
+{
+    fn format_into(self, tokens: &mut Tokens<Julia>) {
+        tokens.push();
+        tokens.append(static_literal("\"\"\""));
+
+        let mut blank = false;
+
+        for line in self.0 {
+            let line = line.into();
+
+            if line.is_empty() {
+                blank = true;
+                continue;
+            }
+
+            if core::mem::take(&mut blank) {
+                tokens.line();
+            } else {
+                tokens.push();
+            }
+
+            tokens.append(escape(&line));
+        }
+
+        tokens.push();
+        tokens.append(static_literal("\"\"\""));
+        tokens.push();
+    }
+}
+
+/// Escape a line of a docstring, which is interpolated and can't contain the
+/// closing delimiter.
+fn escape(line: &str) -> ItemStr {
+    if !line.contains(['\\', '$', '"']) {
+        return ItemStr::from(line);
+    }
+
+    let mut out = String::with_capacity(line.len());
+
+    for c in line.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '$' => out.push_str("\\$"),
+            '"' => out.push_str("\\\""),
+            c => out.push(c),
+        }
+    }
+
+    ItemStr::from(out)
+}
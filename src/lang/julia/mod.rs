@@ -0,0 +1,288 @@
+//! Specialization for Julia code generation.
+//!
+//! # String Quoting in Julia
+//!
+//! Since Julia supports string interpolation, `$` is escaped in quoted strings
+//! along with the usual C-style escapes.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! let toks: julia::Tokens = quote!("cost: $5\n");
+//! assert_eq!("\"cost: \\$5\\n\"", toks.to_string()?);
+//! # Ok::<_, genco::fmt::Error>(())
+//! ```
+//!
+//! # String Interpolation in Julia
+//!
+//! Strings can be interpolated in Julia by using `$[str](<content>)`, where
+//! `$name` interpolates a variable and `$(<expr>)` an expression.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! let toks: julia::Tokens = quote!($[str](Hello $name));
+//! assert_eq!("\"Hello $name\"", toks.to_string()?);
+//!
+//! let toks: julia::Tokens = quote!($[str](Sum: $(a + b)));
+//! assert_eq!("\"Sum: $(a + b)\"", toks.to_string()?);
+//! # Ok::<_, genco::fmt::Error>(())
+//! ```
+
+mod docstring;
+pub use self::docstring::Docstring;
+
+use core::fmt::Write as _;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::{CommentKind, ItemStr};
+
+/// Tokens container specialization for Julia.
+pub type Tokens = crate::Tokens<Julia>;
+
+impl crate::lang::LangSupportsEval for Julia {}
+
+impl_lang! {
+    /// Language specialization for Julia.
+    pub Julia {
+        type Config = Config;
+        type Format = Format;
+        type Item = Import;
+
+        fn write_comment(out: &mut fmt::Formatter<'_>, kind: CommentKind, text: &str) -> fmt::Result {
+            match kind {
+                CommentKind::Line => crate::lang::write_line_comment(out, "#", text),
+                CommentKind::Block => crate::lang::write_block_comment(out, "#=", "=#", text),
+            }
+        }
+
+        fn string_eval_literal(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            literal: &str,
+        ) -> fmt::Result {
+            write!(out, "${}", literal)?;
+            Ok(())
+        }
+
+        fn start_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_str("$(")?;
+            Ok(())
+        }
+
+        fn end_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_char(')')?;
+            Ok(())
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            for c in input.chars() {
+                match c {
+                    '\n' => out.write_str("\\n")?,
+                    '\r' => out.write_str("\\r")?,
+                    '\t' => out.write_str("\\t")?,
+                    '"' => out.write_str("\\\"")?,
+                    '\\' => out.write_str("\\\\")?,
+                    '$' => out.write_str("\\$")?,
+                    c if !c.is_control() => out.write_char(c)?,
+                    c => write!(out, "\\u{:04x}", c as u32)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+
+            Self::imports(&mut header, out, tokens);
+            let format = Format::default();
+            header.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            out.write_str(&self.name)?;
+            Ok(())
+        }
+    }
+}
+
+/// A name brought into scope from a module, either through `using` or
+/// `import`.
+///
+/// Created through the [using()] and [import()] functions.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+pub struct Import {
+    /// The module the name is brought in from.
+    module: ItemStr,
+    /// The name brought into scope.
+    name: ItemStr,
+    /// If the name is imported with `import`, which allows methods to be
+    /// added to it, instead of `using`.
+    extend: bool,
+}
+
+/// Format for Julia.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Config data for Julia.
+#[derive(Debug, Clone, Default)]
+pub struct Config {}
+
+impl Julia {
+    fn imports(out: &mut Tokens, formatter: &mut fmt::Formatter<'_>, tokens: &Tokens) {
+        // Names grouped by the statement they are brought in through.
+        let mut statements = BTreeMap::<_, BTreeMap<_, BTreeSet<_>>>::new();
+
+        for import in tokens.walk_imports() {
+            trace!(module = %import.module, name = %import.name, extend = import.extend, "collecting import");
+
+            formatter.record_import::<Self>(import);
+
+            statements
+                .entry(import.extend)
+                .or_default()
+                .entry(&import.module)
+                .or_default()
+                .insert(&import.name);
+        }
+
+        if statements.is_empty() {
+            return;
+        }
+
+        for (extend, modules) in statements {
+            let keyword = if extend { "import" } else { "using" };
+
+            for (module, names) in modules {
+                quote_in!(*out => $keyword $module: $(for n in names join (, ) => $n));
+                out.push();
+            }
+        }
+
+        out.line();
+    }
+}
+
+/// Bring a name from the given module into scope with `using`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let now = julia::using("Dates", "now");
+/// let date_time = julia::using("Dates", "DateTime");
+/// let show = julia::import("Base", "show");
+///
+/// let toks = quote! {
+///     t::$date_time = $now()
+///
+///     function $show(io::IO, x::Foo)
+///         print(io, "Foo")
+///     end
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "using Dates: DateTime, now",
+///         "import Base: show",
+///         "",
+///         "t::DateTime = now()",
+///         "",
+///         "function show(io::IO, x::Foo)",
+///         "    print(io, \"Foo\")",
+///         "end",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn using<M, N>(module: M, name: N) -> Import
+where
+    M: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        module: module.into(),
+        name: name.into(),
+        extend: false,
+    }
+}
+
+/// Bring a name from the given module into scope with `import`, which is
+/// required to add methods to a function from another module.
+///
+/// See [using()] for an example.
+pub fn import<M, N>(module: M, name: N) -> Import
+where
+    M: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        module: module.into(),
+        name: name.into(),
+        extend: true,
+    }
+}
+
+/// Format a triple-quoted docstring, which documents the definition that
+/// directly follows it.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: julia::Tokens = quote! {
+///     $(julia::docstring(&["    add(a, b)", "", "Add two numbers, costing $0."]))
+///     function add(a, b)
+///         a + b
+///     end
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "\"\"\"",
+///         "    add(a, b)",
+///         "",
+///         "Add two numbers, costing \\$0.",
+///         "\"\"\"",
+///         "function add(a, b)",
+///         "    a + b",
+///         "end",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn docstring<T>(lines: T) -> Docstring<T>
+where
+    T: IntoIterator,
+    T::Item: Into<ItemStr>,
+{
+    Docstring(lines)
+}
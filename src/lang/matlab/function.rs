@@ -0,0 +1,210 @@
+use alloc::vec::Vec;
+
+use crate as genco;
+use crate::lang::matlab::comment;
+use crate::lang::Matlab;
+use crate::tokens::{FormatInto, ItemStr};
+use crate::Tokens;
+use crate::{quote, quote_in};
+
+/// A function definition.
+///
+/// Created through the [function()] function.
+#[derive(Debug, Clone)]
+pub struct Function {
+    name: ItemStr,
+    inputs: Vec<ItemStr>,
+    outputs: Vec<ItemStr>,
+    help: Vec<ItemStr>,
+    body: Tokens<Matlab>,
+}
+
+impl Function {
+    /// Add an input argument to the function.
+    pub fn with_input<N>(mut self, name: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        self.inputs.push(name.into());
+        self
+    }
+
+    /// Add an output argument to the function.
+    pub fn with_output<N>(mut self, name: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        self.outputs.push(name.into());
+        self
+    }
+
+    /// Add a line of help text, which is written as a comment directly after
+    /// the signature where `help` can find it.
+    pub fn with_help<L>(mut self, line: L) -> Self
+    where
+        L: Into<ItemStr>,
+    {
+        self.help.push(line.into());
+        self
+    }
+}
+
+impl FormatInto<Matlab> for Function {
+    fn format_into(self, tokens: &mut Tokens<Matlab>) {
+        tokens.append("function");
+        tokens.space();
+
+        match self.outputs.len() {
+            0 => {}
+            1 => {
+                quote_in!(*tokens => $(&self.outputs[0]) =);
+                tokens.space();
+            }
+            _ => {
+                quote_in!(*tokens => [$(for o in self.outputs join (, ) => $o)] =);
+                tokens.space();
+            }
+        }
+
+        tokens.append(self.name);
+
+        if !self.inputs.is_empty() {
+            quote_in!(*tokens => ($(for i in self.inputs join (, ) => $i)));
+        }
+
+        tokens.indent();
+        tokens.append(comment(self.help));
+        tokens.push();
+        tokens.append(self.body);
+        tokens.unindent();
+        tokens.append("end");
+    }
+}
+
+/// A function file, which starts with the signature of its main function
+/// followed by any local functions.
+///
+/// Created through the [function_file()] function.
+#[derive(Debug, Clone)]
+pub struct FunctionFile {
+    main: Function,
+    locals: Vec<Function>,
+}
+
+impl FunctionFile {
+    /// Add a local function to the file, which is only visible to functions
+    /// in the same file.
+    pub fn with_local(mut self, function: Function) -> Self {
+        self.locals.push(function);
+        self
+    }
+}
+
+impl FormatInto<Matlab> for FunctionFile {
+    fn format_into(self, tokens: &mut Tokens<Matlab>) {
+        tokens.append(self.main);
+
+        for local in self.locals {
+            tokens.line();
+            tokens.append(local);
+        }
+    }
+}
+
+/// Define a function with the given name and body.
+///
+/// Functions without outputs or inputs omit the corresponding parts of the
+/// signature, and multiple outputs are returned through a bracketed list.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let stats = matlab::function("stats", quote! {
+///     m = mean(x);
+///     s = std(x);
+/// })
+/// .with_input("x")
+/// .with_output("m")
+/// .with_output("s")
+/// .with_help("Compute the mean and standard deviation of x.");
+///
+/// let greet = matlab::function("greet", quote!(disp("Hello")));
+///
+/// let toks = quote! {
+///     $stats
+///
+///     $greet
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "function [m, s] = stats(x)",
+///         "    % Compute the mean and standard deviation of x.",
+///         "    m = mean(x);",
+///         "    s = std(x);",
+///         "end",
+///         "",
+///         "function greet",
+///         "    disp('Hello')",
+///         "end",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn function<N, B>(name: N, body: B) -> Function
+where
+    N: Into<ItemStr>,
+    B: FormatInto<Matlab>,
+{
+    Function {
+        name: name.into(),
+        inputs: Vec::new(),
+        outputs: Vec::new(),
+        help: Vec::new(),
+        body: quote!($body),
+    }
+}
+
+/// Generate a function file, where the file starts with the signature of the
+/// given main function.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let main = matlab::function("area", quote!(a = square(r) * pi;))
+///     .with_input("r")
+///     .with_output("a");
+///
+/// let square = matlab::function("square", quote!(y = x .^ 2;))
+///     .with_input("x")
+///     .with_output("y");
+///
+/// let file = matlab::function_file(main).with_local(square);
+///
+/// let toks: matlab::Tokens = quote!($file);
+///
+/// assert_eq!(
+///     vec![
+///         "function a = area(r)",
+///         "    a = square(r) * pi;",
+///         "end",
+///         "",
+///         "function y = square(x)",
+///         "    y = x .^ 2;",
+///         "end",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn function_file(main: Function) -> FunctionFile {
+    FunctionFile {
+        main,
+        locals: Vec::new(),
+    }
+}
@@ -0,0 +1,175 @@
+//! Specialization for MATLAB and Octave code generation.
+//!
+//! # String Quoting in MATLAB
+//!
+//! Strings are written as single-quoted character vectors, where quotes are
+//! escaped by doubling them.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! let toks: matlab::Tokens = quote!(disp("it's \"here\""));
+//! assert_eq!("disp('it''s \"here\"')", toks.to_string()?);
+//! # Ok::<_, genco::fmt::Error>(())
+//! ```
+//!
+//! # Function Files
+//!
+//! A function file must start with the signature of its main function, so
+//! nothing is added to the top of generated files. Package members are
+//! referenced through their fully qualified name through [import()] instead of
+//! `import` statements for the same reason. See [function_file()] for how to
+//! generate a complete function file.
+
+mod comment;
+pub use self::comment::Comment;
+
+mod function;
+pub use self::function::{function, function_file, Function, FunctionFile};
+
+use core::fmt::Write as _;
+
+use crate::fmt;
+use crate::tokens::{CommentKind, ItemStr};
+
+/// Tokens container specialization for MATLAB.
+pub type Tokens = crate::Tokens<Matlab>;
+
+impl_lang! {
+    /// Language specialization for MATLAB and Octave.
+    pub Matlab {
+        type Config = Config;
+        type Format = Format;
+        type Item = Import;
+
+        fn open_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            _has_eval: bool,
+        ) -> fmt::Result {
+            out.write_char('\'')?;
+            Ok(())
+        }
+
+        fn close_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            _has_eval: bool,
+        ) -> fmt::Result {
+            out.write_char('\'')?;
+            Ok(())
+        }
+
+        fn write_comment(out: &mut fmt::Formatter<'_>, _kind: CommentKind, text: &str) -> fmt::Result {
+            // NB: Block comments in MATLAB have to be on lines of their own,
+            // so every comment is written as a line comment.
+            crate::lang::write_line_comment(out, "%", text)
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            for c in input.chars() {
+                match c {
+                    '\'' => out.write_str("''")?,
+                    c => out.write_char(c)?,
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            out.write_str(&self.package)?;
+            out.write_char('.')?;
+            out.write_str(&self.name)?;
+            Ok(())
+        }
+    }
+}
+
+/// A member of a package, which is referenced by its fully qualified name.
+///
+/// Created through the [import()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+pub struct Import {
+    /// The package of the member, like `matlab.net.http`.
+    package: ItemStr,
+    /// The name of the member.
+    name: ItemStr,
+}
+
+/// Format for MATLAB.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Config data for MATLAB.
+#[derive(Debug, Clone, Default)]
+pub struct Config {}
+
+/// Reference a member of a package, like `RequestMessage` in
+/// `matlab.net.http`.
+///
+/// Since an `import` statement would have to follow the signature of a
+/// function file, members are always referenced by their fully qualified name.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let request = matlab::import("matlab.net.http", "RequestMessage");
+///
+/// let toks = quote! {
+///     r = $request();
+/// };
+///
+/// assert_eq!(
+///     vec!["r = matlab.net.http.RequestMessage();"],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<P, N>(package: P, name: N) -> Import
+where
+    P: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        package: package.into(),
+        name: name.into(),
+    }
+}
+
+/// Format a comment where each line is preceeded by `%`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: matlab::Tokens = quote! {
+///     $(matlab::comment(&["Compute the mean.", "Ignores NaN values."]))
+///     m = mean(x, "omitnan");
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "% Compute the mean.",
+///         "% Ignores NaN values.",
+///         "m = mean(x, 'omitnan');",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn comment<T>(comment: T) -> Comment<T>
+where
+    T: IntoIterator,
+    T::Item: Into<ItemStr>,
+{
+    Comment(comment)
+}
@@ -17,31 +17,85 @@
 //! # }
 //! ```
 
+#[cfg(feature = "lang-c")]
 pub mod c;
+#[cfg(feature = "lang-csharp")]
 pub mod csharp;
+#[cfg(feature = "lang-dart")]
 pub mod dart;
+#[cfg(feature = "lang-go")]
 pub mod go;
+#[cfg(feature = "lang-java")]
 pub mod java;
+#[cfg(feature = "lang-js")]
 pub mod js;
+#[cfg(feature = "lang-julia")]
+pub mod julia;
+#[cfg(feature = "lang-matlab")]
+pub mod matlab;
+#[cfg(feature = "lang-nix")]
 pub mod nix;
+#[cfg(feature = "lang-php")]
+pub mod php;
+#[cfg(feature = "lang-python")]
 pub mod python;
+#[cfg(feature = "lang-rust")]
 pub mod rust;
+#[cfg(feature = "lang-swift")]
 pub mod swift;
+#[cfg(feature = "lang-typescript")]
+pub mod typescript;
+#[cfg(feature = "lang-verilog")]
+pub mod verilog;
+#[cfg(feature = "lang-vhdl")]
+pub mod vhdl;
+#[cfg(feature = "lang-xml")]
+pub mod xml;
 
+#[cfg(feature = "lang-c")]
 pub use self::c::C;
+#[cfg(feature = "lang-csharp")]
 pub use self::csharp::Csharp;
+#[cfg(feature = "lang-dart")]
 pub use self::dart::Dart;
+#[cfg(feature = "lang-go")]
 pub use self::go::Go;
+#[cfg(feature = "lang-java")]
 pub use self::java::Java;
+#[cfg(feature = "lang-js")]
 pub use self::js::JavaScript;
+#[cfg(feature = "lang-julia")]
+pub use self::julia::Julia;
+#[cfg(feature = "lang-matlab")]
+pub use self::matlab::Matlab;
+#[cfg(feature = "lang-nix")]
 pub use self::nix::Nix;
+#[cfg(feature = "lang-php")]
+pub use self::php::Php;
+#[cfg(feature = "lang-python")]
 pub use self::python::Python;
+#[cfg(feature = "lang-rust")]
 pub use self::rust::Rust;
+#[cfg(feature = "lang-swift")]
 pub use self::swift::Swift;
+#[cfg(feature = "lang-typescript")]
+pub use self::typescript::TypeScript;
+#[cfg(feature = "lang-verilog")]
+pub use self::verilog::Verilog;
+#[cfg(feature = "lang-vhdl")]
+pub use self::vhdl::Vhdl;
+#[cfg(feature = "lang-xml")]
+pub use self::xml::Xml;
+
+mod style;
+pub use self::style::Style;
 
 use core::fmt::Write as _;
 
+use alloc::format;
+
 use crate::fmt;
+use crate::tokens::{Case, CommentKind, IdentKind, ItemStr};
 use crate::Tokens;
 
 /// Trait to implement for language specialization.
@@ -64,6 +118,14 @@ where
         fmt::Indentation::Space(4)
     }
 
+    /// Provide the default number of indentation levels used for lines which
+    /// are continued because a group was broken up.
+    ///
+    /// See [fmt::Config::with_indent_continuation].
+    fn default_indent_continuation() -> usize {
+        1
+    }
+
     /// Start a string quote.
     fn open_quote(
         out: &mut fmt::Formatter<'_>,
@@ -117,11 +179,66 @@ where
         Ok(())
     }
 
+    /// Write a comment of the given kind.
+    ///
+    /// The default implementation writes C-like comments, with line comments
+    /// prefixed by `//` and block comments delimited by `/*` and `*/`. Since a
+    /// line comment extends to the end of the line, anything following it is
+    /// pushed onto the next line.
+    fn write_comment(out: &mut fmt::Formatter<'_>, kind: CommentKind, text: &str) -> fmt::Result {
+        match kind {
+            CommentKind::Line => write_line_comment(out, "//", text),
+            CommentKind::Block => write_block_comment(out, "/*", "*/", text),
+        }
+    }
+
+    /// Sanitize an identifier according to language convention.
+    ///
+    /// This is used when formatting [Ident] and [TypeName], and is typically
+    /// used to escape identifiers which collide with reserved keywords.
+    ///
+    /// The default implementation returns the identifier as-is.
+    ///
+    /// [Ident]: crate::tokens::Ident
+    /// [TypeName]: crate::tokens::TypeName
+    fn sanitize_ident(ident: ItemStr) -> ItemStr {
+        ident
+    }
+
+    /// The naming convention to apply to identifiers of the given kind.
+    ///
+    /// This is used when formatting an [Ident] which has a kind, and for
+    /// [TypeName]. Returning `None` leaves the identifier as-is, which is
+    /// what the default implementation does.
+    ///
+    /// [Ident]: crate::tokens::Ident
+    /// [TypeName]: crate::tokens::TypeName
+    fn ident_case(_config: &Self::Config, _kind: IdentKind) -> Option<Case> {
+        None
+    }
+
+    /// The formatting style options to use with the given configuration.
+    ///
+    /// The default implementation doesn't enable any style options.
+    fn style(_config: &Self::Config) -> Style {
+        Style::default()
+    }
+
     /// Performing string quoting according to language convention.
     fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
         out.write_str(input)
     }
 
+    /// Performing string quoting of the constant parts of a string which
+    /// contains evaluations, like `Hello ` in `$[str](Hello $(name))`.
+    ///
+    /// This is needed by languages where interpolated strings require
+    /// additional escaping. The default implementation defers to
+    /// [Lang::write_quoted].
+    fn write_quoted_eval(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+        Self::write_quoted(out, input)
+    }
+
     /// Write a file according to the specified language convention.
     fn format_file(
         tokens: &Tokens<Self>,
@@ -139,6 +256,54 @@ where
 /// [quoted string interpolation]: https://docs.rs/genco/0/genco/macro.quote.html#quoted-string-interpolation
 pub trait LangSupportsEval: Lang {}
 
+/// Trait implemented by the configuration of languages which place the
+/// generated file in a namespace-like scope, such as a package in Java or Go
+/// or a namespace in C# or PHP.
+///
+/// This allows file emitters which are generic over the language to construct
+/// and configure [Lang::Config].
+///
+/// # Examples
+///
+/// ```
+/// use genco::fmt;
+/// use genco::lang::LangConfig;
+/// use genco::prelude::*;
+///
+/// fn emit<L>(tokens: &Tokens<L>, namespace: &str) -> fmt::Result<Vec<String>>
+/// where
+///     L: Lang,
+///     L::Config: LangConfig,
+/// {
+///     let config = L::Config::default().with_namespace(namespace);
+///     let fmt = fmt::Config::from_lang::<L>();
+///
+///     let mut w = fmt::VecWriter::new();
+///     tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+///     Ok(w.into_vec())
+/// }
+///
+/// let java: java::Tokens = quote!(class Model {});
+/// let csharp: csharp::Tokens = quote!(class Model {});
+///
+/// assert_eq!(
+///     vec!["package com.example;", "", "class Model {}"],
+///     emit(&java, "com.example")?
+/// );
+///
+/// assert_eq!(
+///     vec!["namespace Example {", "    class Model {}", "}"],
+///     emit(&csharp, "Example")?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub trait LangConfig: Default {
+    /// Configure the namespace-like scope of the generated file.
+    fn with_namespace<N>(self, namespace: N) -> Self
+    where
+        N: Into<ItemStr>;
+}
+
 /// Dummy implementation for a language.
 impl Lang for () {
     type Config = ();
@@ -171,6 +336,96 @@ where
         config: &L::Config,
         format: &L::Format,
     ) -> fmt::Result;
+
+    /// Other language items this item depends on, which are collected as
+    /// imports through [Tokens::walk_imports] as if they were part of the
+    /// token stream.
+    ///
+    /// This allows an item to pull in the imports it needs for the text it
+    /// writes in [LangItem::format]. Dependencies can have dependencies of
+    /// their own.
+    ///
+    /// The default implementation has no dependencies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::fmt::Write as _;
+    ///
+    /// use genco::fmt;
+    /// use genco::prelude::*;
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    /// struct Import(&'static str);
+    ///
+    /// /// An identifier which is wrapped in a `Uuid`.
+    /// #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    /// struct TypedId(&'static str, Vec<Any>);
+    ///
+    /// genco::impl_lang! {
+    ///     MyLang {
+    ///         type Config = ();
+    ///         type Format = ();
+    ///         type Item = Any;
+    ///
+    ///         fn format_file(
+    ///             tokens: &Tokens<MyLang>,
+    ///             out: &mut fmt::Formatter<'_>,
+    ///             config: &Self::Config,
+    ///         ) -> fmt::Result {
+    ///             let mut header = Tokens::<MyLang>::new();
+    ///
+    ///             for import in tokens.walk_imports() {
+    ///                 if let Any::Import(import) = import {
+    ///                     header.push();
+    ///                     quote_in!(header => import $(import.0));
+    ///                 }
+    ///             }
+    ///
+    ///             header.line();
+    ///             header.format(out, config, &())?;
+    ///             tokens.format(out, config, &())
+    ///         }
+    ///     }
+    ///
+    ///     Import {
+    ///         fn format(&self, out: &mut fmt::Formatter<'_>, _: &(), _: &()) -> fmt::Result {
+    ///             out.write_str(self.0)
+    ///         }
+    ///     }
+    ///
+    ///     TypedId {
+    ///         fn format(&self, out: &mut fmt::Formatter<'_>, _: &(), _: &()) -> fmt::Result {
+    ///             write!(out, "{}(Uuid)", self.0)
+    ///         }
+    ///
+    ///         fn dependencies(&self) -> &[Any] {
+    ///             &self.1
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let user_id = TypedId("UserId", vec![Any::Import(Import("uuid.Uuid"))]);
+    ///
+    /// let t: Tokens<MyLang> = quote! {
+    ///     type $user_id
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import uuid.Uuid",
+    ///         "",
+    ///         "type UserId(Uuid)",
+    ///     ],
+    ///     t.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    ///
+    /// [Tokens::walk_imports]: crate::Tokens::walk_imports
+    fn dependencies(&self) -> &[L::Item] {
+        &[]
+    }
 }
 
 /// Escape the given string according to a C-family escape sequence.
@@ -218,3 +473,65 @@ pub fn c_family_write_quoted(out: &mut fmt::Formatter, input: &str) -> fmt::Resu
 
     Ok(())
 }
+
+/// Escape the given string according to a C-family escape sequence, while also
+/// doubling any braces (`{` and `}`).
+///
+/// This is how the constant parts of interpolated strings are escaped in
+/// languages like C# and Python.
+#[cfg(any(feature = "lang-csharp", feature = "lang-python"))]
+pub(crate) fn c_family_write_quoted_braces(out: &mut fmt::Formatter, input: &str) -> fmt::Result {
+    let mut last = 0;
+
+    for (n, c) in input.char_indices() {
+        if matches!(c, '{' | '}') {
+            c_family_write_quoted(out, &input[last..n])?;
+            out.write_char(c)?;
+            out.write_char(c)?;
+            last = n + 1;
+        }
+    }
+
+    c_family_write_quoted(out, &input[last..])
+}
+
+/// Write a block comment with the given delimiters.
+///
+/// Occurrences of the closing delimiter in the text are broken up by a space,
+/// so that they don't end the comment early.
+pub(crate) fn write_block_comment(
+    out: &mut fmt::Formatter<'_>,
+    open: &str,
+    close: &str,
+    text: &str,
+) -> fmt::Result {
+    out.write_str(open)?;
+
+    if !text.is_empty() {
+        let (head, tail) = close.split_at(1);
+
+        out.write_char(' ')?;
+        out.write_str(&text.replace(close, &format!("{head} {tail}")))?;
+        out.write_char(' ')?;
+    }
+
+    out.write_str(close)
+}
+
+/// Write a line comment with the given prefix, making sure that anything
+/// following it is written on the next line.
+pub(crate) fn write_line_comment(
+    out: &mut fmt::Formatter<'_>,
+    prefix: &str,
+    text: &str,
+) -> fmt::Result {
+    out.write_str(prefix)?;
+
+    if !text.is_empty() {
+        out.write_char(' ')?;
+        out.write_str(text)?;
+    }
+
+    out.end_line_comment();
+    Ok(())
+}
@@ -8,7 +8,7 @@ use alloc::string::ToString;
 use crate as genco;
 use crate::fmt;
 use crate::quote_in;
-use crate::tokens::ItemStr;
+use crate::tokens::{CommentKind, ItemStr};
 
 /// Tokens
 pub type Tokens = crate::Tokens<Nix>;
@@ -20,6 +20,13 @@ impl_lang! {
         type Format = Format;
         type Item = Import;
 
+        fn write_comment(out: &mut fmt::Formatter<'_>, kind: CommentKind, text: &str) -> fmt::Result {
+            match kind {
+                CommentKind::Line => crate::lang::write_line_comment(out, "#", text),
+                CommentKind::Block => crate::lang::write_block_comment(out, "/*", "*/", text),
+            }
+        }
+
         fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
             super::c_family_write_quoted(out, input)
         }
@@ -32,10 +39,10 @@ impl_lang! {
             let mut header = Tokens::new();
 
             if !config.scoped {
-                Self::arguments(&mut header, tokens);
+                Self::arguments(&mut header, out, tokens);
             }
-            Self::withs(&mut header, tokens);
-            Self::imports(&mut header, tokens);
+            Self::withs(&mut header, out, tokens);
+            Self::imports(&mut header, out, tokens);
             let format = Format::default();
             header.format(out, config, &format)?;
             tokens.format(out, config, &format)?;
@@ -58,6 +65,7 @@ impl_lang! {
 
 /// Import
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub enum Import {
     /// Argument
     Argument(ImportArgument),
@@ -71,10 +79,12 @@ pub enum Import {
 
 /// ImportArgument
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImportArgument(ItemStr);
 
 /// ImportInherit
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImportInherit {
     /// Path
     path: ItemStr,
@@ -84,6 +94,7 @@ pub struct ImportInherit {
 
 /// ImportVariable
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImportVariable {
     /// Name
     name: ItemStr,
@@ -93,6 +104,7 @@ pub struct ImportVariable {
 
 /// ImportWith
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImportWith {
     /// Argument
     argument: ItemStr,
@@ -105,7 +117,7 @@ pub struct ImportWith {
 pub struct Format {}
 
 /// Nix formatting configuration.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Config {
     scoped: bool,
 }
@@ -118,12 +130,13 @@ impl Config {
 }
 
 impl Nix {
-    fn arguments(out: &mut Tokens, tokens: &Tokens) {
+    fn arguments(out: &mut Tokens, formatter: &mut fmt::Formatter<'_>, tokens: &Tokens) {
         let mut arguments = BTreeSet::new();
 
         for imports in tokens.walk_imports() {
             match imports {
                 Import::Argument(argument) => {
+                    formatter.record_import::<Self>(imports);
                     arguments.insert(argument.0.to_string());
                 }
                 Import::Inherit(inherit) => {
@@ -143,6 +156,7 @@ impl Nix {
                                 }
                             }
                             Import::Argument(argument) => {
+                                formatter.record_import::<Self>(import);
                                 arguments.insert(argument.0.to_string());
                             }
                             _ => (),
@@ -177,11 +191,12 @@ impl Nix {
         out.line();
     }
 
-    fn withs(out: &mut Tokens, tokens: &Tokens) {
+    fn withs(out: &mut Tokens, formatter: &mut fmt::Formatter<'_>, tokens: &Tokens) {
         let mut withs = BTreeSet::new();
 
         for imports in tokens.walk_imports() {
             if let Import::With(with) = imports {
+                formatter.record_import::<Self>(imports);
                 withs.insert(&with.argument);
             }
         }
@@ -198,19 +213,25 @@ impl Nix {
         out.line();
     }
 
-    fn imports(out: &mut Tokens, tokens: &Tokens) {
+    fn imports(out: &mut Tokens, formatter: &mut fmt::Formatter<'_>, tokens: &Tokens) {
         let mut inherits = BTreeSet::new();
         let mut variables = BTreeSet::new();
 
         for imports in tokens.walk_imports() {
             match imports {
                 Import::Inherit(inherit) => {
+                    trace!(path = %inherit.path, name = %inherit.name, "collecting inherit");
+                    formatter.record_import::<Self>(imports);
                     inherits.insert((&inherit.path, &inherit.name));
                 }
                 Import::Variable(variable) => {
+                    trace!(name = %variable.name, "collecting variable");
+                    formatter.record_import::<Self>(imports);
+
                     let value = &variable.value;
                     for import in value.walk_imports() {
                         if let Import::Inherit(inherit) = import {
+                            formatter.record_import::<Self>(import);
                             inherits.insert((&inherit.path, &inherit.name));
                         }
                     }
@@ -0,0 +1,408 @@
+//! Specialization for PHP code generation.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! let user = php::import("App\\Models", "User");
+//! let post = php::import("App\\Models", "Post");
+//!
+//! let toks: php::Tokens = quote! {
+//!     function posts($user $$u): array {
+//!         return $post::where("user_id", $$u->id)->get();
+//!     }
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "<?php",
+//!         "",
+//!         "use App\\Models\\{Post, User};",
+//!         "",
+//!         "function posts(User $u): array {",
+//!         "    return Post::where(\"user_id\", $u->id)->get();",
+//!         "}",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok::<_, genco::fmt::Error>(())
+//! ```
+//!
+//! # String Quoting in PHP
+//!
+//! Strings are double-quoted by default, where `$` is escaped to prevent
+//! variables from being interpolated. Single-quoted strings can be produced
+//! through [single_quoted()].
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! let toks: php::Tokens = quote!("cost: $5\n");
+//! assert_eq!("\"cost: \\$5\\n\"", toks.to_string()?);
+//!
+//! let toks: php::Tokens = quote!($(php::single_quoted("it's $5")));
+//! assert_eq!("'it\\'s $5'", toks.to_string()?);
+//! # Ok::<_, genco::fmt::Error>(())
+//! ```
+
+use core::fmt::Write as _;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::{FormatInto, Item, ItemStr};
+
+const SEP: &str = "\\";
+
+/// Tokens container specialization for PHP.
+pub type Tokens = crate::Tokens<Php>;
+
+impl_lang! {
+    /// Language specialization for PHP.
+    pub Php {
+        type Config = Config;
+        type Format = Format;
+        type Item = Import;
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // Reference: https://www.php.net/manual/en/language.types.string.php#language.types.string.syntax.double
+
+            for c in input.chars() {
+                match c {
+                    '\n' => out.write_str("\\n")?,
+                    '\r' => out.write_str("\\r")?,
+                    '\t' => out.write_str("\\t")?,
+                    // vertical tab
+                    '\u{000b}' => out.write_str("\\v")?,
+                    // escape
+                    '\u{001b}' => out.write_str("\\e")?,
+                    // form feed
+                    '\u{000c}' => out.write_str("\\f")?,
+                    '\\' => out.write_str("\\\\")?,
+                    '"' => out.write_str("\\\"")?,
+                    '$' => out.write_str("\\$")?,
+                    c if !c.is_control() => out.write_char(c)?,
+                    c if (c as u32) < 0x80 => {
+                        write!(out, "\\x{:02x}", c as u32)?;
+                    }
+                    c => {
+                        write!(out, "\\u{{{:x}}}", c as u32)?;
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+
+            quote_in!(header => <?php);
+            header.line();
+
+            if let Some(namespace) = &config.namespace {
+                quote_in!(header => namespace $namespace;);
+                header.line();
+            }
+
+            Self::imports(&mut header, out, tokens, config);
+            let format = Format::default();
+            header.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            out.write_str(self.alias.as_ref().unwrap_or(&self.name))?;
+            Ok(())
+        }
+    }
+}
+
+/// The import of a PHP class, interface, or trait, like `use
+/// App\Models\User`.
+///
+/// Created through the [import()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+pub struct Import {
+    /// Namespace of the imported name.
+    namespace: ItemStr,
+    /// Name imported.
+    name: ItemStr,
+    /// Alias of the imported name.
+    alias: Option<ItemStr>,
+}
+
+impl Import {
+    /// Alias the imported name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let user = php::import("App\\Models", "User").with_alias("AppUser");
+    ///
+    /// let toks = quote!($user);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "<?php",
+    ///         "",
+    ///         "use App\\Models\\User as AppUser;",
+    ///         "",
+    ///         "AppUser",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_alias<A>(self, alias: A) -> Self
+    where
+        A: Into<ItemStr>,
+    {
+        Self {
+            alias: Some(alias.into()),
+            ..self
+        }
+    }
+}
+
+/// Format for PHP.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Config data for PHP.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    namespace: Option<ItemStr>,
+}
+
+impl Config {
+    /// Configure the namespace of the file.
+    ///
+    /// Names imported from the same namespace don't need a `use` statement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let user = php::import("App\\Models", "User");
+    /// let controller = php::import("App\\Http\\Controllers", "Controller");
+    ///
+    /// let toks: php::Tokens = quote! {
+    ///     class UserController extends $controller {
+    ///         public function show($user $$user) {}
+    ///     }
+    /// };
+    ///
+    /// let config = php::Config::default().with_namespace("App\\Http\\Controllers");
+    /// let fmt = fmt::Config::from_lang::<Php>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "<?php",
+    ///         "",
+    ///         "namespace App\\Http\\Controllers;",
+    ///         "",
+    ///         "use App\\Models\\User;",
+    ///         "",
+    ///         "class UserController extends Controller {",
+    ///         "    public function show(User $user) {}",
+    ///         "}",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_namespace<N>(self, namespace: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        Self {
+            namespace: Some(namespace.into()),
+        }
+    }
+}
+
+impl crate::lang::LangConfig for Config {
+    fn with_namespace<N>(self, namespace: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        self.with_namespace(namespace)
+    }
+}
+
+impl Php {
+    fn imports(
+        out: &mut Tokens,
+        formatter: &mut fmt::Formatter<'_>,
+        tokens: &Tokens,
+        config: &Config,
+    ) {
+        let mut namespaces = BTreeMap::<_, BTreeSet<_>>::new();
+
+        for import in tokens.walk_imports() {
+            if import.alias.is_none() && Some(&*import.namespace) == config.namespace.as_deref() {
+                trace!(namespace = %import.namespace, name = %import.name, "skipping import, same namespace as file");
+                continue;
+            }
+
+            trace!(namespace = %import.namespace, name = %import.name, alias = ?import.alias, "collecting import");
+
+            formatter.record_import::<Self>(import);
+
+            namespaces
+                .entry(&import.namespace)
+                .or_default()
+                .insert((&import.name, import.alias.as_ref()));
+        }
+
+        if namespaces.is_empty() {
+            return;
+        }
+
+        for (namespace, names) in namespaces {
+            let prefix = if namespace.is_empty() {
+                String::new()
+            } else {
+                alloc::format!("{namespace}{SEP}")
+            };
+
+            if names.len() == 1 {
+                for (name, alias) in names {
+                    quote_in!(*out => use $(&prefix)$(ref t => name_as(t, name, alias)););
+                }
+            } else {
+                quote_in! { *out =>
+                    use $(&prefix){$(for (name, alias) in names join (, ) => $(ref t => name_as(t, name, alias)))};
+                }
+            }
+
+            out.push();
+        }
+
+        out.line();
+
+        fn name_as(t: &mut Tokens, name: &ItemStr, alias: Option<&ItemStr>) {
+            t.append(name);
+
+            if let Some(alias) = alias {
+                quote_in!(*t => $[' ']as $alias);
+            }
+        }
+    }
+}
+
+/// Import a class, interface, or trait from the given namespace.
+///
+/// Names imported from the same namespace are grouped into a single `use`
+/// statement.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let carbon = php::import("Carbon", "Carbon");
+/// let request = php::import("Illuminate\\Http", "Request");
+/// let response = php::import("Illuminate\\Http", "Response");
+/// let date_time = php::import("", "DateTime");
+///
+/// let toks = quote! {
+///     $carbon
+///     $request
+///     $response
+///     $date_time
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "<?php",
+///         "",
+///         "use DateTime;",
+///         "use Carbon\\Carbon;",
+///         "use Illuminate\\Http\\{Request, Response};",
+///         "",
+///         "Carbon",
+///         "Request",
+///         "Response",
+///         "DateTime",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<N, T>(namespace: N, name: T) -> Import
+where
+    N: Into<ItemStr>,
+    T: Into<ItemStr>,
+{
+    Import {
+        namespace: namespace.into(),
+        name: name.into(),
+        alias: None,
+    }
+}
+
+/// Format a single-quoted string, where only `'` and `\` are escaped.
+///
+/// Note that single-quoted strings can't contain escape sequences, so control
+/// characters like newlines are written as-is.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: php::Tokens = quote!(echo $(php::single_quoted("C:\\dir\\ it's")));
+/// assert_eq!("echo 'C:\\\\dir\\\\ it\\'s'", toks.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn single_quoted<T>(input: T) -> SingleQuoted
+where
+    T: Into<ItemStr>,
+{
+    SingleQuoted(input.into())
+}
+
+/// A single-quoted string.
+///
+/// Created through the [single_quoted()] function.
+#[derive(Debug, Clone)]
+pub struct SingleQuoted(ItemStr);
+
+impl FormatInto<Php> for SingleQuoted {
+    fn format_into(self, tokens: &mut Tokens) {
+        let mut s = String::with_capacity(self.0.len() + 2);
+        s.push('\'');
+
+        for c in self.0.chars() {
+            match c {
+                '\'' => s.push_str("\\'"),
+                '\\' => s.push_str("\\\\"),
+                c => s.push(c),
+            }
+        }
+
+        s.push('\'');
+        tokens.item(Item::Literal(ItemStr::from(s)));
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::vec::Vec;
+
+use crate as genco;
+use crate::lang::python::{decorator, import};
+use crate::lang::Python;
+use crate::tokens::{FormatInto, ItemStr};
+use crate::Tokens;
+use crate::{quote, quote_in};
+
+/// The default value of a dataclass [Field].
+enum Default {
+    Value(Tokens<Python>),
+    Factory(Tokens<Python>),
+}
+
+/// A field in a [Dataclass].
+///
+/// This struct is created by the [field][super::field()] function.
+pub struct Field {
+    name: ItemStr,
+    ty: Tokens<Python>,
+    default: Option<Default>,
+}
+
+impl Field {
+    pub(super) fn new(name: ItemStr, ty: Tokens<Python>) -> Self {
+        Self {
+            name,
+            ty,
+            default: None,
+        }
+    }
+
+    /// Set the default value of the field, like `count: int = 0`.
+    pub fn with_default<V>(self, value: V) -> Self
+    where
+        V: FormatInto<Python>,
+    {
+        Self {
+            default: Some(Default::Value(quote!($value))),
+            ..self
+        }
+    }
+
+    /// Set a factory which constructs the default value of the field, like
+    /// `tags: list = field(default_factory=list)`.
+    ///
+    /// This is needed for mutable default values like lists and dictionaries,
+    /// and imports `field` from `dataclasses`.
+    pub fn with_default_factory<F>(self, factory: F) -> Self
+    where
+        F: FormatInto<Python>,
+    {
+        Self {
+            default: Some(Default::Factory(quote!($factory))),
+            ..self
+        }
+    }
+}
+
+/// Format a class decorated with `@dataclass`.
+///
+/// This struct is created by the [dataclass][super::dataclass()] function.
+pub struct Dataclass {
+    pub(super) name: ItemStr,
+    pub(super) fields: Vec<Field>,
+    pub(super) arguments: Vec<Tokens<Python>>,
+}
+
+impl Dataclass {
+    /// Add an argument to the `@dataclass` decorator, like `frozen=True`.
+    pub fn with_argument<A>(mut self, argument: A) -> Self
+    where
+        A: FormatInto<Python>,
+    {
+        self.arguments.push(quote!($argument));
+        self
+    }
+}
+
+impl FormatInto<Python> for Dataclass {
+    fn format_into(self, tokens: &mut Tokens<Python>) {
+        let dataclass = import("dataclasses", "dataclass");
+        let field = import("dataclasses", "field");
+
+        quote_in! { *tokens =>
+            $(decorator(dataclass, self.arguments))
+            class $(self.name):
+                $(if self.fields.is_empty() {
+                    pass
+                } else {
+                    $(for f in self.fields join ($['\r']) {
+                        $(f.name): $(f.ty)$(match f.default {
+                            Some(Default::Value(value)) => $[' ']= $value,
+                            Some(Default::Factory(factory)) => $[' ']= $(&field)(default_factory=$factory),
+                            None => (),
+                        })
+                    })
+                })
+        }
+    }
+}
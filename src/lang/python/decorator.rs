@@ -0,0 +1,30 @@
+use crate as genco;
+use crate::lang::Python;
+use crate::quote_in;
+use crate::tokens::FormatInto;
+use crate::Tokens;
+
+/// Format a decorator, like `@functools.cache` or `@app.route("/")`.
+///
+/// This struct is created by the [decorator][super::decorator()] function.
+pub struct Decorator<T, A>(pub(super) T, pub(super) A);
+
+impl<T, A> FormatInto<Python> for Decorator<T, A>
+where
+    T: FormatInto<Python>,
+    A: IntoIterator,
+    A::Item: FormatInto<Python>,
+{
+    fn format_into(self, tokens: &mut Tokens<Python>) {
+        let Decorator(target, args) = self;
+        let mut args = args.into_iter().peekable();
+
+        tokens.push();
+
+        quote_in! { *tokens =>
+            @$target$(if args.peek().is_some() => ($(for a in args join (, ) => $a)))
+        }
+
+        tokens.push();
+    }
+}
@@ -0,0 +1,923 @@
+//! Specialization for Python code generation.
+//!
+//! # Examples
+//!
+//! String quoting in Python:
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: python::Tokens = quote!("hello \n world");
+//! assert_eq!("\"hello \\n world\"", toks.to_string()?);
+//!
+//! let toks: python::Tokens = quote!($(quoted("hello \n world")));
+//! assert_eq!("\"hello \\n world\"", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # String Interpolation in Python
+//!
+//! Strings which evaluate expressions are written as [f-strings], where literal
+//! braces are escaped by doubling them. Anything in the evaluated expression is
+//! passed through as-is, so it can include things like [format specifiers] or
+//! the self-documenting `=`.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: python::Tokens = quote!($[str](Hello $(name) $[const]("{braces}")));
+//! assert_eq!("f\"Hello {name} {{braces}}\"", toks.to_string()?);
+//!
+//! let toks: python::Tokens = quote!($[str](Total: $(total:.2f), $(count=)));
+//! assert_eq!("f\"Total: {total:.2f}, {count=}\"", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [f-strings]: https://docs.python.org/3/reference/lexical_analysis.html#f-strings
+//! [format specifiers]: https://docs.python.org/3/library/string.html#formatspec
+
+mod dataclass;
+pub use self::dataclass::{Dataclass, Field};
+
+mod decorator;
+pub use self::decorator::Decorator;
+
+use core::fmt::Write as _;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate as genco;
+use crate::fmt;
+use crate::tokens::{Case, CommentKind, FormatInto, IdentKind, ItemStr, Naming};
+use crate::{quote, quote_in};
+
+/// Tokens container specialization for Python.
+pub type Tokens = crate::Tokens<Python>;
+
+impl crate::lang::LangSupportsEval for Python {}
+
+impl_lang! {
+    /// Language specialization for Python.
+    pub Python {
+        type Config = Config;
+        type Format = Format;
+        type Item = Any;
+
+        fn ident_case(config: &Self::Config, kind: IdentKind) -> Option<Case> {
+            config.naming.get(kind)
+        }
+
+        fn write_comment(out: &mut fmt::Formatter<'_>, _kind: CommentKind, text: &str) -> fmt::Result {
+            // NB: Python doesn't have block comments.
+            crate::lang::write_line_comment(out, "#", text)
+        }
+
+        fn sanitize_ident(ident: ItemStr) -> ItemStr {
+            // Following PEP 8, conflicts with keywords are resolved by
+            // appending a trailing underscore.
+            if KEYWORDS.contains(&&*ident) {
+                return format!("{ident}_").into();
+            }
+
+            ident
+        }
+
+        fn open_quote(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+            has_eval: bool,
+        ) -> fmt::Result {
+            if has_eval {
+                out.write_str("f\"")?;
+            } else {
+                out.write_char('"')?;
+            }
+
+            Ok(())
+        }
+
+        fn start_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_char('{')?;
+            Ok(())
+        }
+
+        fn end_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_char('}')?;
+            Ok(())
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            // From: https://docs.python.org/3/reference/lexical_analysis.html#string-and-bytes-literals
+            super::c_family_write_quoted(out, input)
+        }
+
+        fn write_quoted_eval(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            super::c_family_write_quoted_braces(out, input)
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            let mut format = Format::default();
+            Self::imports(&mut imports, out, tokens, &mut format);
+            imports.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, format: &Format) -> fmt::Result {
+            if self.function_local {
+                out.write_str(self.module.local_name())?;
+                out.write_str(SEP)?;
+                out.write_str(&self.name)?;
+                return Ok(());
+            }
+
+            match &self.module {
+                TypeModule::Qualified { module, alias } => {
+                    out.write_str(alias.as_ref().unwrap_or(module))?;
+                    out.write_str(SEP)?;
+                }
+                TypeModule::Unqualified { module } => {
+                    if format.conflicts.contains(&(module.to_string(), self.name.to_string())) {
+                        out.write_str(module)?;
+                        out.write_str(SEP)?;
+                        out.write_str(&self.name)?;
+                        return Ok(());
+                    }
+                }
+            }
+
+            let name = match &self.alias {
+                Some(alias) => alias,
+                None => &self.name,
+            };
+
+            out.write_str(name)?;
+            Ok(())
+        }
+    }
+
+    ImportModule {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            let module = match &self.alias {
+                Some(alias) => alias,
+                None => &self.module,
+            };
+
+            out.write_str(module)?;
+            Ok(())
+        }
+    }
+}
+
+/// Formatting state for python.
+#[derive(Debug, Default)]
+pub struct Format {
+    /// Names imported from a module which conflict with another imported
+    /// name, and are used qualified instead.
+    conflicts: BTreeSet<(String, String)>,
+}
+/// Configuration for python.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    naming: Naming,
+}
+
+impl_naming!(Config);
+
+static SEP: &str = ".";
+
+/// Reserved keywords in Python.
+///
+/// See: <https://docs.python.org/3/reference/lexical_analysis.html#keywords>
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+enum TypeModule {
+    Unqualified {
+        /// Name of imported module.
+        module: ItemStr,
+    },
+    Qualified {
+        /// Name of imported module.
+        module: ItemStr,
+        /// Alias of imported module.
+        alias: Option<ItemStr>,
+    },
+}
+
+impl TypeModule {
+    fn qualified(self) -> Self {
+        match self {
+            Self::Unqualified { module } => Self::Qualified {
+                module,
+                alias: None,
+            },
+            other => other,
+        }
+    }
+
+    /// The name the module is available as once it's imported.
+    fn local_name(&self) -> &ItemStr {
+        match self {
+            Self::Unqualified { module } => module,
+            Self::Qualified { module, alias } => alias.as_ref().unwrap_or(module),
+        }
+    }
+
+    fn with_alias<T>(self, alias: T) -> Self
+    where
+        T: Into<ItemStr>,
+    {
+        match self {
+            Self::Qualified { module, .. } | Self::Unqualified { module } => Self::Qualified {
+                module,
+                alias: Some(alias.into()),
+            },
+        }
+    }
+}
+
+/// The import of a Python name `from module import foo`.
+///
+/// Created through the [import()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+pub struct Import {
+    /// Module of the imported name.
+    module: TypeModule,
+    /// The name that was imported.
+    name: ItemStr,
+    /// Alias of the name imported.
+    alias: Option<ItemStr>,
+    /// The import is not hoisted to the top of the file.
+    function_local: bool,
+}
+
+impl Import {
+    /// Configure the importe name with the specified alias.
+    ///
+    /// This implised that the import is not qualified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let toks = quote! {
+    ///     $(python::import("collections", "namedtuple").with_alias("nt"))
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "from collections import namedtuple as nt",
+    ///         "",
+    ///         "nt",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_alias<T>(self, alias: T) -> Self
+    where
+        T: Into<ItemStr>,
+    {
+        Self {
+            alias: Some(alias.into()),
+            ..self
+        }
+    }
+
+    /// Indicate that the import is qualified (module prefixed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let toks = quote! {
+    ///     $(python::import("collections", "namedtuple").qualified())
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import collections",
+    ///         "",
+    ///         "collections.namedtuple",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn qualified(self) -> Self {
+        Self {
+            module: self.module.qualified(),
+            ..self
+        }
+    }
+
+    /// Configure the imported name with the specified alias.
+    ///
+    /// This implies that the import is qualified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let toks = quote! {
+    ///     $(python::import("collections", "namedtuple").with_module_alias("c"))
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import collections as c",
+    ///         "",
+    ///         "c.namedtuple",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_module_alias<T>(self, module_alias: T) -> Self
+    where
+        T: Into<ItemStr>,
+    {
+        Self {
+            module: self.module.with_alias(module_alias),
+            ..self
+        }
+    }
+
+    /// Indicate that the import is not added to the top of the file, which is
+    /// useful for optional or heavy dependencies that should only be imported
+    /// when they're used.
+    ///
+    /// The name is always written qualified with its module, and the statement
+    /// which imports the module where it's needed can be emitted through
+    /// [Import::statement].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let array = python::import("numpy", "array")
+    ///     .with_module_alias("np")
+    ///     .function_local();
+    /// let sqrt = python::import("math", "sqrt");
+    ///
+    /// let toks = quote! {
+    ///     def compute(values):
+    ///         $(array.statement())
+    ///         return $(&array)([$sqrt(v) for v in values])
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "from math import sqrt",
+    ///         "",
+    ///         "def compute(values):",
+    ///         "    import numpy as np",
+    ///         "    return np.array([sqrt(v) for v in values])",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn function_local(self) -> Self {
+        Self {
+            function_local: true,
+            ..self
+        }
+    }
+
+    /// Construct the statement which imports the module of this import, like
+    /// `import numpy as np`.
+    ///
+    /// This is written where it's used and is not added to the imports at the
+    /// top of the file, which is useful together with
+    /// [Import::function_local].
+    pub fn statement(&self) -> ImportStatement {
+        let (module, alias) = match &self.module {
+            TypeModule::Unqualified { module } => (module.clone(), None),
+            TypeModule::Qualified { module, alias } => (module.clone(), alias.clone()),
+        };
+
+        ImportStatement { module, alias }
+    }
+}
+
+/// A statement importing a module, like `import numpy as np`.
+///
+/// Created through [Import::statement].
+#[derive(Debug, Clone)]
+pub struct ImportStatement {
+    module: ItemStr,
+    alias: Option<ItemStr>,
+}
+
+impl FormatInto<Python> for ImportStatement {
+    fn format_into(self, tokens: &mut Tokens) {
+        quote_in!(*tokens => import $(self.module)$(if let Some(a) = self.alias => $[' ']as $a));
+    }
+}
+
+/// The import of a Python module `import module`.
+///
+/// Created through the [import_module()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImportModule {
+    /// Module of the imported name.
+    module: ItemStr,
+
+    /// Alias of module imported.
+    alias: Option<ItemStr>,
+}
+
+impl ImportModule {
+    /// Set alias for imported module.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let toks = quote! {
+    ///     $(python::import_module("collections").with_alias("c"))
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import collections as c",
+    ///         "",
+    ///         "c",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_alias<N>(self, new_alias: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        Self {
+            alias: Some(new_alias.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "ir")]
+impl serde::Serialize for Any {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        enum AnyRef<'a> {
+            Import(&'a Import),
+            ImportModule(&'a ImportModule),
+        }
+
+        match self {
+            Any::Import(import) => AnyRef::Import(import),
+            Any::ImportModule(import) => AnyRef::ImportModule(import),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "ir")]
+impl<'de> serde::Deserialize<'de> for Any {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        enum AnyOwned {
+            Import(Import),
+            ImportModule(ImportModule),
+        }
+
+        Ok(match AnyOwned::deserialize(deserializer)? {
+            AnyOwned::Import(import) => Any::Import(import),
+            AnyOwned::ImportModule(import) => Any::ImportModule(import),
+        })
+    }
+}
+
+impl Python {
+    fn imports(
+        out: &mut Tokens,
+        formatter: &mut fmt::Formatter<'_>,
+        tokens: &Tokens,
+        format: &mut Format,
+    ) {
+        let mut imported_from = BTreeMap::new();
+        let mut imports = BTreeSet::new();
+        let mut unqualified = BTreeSet::new();
+
+        for import in tokens.walk_imports() {
+            match import {
+                Any::Import(Import {
+                    function_local: true,
+                    ..
+                }) => {
+                    trace!(?import, "skipping import, imported locally in a function");
+                    continue;
+                }
+                Any::Import(Import {
+                    module,
+                    alias,
+                    name,
+                    ..
+                }) => match module {
+                    TypeModule::Qualified { module, alias } => {
+                        formatter.record_import::<Self>(import);
+                        imports.insert((module, alias));
+                    }
+                    TypeModule::Unqualified { module } => {
+                        unqualified.insert((module, name, alias, import));
+                    }
+                },
+                Any::ImportModule(ImportModule { module, alias }) => {
+                    formatter.record_import::<Self>(import);
+                    imports.insert((module, alias));
+                }
+            }
+        }
+
+        // Names which are bound by the imports, and what they refer to.
+        let mut names = BTreeMap::new();
+
+        for (module, name, alias, import) in unqualified {
+            let local = alias.as_ref().unwrap_or(name);
+
+            match names.get(local) {
+                Some(&(existing_module, existing_name))
+                    if (existing_module, existing_name) != (module, name) =>
+                {
+                    // The name is already bound by another import, so this one
+                    // is used qualified.
+                    trace!(%module, %name, "qualifying import, name already imported");
+
+                    let conflict = if existing_name == local {
+                        fmt::Conflict::Module(existing_module.to_string())
+                    } else {
+                        fmt::Conflict::Alias
+                    };
+
+                    formatter.warn(fmt::Warning::Qualified {
+                        module: module.to_string(),
+                        name: name.to_string(),
+                        conflict,
+                    });
+
+                    format
+                        .conflicts
+                        .insert((module.to_string(), name.to_string()));
+                    imports.insert((module, &None));
+                    continue;
+                }
+                _ => {
+                    trace!(%module, %name, ?alias, "collecting import");
+                    formatter.record_import::<Self>(import);
+                    names.insert(local, (module, name));
+                }
+            }
+
+            imported_from
+                .entry(module)
+                .or_insert_with(BTreeSet::new)
+                .insert((name, alias));
+        }
+
+        if imported_from.is_empty() && imports.is_empty() {
+            return;
+        }
+
+        for (module, imports) in imported_from {
+            out.push();
+
+            let imports = imports
+                .into_iter()
+                .map(|(name, alias)| quote!($name$(if let Some(a) = alias => $[' ']as $a)))
+                .collect::<Vec<_>>();
+
+            if imports.len() == 1 {
+                quote_in! {*out =>
+                    from $module import $(imports.into_iter().next())
+                }
+            } else {
+                quote_in! {*out =>
+                    from $module import $(for i in imports join (, ) => $i)
+                }
+            }
+        }
+
+        for (module, alias) in imports {
+            out.push();
+
+            quote_in! {*out =>
+                import $module$(if let Some(a) = alias => $[' ']as $a)
+            }
+        }
+
+        out.line();
+    }
+}
+
+/// The import of a Python name `from module import foo`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks = quote! {
+///     $(python::import("collections", "namedtuple").with_alias("nt"))
+///     $(python::import("collections", "namedtuple"))
+///     $(python::import("collections", "namedtuple").qualified())
+///     $(python::import("collections", "namedtuple").with_module_alias("c"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "from collections import namedtuple, namedtuple as nt",
+///         "import collections",
+///         "import collections as c",
+///         "",
+///         "nt",
+///         "namedtuple",
+///         "collections.namedtuple",
+///         "c.namedtuple",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
+/// # Conflicting names
+///
+/// If more than one module is imported which provides the same name, the
+/// first module in order is imported from and the others are imported as
+/// modules which qualifies their use.
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let a = python::import("collections", "OrderedDict");
+/// let b = python::import("ordered", "OrderedDict");
+/// let c = python::import("typing", "Dict").with_alias("OrderedDict");
+///
+/// let toks = quote! {
+///     $a
+///     $b
+///     $c
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "from collections import OrderedDict",
+///         "import ordered",
+///         "import typing",
+///         "",
+///         "OrderedDict",
+///         "ordered.OrderedDict",
+///         "typing.Dict",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<M, N>(module: M, name: N) -> Import
+where
+    M: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        module: TypeModule::Unqualified {
+            module: module.into(),
+        },
+        name: name.into(),
+        alias: None,
+        function_local: false,
+    }
+}
+
+/// The import of a Python module `import module`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks = quote! {
+///     $(python::import_module("collections"))
+///     $(python::import_module("collections").with_alias("c"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import collections",
+///         "import collections as c",
+///         "",
+///         "collections",
+///         "c",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import_module<M>(module: M) -> ImportModule
+where
+    M: Into<ItemStr>,
+{
+    ImportModule {
+        module: module.into(),
+        alias: None,
+    }
+}
+
+/// Format a decorator, like `@functools.cache` or `@app.route("/")`.
+///
+/// The target of the decorator is typically an [Import], which ensures that
+/// it is imported. If `args` is empty, the decorator is written without
+/// parenthesis.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let cache = python::import("functools", "cache");
+/// let route = python::import_module("flask");
+///
+/// let toks = quote! {
+///     $(python::decorator(cache, None::<&str>))
+///     $(python::decorator(quote!($route.route), [quote!("/"), quote!(methods=["GET"])]))
+///     def index():
+///         pass
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "from functools import cache",
+///         "import flask",
+///         "",
+///         "@cache",
+///         "@flask.route(\"/\", methods=[\"GET\"])",
+///         "def index():",
+///         "    pass",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn decorator<T, A>(target: T, args: A) -> Decorator<T, A>
+where
+    T: FormatInto<Python>,
+    A: IntoIterator,
+    A::Item: FormatInto<Python>,
+{
+    Decorator(target, args)
+}
+
+/// Format a class decorated with `@dataclass`, importing `dataclass` (and
+/// `field` if needed) from `dataclasses`.
+///
+/// Fields are constructed with [field()].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let optional = python::import("typing", "Optional");
+///
+/// let toks = quote! {
+///     $(python::dataclass("User", [
+///         python::field("name", "str"),
+///         python::field("email", quote!($(&optional)[str])).with_default("None"),
+///         python::field("tags", "list[str]").with_default_factory("list"),
+///     ]).with_argument("frozen=True"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "from dataclasses import dataclass, field",
+///         "from typing import Optional",
+///         "",
+///         "@dataclass(frozen=True)",
+///         "class User:",
+///         "    name: str",
+///         "    email: Optional[str] = None",
+///         "    tags: list[str] = field(default_factory=list)",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn dataclass<N, F>(name: N, fields: F) -> Dataclass
+where
+    N: Into<ItemStr>,
+    F: IntoIterator<Item = Field>,
+{
+    Dataclass {
+        name: name.into(),
+        fields: fields.into_iter().collect(),
+        arguments: Vec::new(),
+    }
+}
+
+/// Construct a field with a type hint for use in a [dataclass()].
+pub fn field<N, T>(name: N, ty: T) -> Field
+where
+    N: Into<ItemStr>,
+    T: FormatInto<Python>,
+{
+    Field::new(name.into(), quote!($ty))
+}
+
+/// Render an index file like an `__init__.py`, which re-exports the symbols
+/// declared in the given modules.
+///
+/// Modules without any declarations are imported as a whole. The declarations
+/// of a module are typically collected through
+/// [Tokens::declarations][crate::Tokens::declarations].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let index = python::index([
+///     ("user", vec!["User", "UserId"]),
+///     ("util", vec![]),
+/// ]);
+///
+/// assert_eq!(
+///     vec![
+///         "from .user import User, UserId",
+///         "from . import util",
+///     ],
+///     index.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn index<I, M, D>(modules: I) -> Tokens
+where
+    I: IntoIterator<Item = (M, D)>,
+    M: Into<ItemStr>,
+    D: IntoIterator,
+    D::Item: Into<ItemStr>,
+{
+    let mut t = Tokens::new();
+
+    for (module, declarations) in modules {
+        let module = module.into();
+        let mut declarations = declarations.into_iter().map(Into::into).peekable();
+
+        if declarations.peek().is_none() {
+            quote_in!(t => from . import $module);
+        } else {
+            quote_in!(t => from .$module import $(for d in declarations join (, ) => $d));
+        }
+
+        t.push();
+    }
+
+    t
+}
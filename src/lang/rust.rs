@@ -41,12 +41,29 @@
 use core::fmt::Write as _;
 
 use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 use crate::fmt;
-use crate::tokens::ItemStr;
+use crate::lang::Style;
+use crate::tokens::{
+    separated_with_style, static_literal, Case, FormatInto, IdentKind, Item, ItemStr, Naming,
+};
 
 const SEP: &str = "::";
 
+/// Reserved keywords in Rust.
+///
+/// See: <https://doc.rust-lang.org/reference/keywords.html>
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
 /// Tokens container specialization for Rust.
 pub type Tokens = crate::Tokens<Rust>;
 
@@ -57,6 +74,23 @@ impl_lang! {
         type Format = Format;
         type Item = Import;
 
+        fn ident_case(config: &Self::Config, kind: IdentKind) -> Option<Case> {
+            config.naming.get(kind)
+        }
+
+        fn style(config: &Self::Config) -> Style {
+            config.style
+        }
+
+        fn sanitize_ident(ident: ItemStr) -> ItemStr {
+            match &*ident {
+                // Keywords which can't be used as raw identifiers.
+                "crate" | "self" | "Self" | "super" => format!("{ident}_").into(),
+                s if KEYWORDS.contains(&s) => format!("r#{ident}").into(),
+                _ => ident,
+            }
+        }
+
         fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
             // From: https://doc.rust-lang.org/reference/tokens.html#literals
 
@@ -94,9 +128,20 @@ impl_lang! {
             config: &Self::Config,
         ) -> fmt::Result {
             let mut imports: Tokens = Tokens::new();
-            Self::imports(&mut imports, config, tokens);
 
-            let format = Format::default();
+            if let Some(header) = &config.header {
+                imports.append(header);
+                imports.line();
+            }
+
+            let mut format = Format::default();
+            Self::imports(&mut imports, out, config, tokens, &mut format);
+
+            if let Some(after_imports) = &config.after_imports {
+                imports.append(after_imports);
+                imports.line();
+            }
+
             imports.format(out, config, &format)?;
             tokens.format(out, config, &format)?;
             Ok(())
@@ -104,7 +149,26 @@ impl_lang! {
     }
 
     Import {
-        fn format(&self, out: &mut fmt::Formatter<'_>, config: &Config, _: &Format) -> fmt::Result {
+        fn format(&self, out: &mut fmt::Formatter<'_>, config: &Config, format: &Format) -> fmt::Result {
+            if self.dyn_trait && config.edition >= Edition::E2018 {
+                out.write_str("dyn ")?;
+            }
+
+            if let Some(renamed) = self.renamed(config, format) {
+                match renamed {
+                    Renamed::Qualified => {
+                        if let Module::Module { module, .. } = &self.module {
+                            self.write_prefixed(out, module)?;
+                        }
+                    }
+                    Renamed::Alias(alias) => {
+                        out.write_str(alias)?;
+                    }
+                }
+
+                return Ok(());
+            }
+
             match &self.module {
                 Module::Module {
                     import: Some(ImportMode::Direct),
@@ -132,21 +196,91 @@ impl_lang! {
                     out.write_str(SEP)?;
                     out.write_str(&self.name)?;
                 }
+                Module::Glob { .. } => {
+                    self.write_direct(out)?;
+                }
             }
 
             Ok(())
         }
+
+        fn dependencies(&self) -> &[Import] {
+            &self.dependencies
+        }
     }
 }
 
 /// Format state for Rust.
 #[derive(Debug, Default)]
-pub struct Format {}
+pub struct Format {
+    /// Imports, by module and name, whose name conflicts with an import from
+    /// another module and how they're referred to instead.
+    renamed: BTreeMap<(String, String), Renamed>,
+}
+
+/// How an import with a conflicting name is referred to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Renamed {
+    /// Through its module, like `toml::Value`.
+    Qualified,
+    /// Through a synthesized alias, like `TomlValue`.
+    Alias(String),
+}
 
 /// Language configuration for Rust.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Config {
     default_import: ImportMode,
+    conflict_mode: ConflictMode,
+    edition: Edition,
+    naming: Naming,
+    style: Style,
+    header: Option<Tokens>,
+    after_imports: Option<Tokens>,
+}
+
+impl_naming! {
+    Config,
+    /// Naming conventions are applied before keywords are escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    /// use genco::tokens::{Case, Ident, TypeName};
+    ///
+    /// let ty = TypeName::new("HTTPRequest")?;
+    /// let field = Ident::new("requestId")?.field();
+    /// let keyword = Ident::new("Type")?.field();
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     struct $ty {
+    ///         $field: u64,
+    ///         $keyword: u32,
+    ///     }
+    /// };
+    ///
+    /// let config = rust::Config::default()
+    ///     .with_field_naming(Case::Snake)
+    ///     .with_type_naming(Case::Pascal);
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "struct HttpRequest {",
+    ///         "    request_id: u64,",
+    ///         "    r#type: u32,",
+    ///         "}",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
 }
 
 impl Config {
@@ -154,7 +288,242 @@ impl Config {
     ///
     /// See [Import] for more details.
     pub fn with_default_import(self, default_import: ImportMode) -> Self {
-        Self { default_import }
+        Self {
+            default_import,
+            ..self
+        }
+    }
+
+    /// Configure how imports of the same name from different modules are
+    /// resolved.
+    ///
+    /// Conflicts are resolved deterministically: the import from the module
+    /// which sorts first keeps its name, while the others are referred to as
+    /// configured. Explicitly aliased imports are never renamed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let json = rust::import("serde_json", "Value");
+    /// let toml = rust::import("toml", "Value");
+    /// let yaml = rust::import("serde::yaml", "Value");
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     fn convert(a: $json, b: $toml, c: $yaml) {}
+    /// };
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use serde::yaml::Value;",
+    ///         "",
+    ///         "fn convert(a: serde_json::Value, b: toml::Value, c: Value) {}",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    ///
+    /// let config = rust::Config::default().with_conflict_mode(rust::ConflictMode::Alias);
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use serde::yaml::Value;",
+    ///         "use serde_json::Value as SerdeJsonValue;",
+    ///         "use toml::Value as TomlValue;",
+    ///         "",
+    ///         "fn convert(a: SerdeJsonValue, b: TomlValue, c: Value) {}",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_conflict_mode(self, conflict_mode: ConflictMode) -> Self {
+        Self {
+            conflict_mode,
+            ..self
+        }
+    }
+
+    /// Configure the edition of the generated code, which defaults to
+    /// [Edition::E2018].
+    ///
+    /// * In [Edition::E2015], an `extern crate` declaration is written for
+    ///   every crate other than `std` which is imported from, and trait
+    ///   objects created through [Import::dyn_trait] are written without
+    ///   `dyn`.
+    /// * From [Edition::E2021], names which are part of the prelude of the
+    ///   edition, like `TryFrom` and `FromIterator`, are not imported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let serialize = rust::import("serde", "Serialize");
+    /// let debug = rust::import("std::fmt", "Debug").dyn_trait();
+    /// let try_from = rust::import("std::convert", "TryFrom");
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     fn convert<T: $serialize + $(&try_from)<u32>>(value: Box<$debug>) -> T {
+    ///         T::try_from(42).unwrap()
+    ///     }
+    /// };
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    ///
+    /// let config = rust::Config::default().with_edition(rust::Edition::E2015);
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "extern crate serde;",
+    ///         "",
+    ///         "use serde::Serialize;",
+    ///         "use std::convert::TryFrom;",
+    ///         "use std::fmt::Debug;",
+    ///         "",
+    ///         "fn convert<T: Serialize + TryFrom<u32>>(value: Box<Debug>) -> T {",
+    ///         "    T::try_from(42).unwrap()",
+    ///         "}",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    ///
+    /// let config = rust::Config::default().with_edition(rust::Edition::E2021);
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use serde::Serialize;",
+    ///         "use std::fmt::Debug;",
+    ///         "",
+    ///         "fn convert<T: Serialize + TryFrom<u32>>(value: Box<dyn Debug>) -> T {",
+    ///         "    T::try_from(42).unwrap()",
+    ///         "}",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_edition(self, edition: Edition) -> Self {
+        Self { edition, ..self }
+    }
+
+    /// Configure tokens to write at the top of the file, before any imports.
+    ///
+    /// This is typically used for license headers or banners for generated
+    /// files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let map = rust::import("std::collections", "HashMap");
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     pub type Map = $map<u32, u32>;
+    /// };
+    ///
+    /// let config = rust::Config::default()
+    ///     .with_header(quote! {
+    ///         $("// SPDX-License-Identifier: MIT")
+    ///         $("// DO NOT EDIT: This file is generated.")
+    ///     })
+    ///     .with_after_imports(quote!(const _: () = ();));
+    ///
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "// SPDX-License-Identifier: MIT",
+    ///         "// DO NOT EDIT: This file is generated.",
+    ///         "",
+    ///         "use std::collections::HashMap;",
+    ///         "",
+    ///         "const _: () = ();",
+    ///         "",
+    ///         "pub type Map = HashMap<u32, u32>;",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_header(self, header: Tokens) -> Self {
+        Self {
+            header: Some(header),
+            ..self
+        }
+    }
+
+    /// Configure tokens to write after the imports of the file, before the
+    /// file contents.
+    ///
+    /// See [Config::with_header] for an example.
+    pub fn with_after_imports(self, after_imports: Tokens) -> Self {
+        Self {
+            after_imports: Some(after_imports),
+            ..self
+        }
+    }
+
+    /// Write a trailing comma after the last item of lists which are broken
+    /// onto multiple lines, like rustfmt does.
+    ///
+    /// This affects lists written through [separated_with_style], as well as
+    /// [generics()] and [where_clause()].
+    ///
+    /// [separated_with_style]: crate::tokens::separated_with_style()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let toks: rust::Tokens = quote! {
+    ///     fn foo$(rust::generics(["First", "Second", "Third"]))() {}
+    /// };
+    ///
+    /// let config = rust::Config::default().with_trailing_commas(true);
+    /// let fmt = fmt::Config::from_lang::<Rust>().with_max_width(20);
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "fn foo<",
+    ///         "    First,",
+    ///         "    Second,",
+    ///         "    Third,",
+    ///         ">() {}",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_trailing_commas(self, trailing_commas: bool) -> Self {
+        Self {
+            style: self.style.with_trailing_commas(trailing_commas),
+            ..self
+        }
     }
 }
 
@@ -162,12 +531,19 @@ impl Default for Config {
     fn default() -> Self {
         Config {
             default_import: ImportMode::Direct,
+            conflict_mode: ConflictMode::Qualified,
+            edition: Edition::E2018,
+            naming: Naming::default(),
+            style: Style::default(),
+            header: None,
+            after_imports: None,
         }
     }
 }
 
 /// The import mode to use when generating import statements.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub enum ImportMode {
     /// Import names without a module prefix.
     ///
@@ -181,7 +557,57 @@ pub enum ImportMode {
     Qualified,
 }
 
+/// How imports of the same name from different modules are resolved.
+///
+/// See [Config::with_conflict_mode].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConflictMode {
+    /// Refer to the conflicting type through its module, like `toml::Value`.
+    Qualified,
+    /// Import the conflicting type with an alias made up of its module and
+    /// its name, like `use toml::Value as TomlValue`.
+    ///
+    /// Falls back to [ConflictMode::Qualified] if the alias is already in
+    /// use.
+    Alias,
+}
+
+/// An edition of Rust.
+///
+/// See [Config::with_edition].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Edition {
+    /// The 2015 edition.
+    E2015,
+    /// The 2018 edition.
+    E2018,
+    /// The 2021 edition.
+    E2021,
+    /// The 2024 edition.
+    E2024,
+}
+
+impl Edition {
+    /// Test if the given name is brought into scope by the prelude of the
+    /// edition.
+    fn is_prelude(self, module: &str, name: &str) -> bool {
+        let module = match module.split_once(SEP) {
+            Some(("std" | "core", module)) => module,
+            _ => return false,
+        };
+
+        match (module, name) {
+            ("convert", "TryFrom" | "TryInto") | ("iter", "FromIterator") => self >= Self::E2021,
+            ("future", "Future" | "IntoFuture") => self >= Self::E2024,
+            _ => false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::enum_variant_names)]
 enum Module {
     /// Type imported directly from module with the specified mode.
     Module {
@@ -190,6 +616,8 @@ enum Module {
     },
     /// Prefixed with an alias.
     Aliased { module: ItemStr, alias: ItemStr },
+    /// Brought into scope through a glob import of the module.
+    Glob { module: ItemStr },
 }
 
 impl Module {
@@ -200,7 +628,7 @@ impl Module {
         A: Into<ItemStr>,
     {
         match self {
-            Self::Module { module, .. } => Self::Aliased {
+            Self::Module { module, .. } | Self::Glob { module } => Self::Aliased {
                 module,
                 alias: alias.into(),
             },
@@ -212,7 +640,7 @@ impl Module {
     /// module. Set the correct import here.
     fn into_aliased(self) -> Self {
         match self {
-            Self::Module { module, .. } => Self::Module {
+            Self::Module { module, .. } | Self::Glob { module } => Self::Module {
                 import: Some(ImportMode::Direct),
                 module,
             },
@@ -225,7 +653,7 @@ impl Module {
     /// See [ImportMode::Direct].
     fn direct(self) -> Self {
         match self {
-            Self::Module { module, .. } => Self::Module {
+            Self::Module { module, .. } | Self::Glob { module } => Self::Module {
                 module,
                 import: Some(ImportMode::Direct),
             },
@@ -238,7 +666,7 @@ impl Module {
     /// See [ImportMode::Qualified].
     fn qualified(self) -> Self {
         match self {
-            Self::Module { module, .. } => Self::Module {
+            Self::Module { module, .. } | Self::Glob { module } => Self::Module {
                 module,
                 import: Some(ImportMode::Qualified),
             },
@@ -251,6 +679,7 @@ impl Module {
 ///
 /// Created through the [import()] function.
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub struct Import {
     /// How the type is imported.
     module: Module,
@@ -258,6 +687,12 @@ pub struct Import {
     name: ItemStr,
     /// Alias to use for the type.
     alias: Option<ItemStr>,
+    /// Other imports needed wherever this import is used.
+    dependencies: Vec<Import>,
+    /// Conditional compilation predicate for the import.
+    cfg: Option<ItemStr>,
+    /// If the import is a trait which is used as a trait object.
+    dyn_trait: bool,
 }
 
 impl Import {
@@ -387,6 +822,149 @@ impl Import {
         }
     }
 
+    /// Add an import which is needed wherever this import is used, even
+    /// though it's not part of the token stream.
+    ///
+    /// This is useful for wrapper types, like a type alias which is defined in
+    /// terms of another type which also needs to be in scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let uuid = rust::import("uuid", "Uuid");
+    /// let db_id = rust::import("crate::db", "DbId").with_dependency(uuid);
+    ///
+    /// let toks = quote! {
+    ///     fn parse_id(s: &str) -> Option<$db_id> {
+    ///         Uuid::parse_str(s).ok().map(DbId)
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use crate::db::DbId;",
+    ///         "use uuid::Uuid;",
+    ///         "",
+    ///         "fn parse_id(s: &str) -> Option<DbId> {",
+    ///         "    Uuid::parse_str(s).ok().map(DbId)",
+    ///         "}",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_dependency(mut self, dependency: Import) -> Self {
+        self.dependencies.push(dependency);
+        self
+    }
+
+    /// Only import the type if the given conditional compilation predicate
+    /// holds, like `feature = "std"`.
+    ///
+    /// The generated `use` statement is prefixed with a corresponding
+    /// `#[cfg(...)]` attribute, and imports with different predicates are
+    /// never grouped together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let read = rust::import("std::io", "Read").with_cfg("feature = \"std\"");
+    /// let write = rust::import("std::io", "Write").with_cfg("feature = \"std\"");
+    /// let cursor = rust::import("std::io", "Cursor");
+    ///
+    /// let toks = quote! {
+    ///     $read
+    ///     $write
+    ///     $cursor
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use std::io::Cursor;",
+    ///         "#[cfg(feature = \"std\")]",
+    ///         "use std::io::{Read, Write};",
+    ///         "",
+    ///         "Read",
+    ///         "Write",
+    ///         "Cursor",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_cfg<C>(self, cfg: C) -> Self
+    where
+        C: Into<ItemStr>,
+    {
+        Self {
+            cfg: Some(cfg.into()),
+            ..self
+        }
+    }
+
+    /// Use the imported trait as a trait object, which is prefixed with `dyn`
+    /// unless the configured [Edition] is [Edition::E2015].
+    ///
+    /// See [Config::with_edition] for an example.
+    pub fn dyn_trait(self) -> Self {
+        Self {
+            dyn_trait: true,
+            ..self
+        }
+    }
+
+    /// How the import is referred to if its name conflicts with another
+    /// import.
+    fn renamed<'a>(&self, config: &Config, format: &'a Format) -> Option<&'a Renamed> {
+        if format.renamed.is_empty() {
+            return None;
+        }
+
+        let module = self.direct_module(config)?;
+
+        format
+            .renamed
+            .get(&(module.to_string(), self.name.to_string()))
+    }
+
+    /// The name of the external crate the import is from, if any.
+    fn crate_name(&self) -> Option<&str> {
+        let module = match &self.module {
+            Module::Module { module, .. }
+            | Module::Aliased { module, .. }
+            | Module::Glob { module } => module,
+        };
+
+        match module.split(SEP).next()? {
+            "" | "std" | "crate" | "self" | "super" => None,
+            name => Some(name),
+        }
+    }
+
+    /// The module of the import if its name is imported directly into scope
+    /// without an alias.
+    fn direct_module(&self, config: &Config) -> Option<&ItemStr> {
+        if self.alias.is_some() {
+            return None;
+        }
+
+        match &self.module {
+            Module::Module {
+                module,
+                import: Some(ImportMode::Direct),
+            } => Some(module),
+            Module::Module {
+                module,
+                import: None,
+            } if config.default_import == ImportMode::Direct => Some(module),
+            _ => None,
+        }
+    }
+
     /// Write the direct name of the type.
     fn write_direct(&self, out: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(alias) = &self.alias {
@@ -409,34 +987,154 @@ impl Import {
 }
 
 impl Rust {
-    fn imports(out: &mut Tokens, config: &Config, tokens: &Tokens) {
-        use alloc::collections::btree_set;
+    /// Decide how imports whose names conflict with imports from other
+    /// modules are referred to.
+    fn resolve_conflicts(
+        formatter: &mut fmt::Formatter<'_>,
+        config: &Config,
+        imports: &VecDeque<&Import>,
+        format: &mut Format,
+    ) {
+        // Modules from which each name is imported directly.
+        let mut names = BTreeMap::<&str, BTreeSet<&str>>::new();
+        // Names which are brought into scope through explicit aliases.
+        let mut taken = BTreeSet::<String>::new();
 
-        use crate as genco;
-        use crate::quote_in;
+        for import in imports {
+            if let Some(alias) = &import.alias {
+                taken.insert(alias.to_string());
+                continue;
+            }
 
-        let mut modules = BTreeMap::<&ItemStr, Import>::new();
+            if let Some(module) = import.direct_module(config) {
+                names.entry(&import.name).or_default().insert(module);
+            }
+        }
 
-        let mut queue = VecDeque::new();
+        for (name, modules) in &names {
+            // The module which sorts first keeps the name, unless it's taken
+            // by an alias.
+            let keep = usize::from(!taken.contains(*name));
 
-        for import in tokens.walk_imports() {
+            for module in modules.iter().skip(keep) {
+                let renamed = match config.conflict_mode {
+                    ConflictMode::Qualified => Renamed::Qualified,
+                    ConflictMode::Alias => {
+                        let prefix = module.rsplit(SEP).next().unwrap_or(module);
+                        let alias = format!("{}{name}", Case::Pascal.convert(prefix));
+
+                        if taken.contains(&alias) || names.contains_key(alias.as_str()) {
+                            Renamed::Qualified
+                        } else {
+                            taken.insert(alias.clone());
+                            Renamed::Alias(alias)
+                        }
+                    }
+                };
+
+                trace!(module, name, ?renamed, "renaming conflicting import");
+
+                let conflict = match modules.iter().next() {
+                    Some(first) if keep == 1 => fmt::Conflict::Module(first.to_string()),
+                    _ => fmt::Conflict::Alias,
+                };
+
+                formatter.warn(match &renamed {
+                    Renamed::Qualified => fmt::Warning::Qualified {
+                        module: module.to_string(),
+                        name: name.to_string(),
+                        conflict,
+                    },
+                    Renamed::Alias(alias) => fmt::Warning::Aliased {
+                        module: module.to_string(),
+                        name: name.to_string(),
+                        alias: alias.clone(),
+                        conflict,
+                    },
+                });
+
+                format
+                    .renamed
+                    .insert((module.to_string(), name.to_string()), renamed);
+            }
+        }
+    }
+
+    fn imports(
+        out: &mut Tokens,
+        formatter: &mut fmt::Formatter<'_>,
+        config: &Config,
+        tokens: &Tokens,
+        format: &mut Format,
+    ) {
+        use alloc::collections::btree_set;
+
+        use crate as genco;
+        use crate::quote_in;
+
+        // Imports are grouped by their predicate and then by module, so that
+        // unconditional imports come first.
+        let mut modules = BTreeMap::<(Option<&ItemStr>, &ItemStr), Import>::new();
+
+        let mut queue = VecDeque::new();
+
+        for import in tokens.walk_imports() {
             queue.push_back(import);
         }
 
+        Self::resolve_conflicts(formatter, config, &queue, format);
+
+        // Crates which need an `extern crate` declaration, and the predicates
+        // of the imports they're used by.
+        let mut crates = BTreeMap::<&str, BTreeSet<Option<&ItemStr>>>::new();
+
         while let Some(import) = queue.pop_front() {
+            if config.edition == Edition::E2015 {
+                if let Some(name) = import.crate_name() {
+                    crates.entry(name).or_default().insert(import.cfg.as_ref());
+                }
+            }
+
+            if let Some(module) = import.direct_module(config) {
+                if config.edition.is_prelude(module, &import.name) {
+                    continue;
+                }
+            }
+
+            if let Some(renamed) = import.renamed(config, format) {
+                if let Module::Module { module, .. } = &import.module {
+                    let module = modules.entry((import.cfg.as_ref(), module)).or_default();
+
+                    match renamed {
+                        Renamed::Qualified => {
+                            module.self_import = true;
+                        }
+                        Renamed::Alias(alias) => {
+                            formatter.record_import::<Self>(import);
+                            module.names.insert((&import.name, Some(alias.as_str())));
+                        }
+                    }
+                }
+
+                continue;
+            }
+
+            trace!(?import.module, name = %import.name, "collecting import");
+            formatter.record_import::<Self>(import);
+
             match &import.module {
                 Module::Module {
                     module,
                     import: Some(ImportMode::Direct),
                 } => {
-                    let module = modules.entry(module).or_default();
-                    module.names.insert((&import.name, import.alias.as_ref()));
+                    let module = modules.entry((import.cfg.as_ref(), module)).or_default();
+                    module.names.insert((&import.name, import.alias.as_deref()));
                 }
                 Module::Module {
                     module,
                     import: Some(ImportMode::Qualified),
                 } => {
-                    let module = modules.entry(module).or_default();
+                    let module = modules.entry((import.cfg.as_ref(), module)).or_default();
                     module.self_import = true;
                 }
                 Module::Module {
@@ -444,30 +1142,59 @@ impl Rust {
                     import: None,
                 } => match config.default_import {
                     ImportMode::Direct => {
-                        let module = modules.entry(module).or_default();
-                        module.names.insert((&import.name, import.alias.as_ref()));
+                        let module = modules.entry((import.cfg.as_ref(), module)).or_default();
+                        module.names.insert((&import.name, import.alias.as_deref()));
                     }
                     ImportMode::Qualified => {
-                        let module = modules.entry(module).or_default();
+                        let module = modules.entry((import.cfg.as_ref(), module)).or_default();
                         module.self_import = true;
                     }
                 },
                 Module::Aliased { module, alias } => {
-                    let module = modules.entry(module).or_default();
+                    let module = modules.entry((import.cfg.as_ref(), module)).or_default();
                     module.self_aliases.insert(alias);
                 }
+                Module::Glob { module } => {
+                    let module = modules.entry((import.cfg.as_ref(), module)).or_default();
+                    module.glob = true;
+                }
             }
         }
 
+        for (name, cfgs) in &crates {
+            // NB: A crate which is used unconditionally is declared once.
+            if cfgs.contains(&None) {
+                quote_in!(*out => extern crate $(*name););
+                out.push();
+                continue;
+            }
+
+            for cfg in cfgs.iter().flatten() {
+                quote_in!(*out => #[cfg($(*cfg))]);
+                out.push();
+                quote_in!(*out => extern crate $(*name););
+                out.push();
+            }
+        }
+
+        if !crates.is_empty() {
+            out.line();
+        }
+
         let mut has_any = false;
 
-        for (m, module) in modules {
+        for ((cfg, m), module) in modules {
             let mut render = module.iter(m);
 
             if let Some(first) = render.next() {
                 has_any = true;
                 out.push();
 
+                if let Some(cfg) = cfg {
+                    quote_in!(*out => #[cfg($cfg)]);
+                    out.push();
+                }
+
                 // render as a group if there's more than one thing being
                 // imported.
                 if let Some(second) = render.next() {
@@ -489,6 +1216,9 @@ impl Rust {
                         RenderItem::SelfAlias { alias } => {
                             quote_in!(*out => use $m as $alias;);
                         }
+                        RenderItem::Glob => {
+                            quote_in!(*out => use $m::*;);
+                        }
                         RenderItem::Name {
                             name,
                             alias: Some(alias),
@@ -516,16 +1246,24 @@ impl Rust {
             self_import: bool,
             /// Aliases for the own module.
             self_aliases: BTreeSet<&'a ItemStr>,
+            /// If everything in the module is imported through a glob.
+            glob: bool,
             /// Set of imported names.
-            names: BTreeSet<(&'a ItemStr, Option<&'a ItemStr>)>,
+            names: BTreeSet<(&'a ItemStr, Option<&'a str>)>,
         }
 
         impl<'a> Import<'a> {
-            fn iter(self, module: &'a str) -> ImportedIter<'a> {
+            fn iter(mut self, module: &'a str) -> ImportedIter<'a> {
+                // Names which are not aliased are already covered by the glob.
+                if self.glob {
+                    self.names.retain(|(_, alias)| alias.is_some());
+                }
+
                 ImportedIter {
                     module,
                     self_import: self.self_import,
                     self_aliases: self.self_aliases.into_iter(),
+                    glob: self.glob,
                     names: self.names.into_iter(),
                 }
             }
@@ -535,7 +1273,8 @@ impl Rust {
             module: &'a str,
             self_import: bool,
             self_aliases: btree_set::IntoIter<&'a ItemStr>,
-            names: btree_set::IntoIter<(&'a ItemStr, Option<&'a ItemStr>)>,
+            glob: bool,
+            names: btree_set::IntoIter<(&'a ItemStr, Option<&'a str>)>,
         }
 
         impl<'a> Iterator for ImportedIter<'a> {
@@ -553,6 +1292,10 @@ impl Rust {
                     return Some(RenderItem::SelfAlias { alias });
                 }
 
+                if core::mem::take(&mut self.glob) {
+                    return Some(RenderItem::Glob);
+                }
+
                 if let Some((name, alias)) = self.names.next() {
                     return Some(RenderItem::Name { name, alias });
                 }
@@ -567,9 +1310,10 @@ impl Rust {
             SelfAlias {
                 alias: &'a ItemStr,
             },
+            Glob,
             Name {
                 name: &'a ItemStr,
-                alias: Option<&'a ItemStr>,
+                alias: Option<&'a str>,
             },
         }
 
@@ -582,6 +1326,9 @@ impl Rust {
                     Self::SelfAlias { alias } => {
                         quote_in!(*out => self as $alias);
                     }
+                    Self::Glob => {
+                        quote_in!(*out => *);
+                    }
                     Self::Name {
                         name,
                         alias: Some(alias),
@@ -710,5 +1457,480 @@ where
         },
         name: name.into(),
         alias: None,
+        dependencies: Vec::new(),
+        cfg: None,
+        dyn_trait: false,
+    }
+}
+
+/// The import of a Rust type which is brought into scope through a glob import
+/// of its module, like `use some_crate::prelude::*`.
+///
+/// The glob is only imported once, and any names from the same module which
+/// would be imported directly are covered by it. Calling
+/// [with_alias()][Import::with_alias()], [direct()][Import::direct()],
+/// [qualified()][Import::qualified()], or
+/// [with_module_alias()][Import::with_module_alias()] on the import switches it
+/// back to being imported explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let parser = rust::import_glob("nom::prelude", "Parser");
+/// let result = rust::import("nom::prelude", "IResult");
+/// let error = rust::import("nom::prelude", "Error").with_alias("NomError");
+///
+/// let toks = quote! {
+///     fn parse<P: $parser>(p: P) -> $result<&str, $error> {
+///         p.parse(input)
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use nom::prelude::{*, Error as NomError};",
+///         "",
+///         "fn parse<P: Parser>(p: P) -> IResult<&str, NomError> {",
+///         "    p.parse(input)",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import_glob<M, N>(module: M, name: N) -> Import
+where
+    M: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        module: Module::Glob {
+            module: module.into(),
+        },
+        name: name.into(),
+        alias: None,
+        dependencies: Vec::new(),
+        cfg: None,
+        dyn_trait: false,
+    }
+}
+
+/// Render an index file like a `mod.rs`, which declares the given modules and
+/// re-exports the symbols they declare.
+///
+/// The declarations of a module are typically collected through
+/// [Tokens::declarations][crate::Tokens::declarations].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let mut user = rust::Tokens::new();
+/// user.declare("User");
+/// user.declare("UserId");
+///
+/// let index = rust::index([
+///     ("user", user.declarations().collect::<Vec<_>>()),
+///     ("group", vec!["Group"]),
+///     ("util", vec![]),
+/// ]);
+///
+/// assert_eq!(
+///     vec![
+///         "pub mod user;",
+///         "pub mod group;",
+///         "pub mod util;",
+///         "",
+///         "pub use self::user::{User, UserId};",
+///         "pub use self::group::Group;",
+///     ],
+///     index.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn index<I, M, D>(modules: I) -> Tokens
+where
+    I: IntoIterator<Item = (M, D)>,
+    M: Into<ItemStr>,
+    D: IntoIterator,
+    D::Item: Into<ItemStr>,
+{
+    use crate as genco;
+    use crate::quote_in;
+
+    let modules = modules
+        .into_iter()
+        .map(|(m, d)| {
+            (
+                m.into(),
+                d.into_iter().map(Into::into).collect::<Vec<ItemStr>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut t = Tokens::new();
+
+    for (module, _) in &modules {
+        quote_in!(t => pub mod $module;);
+        t.push();
+    }
+
+    t.line();
+
+    for (module, declarations) in &modules {
+        match declarations.as_slice() {
+            [] => continue,
+            [name] => quote_in!(t => pub use self::$module::$name;),
+            names => quote_in!(t => pub use self::$module::{$(for n in names join (, ) => $n)};),
+        }
+
+        t.push();
+    }
+
+    t
+}
+
+/// Format a fully qualified path to a trait method, like
+/// `<T as Trait>::method`.
+///
+/// This disambiguates which trait a method is called through, which is needed
+/// when more than one trait in scope provides a method with the same name.
+/// Both the type and the trait are imported as they are used.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let file = rust::import("std::fs", "File");
+/// let read = rust::import("std::io", "Read");
+/// let write = rust::import("std::fmt", "Write");
+///
+/// let toks = quote! {
+///     $(rust::ufcs(&file, &read, "read_to_string"))(&mut f, &mut s)?;
+///     $(rust::ufcs("String", &write, "write_str"))(&mut s, "done")?;
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use std::fmt::Write;",
+///         "use std::fs::File;",
+///         "use std::io::Read;",
+///         "",
+///         "<File as Read>::read_to_string(&mut f, &mut s)?;",
+///         "<String as Write>::write_str(&mut s, \"done\")?;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn ufcs<T, R, M>(ty: T, trait_: R, method: M) -> Ufcs<T, R>
+where
+    T: FormatInto<Rust>,
+    R: FormatInto<Rust>,
+    M: Into<ItemStr>,
+{
+    Ufcs {
+        ty,
+        trait_,
+        method: method.into(),
+    }
+}
+
+/// A fully qualified path to a trait method.
+///
+/// Created through the [ufcs()] function.
+#[derive(Debug, Clone)]
+pub struct Ufcs<T, R> {
+    ty: T,
+    trait_: R,
+    method: ItemStr,
+}
+
+impl<T, R> FormatInto<Rust> for Ufcs<T, R>
+where
+    T: FormatInto<Rust>,
+    R: FormatInto<Rust>,
+{
+    fn format_into(self, tokens: &mut Tokens) {
+        use crate as genco;
+        use crate::quote_in;
+
+        quote_in!(*tokens => <$(self.ty) as $(self.trait_)>::$(self.method));
+    }
+}
+
+/// Format a list of generic parameters, like `<'a, T: Clone + Send>`.
+///
+/// Nothing is written if there are no parameters. If the parameters don't fit
+/// on a single line, each one is written on a line of its own. Parameters with
+/// bounds can be constructed with [bounded()], and any imports used in them
+/// are collected like everywhere else.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let debug = rust::import("std::fmt", "Debug");
+/// let none: [rust::Tokens; 0] = [];
+///
+/// let toks = quote! {
+///     struct Foo$(rust::generics(["'a", "T"]));
+///     struct Bar$(rust::generics([
+///         quote!('a),
+///         quote!($(rust::bounded("T", [quote!(Clone), quote!($(&debug)), quote!('a)]))),
+///     ]));
+///     struct Baz$(rust::generics(none));
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "use std::fmt::Debug;",
+///         "",
+///         "struct Foo<'a, T>;",
+///         "struct Bar<'a, T: Clone + Debug + 'a>;",
+///         "struct Baz;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
+/// Long parameter lists are wrapped, and so are bounds which don't fit on a
+/// line of their own.
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+///
+/// let bounds = || [quote!(Clone), quote!(Send), quote!(Sync), quote!('static)];
+///
+/// let toks: rust::Tokens = quote! {
+///     struct Foo$(rust::generics([
+///         rust::bounded("Key", bounds()),
+///         rust::bounded("Value", bounds()),
+///     ]));
+/// };
+///
+/// let mut w = fmt::VecWriter::new();
+/// let fmt = fmt::Config::from_lang::<Rust>().with_max_width(30);
+/// toks.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+///
+/// assert_eq!(
+///     vec![
+///         "struct Foo<",
+///         "    Key: Clone",
+///         "        + Send",
+///         "        + Sync",
+///         "        + 'static,",
+///         "    Value: Clone",
+///         "        + Send",
+///         "        + Sync",
+///         "        + 'static",
+///         ">;",
+///     ],
+///     w.into_vec()
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn generics<I>(params: I) -> Generics<I>
+where
+    I: IntoIterator,
+    I::Item: FormatInto<Rust>,
+{
+    Generics { params }
+}
+
+/// A list of generic parameters.
+///
+/// Created through the [generics()] function.
+#[derive(Debug, Clone)]
+pub struct Generics<I> {
+    params: I,
+}
+
+impl<I> FormatInto<Rust> for Generics<I>
+where
+    I: IntoIterator,
+    I::Item: FormatInto<Rust>,
+{
+    fn format_into(self, tokens: &mut Tokens) {
+        let mut it = self.params.into_iter().peekable();
+
+        if it.peek().is_none() {
+            return;
+        }
+
+        tokens.item(Item::OpenGroup);
+        tokens.append(static_literal("<"));
+        tokens.item(Item::SoftIndentation(1));
+        separated_with_style(",", it).format_into(tokens);
+        tokens.item(Item::SoftIndentation(-1));
+        tokens.append(static_literal(">"));
+        tokens.item(Item::CloseGroup);
+    }
+}
+
+/// Format a where clause, like `where T: Clone, U: Send`.
+///
+/// Nothing is written if there are no predicates. If the clause doesn't fit on
+/// a single line, each predicate is written on a line of its own. Predicates
+/// with bounds can be constructed with [bounded()].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+///
+/// let debug = rust::import("std::fmt", "Debug");
+/// let none: [rust::Tokens; 0] = [];
+///
+/// let toks = quote! {
+///     fn foo<T>() $(rust::where_clause([rust::bounded("T", [&debug])])) {}
+///
+///     fn bar<T, U>() $(rust::where_clause([
+///         rust::bounded("T", vec![quote!($(&debug)), quote!(Send)]),
+///         rust::bounded("U", vec![quote!(Iterator<Item = T>)]),
+///     ])) {}
+///
+///     fn baz() $(rust::where_clause(none)) {}
+/// };
+///
+/// let mut w = fmt::VecWriter::new();
+/// let fmt = fmt::Config::from_lang::<Rust>().with_max_width(40);
+/// toks.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+///
+/// assert_eq!(
+///     vec![
+///         "use std::fmt::Debug;",
+///         "",
+///         "fn foo<T>() where T: Debug {}",
+///         "",
+///         "fn bar<T, U>()",
+///         "where",
+///         "    T: Debug + Send,",
+///         "    U: Iterator<Item = T>",
+///         "{}",
+///         "",
+///         "fn baz() {}",
+///     ],
+///     w.into_vec()
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn where_clause<I>(predicates: I) -> WhereClause<I>
+where
+    I: IntoIterator,
+    I::Item: FormatInto<Rust>,
+{
+    WhereClause { predicates }
+}
+
+/// A where clause.
+///
+/// Created through the [where_clause()] function.
+#[derive(Debug, Clone)]
+pub struct WhereClause<I> {
+    predicates: I,
+}
+
+impl<I> FormatInto<Rust> for WhereClause<I>
+where
+    I: IntoIterator,
+    I::Item: FormatInto<Rust>,
+{
+    fn format_into(self, tokens: &mut Tokens) {
+        let mut it = self.predicates.into_iter().peekable();
+
+        if it.peek().is_none() {
+            return;
+        }
+
+        tokens.item(Item::OpenGroup);
+        tokens.item(Item::SoftIndentation(0));
+        tokens.append(static_literal("where"));
+        tokens.space();
+        tokens.item(Item::SoftIndentation(1));
+        separated_with_style(",", it).format_into(tokens);
+        tokens.item(Item::SoftIndentation(-1));
+        tokens.item(Item::CloseGroup);
+    }
+}
+
+/// Format a type or lifetime with bounds, like `T: Clone + Send` or `'a: 'b`.
+///
+/// This is used for the parameters of [generics()] and the predicates of
+/// [where_clause()]. If there are no bounds, only the name is written. Bounds
+/// which don't fit on a single line are wrapped before each `+`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let none: [&str; 0] = [];
+///
+/// let toks: rust::Tokens = quote! {
+///     $(rust::bounded("T", ["Clone", "Send"]))
+///     $(rust::bounded("'a", ["'b"]))
+///     $(rust::bounded("U", none))
+/// };
+///
+/// assert_eq!(vec!["T: Clone + Send", "'a: 'b", "U"], toks.to_file_vec()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn bounded<N, B>(name: N, bounds: B) -> Bounded<N, B>
+where
+    N: FormatInto<Rust>,
+    B: IntoIterator,
+    B::Item: FormatInto<Rust>,
+{
+    Bounded { name, bounds }
+}
+
+/// A type or lifetime with bounds.
+///
+/// Created through the [bounded()] function.
+#[derive(Debug, Clone)]
+pub struct Bounded<N, B> {
+    name: N,
+    bounds: B,
+}
+
+impl<N, B> FormatInto<Rust> for Bounded<N, B>
+where
+    N: FormatInto<Rust>,
+    B: IntoIterator,
+    B::Item: FormatInto<Rust>,
+{
+    fn format_into(self, tokens: &mut Tokens) {
+        self.name.format_into(tokens);
+
+        let mut it = self.bounds.into_iter();
+
+        let first = match it.next() {
+            Some(first) => first,
+            None => return,
+        };
+
+        tokens.item(Item::OpenGroup);
+        tokens.append(static_literal(":"));
+        tokens.space();
+        first.format_into(tokens);
+
+        for bound in it {
+            tokens.space();
+            tokens.item(Item::SoftBreak);
+            tokens.append(static_literal("+"));
+            tokens.space();
+            bound.format_into(tokens);
+        }
+
+        tokens.item(Item::CloseGroup);
     }
 }
@@ -0,0 +1,26 @@
+/// Formatting style options of a language, which are consulted while
+/// formatting.
+///
+/// Languages which support style options keep this in their configuration
+/// and provide it through [Lang::style][super::Lang::style]. By default no
+/// style options are enabled.
+///
+/// See [separated_with_style()][crate::tokens::separated_with_style()].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    trailing_commas: bool,
+}
+
+impl Style {
+    /// Write a trailing separator after the last item of lists which are
+    /// broken onto multiple lines.
+    pub fn with_trailing_commas(self, trailing_commas: bool) -> Self {
+        Self { trailing_commas }
+    }
+
+    /// Test if lists which are broken onto multiple lines have a trailing
+    /// separator.
+    pub fn trailing_commas(&self) -> bool {
+        self.trailing_commas
+    }
+}
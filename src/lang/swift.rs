@@ -13,17 +13,35 @@
 //! assert_eq!("\"start π 😊 \\n \\u{7f} ÿ $ end\"", toks.to_string()?);
 //! # Ok(())
 //! # }
+//! ```
+//!
+//! # String Interpolation in Swift
+//!
+//! Swift supports interpolating expressions into strings through `\(expr)`,
+//! which is used when evaluating expressions in strings.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! # fn main() -> genco::fmt::Result {
+//! let toks: swift::Tokens = quote!(print($[str](Hello $(name) $[const]("\"quoted\""))));
+//! assert_eq!("print(\"Hello \\(name) \\\"quoted\\\"\")", toks.to_string()?);
+//! # Ok(())
+//! # }
+//! ```
 
 use core::fmt::Write as _;
 
 use alloc::collections::BTreeSet;
 
 use crate::fmt;
-use crate::tokens::ItemStr;
+use crate::tokens::{Case, FormatInto, IdentKind, ItemStr, Naming};
 
 /// Tokens container specialization for Rust.
 pub type Tokens = crate::Tokens<Swift>;
 
+impl crate::lang::LangSupportsEval for Swift {}
+
 impl_lang! {
     /// Swift token specialization.
     pub Swift {
@@ -31,6 +49,28 @@ impl_lang! {
         type Format = Format;
         type Item = Import;
 
+        fn ident_case(config: &Self::Config, kind: IdentKind) -> Option<Case> {
+            config.naming.get(kind)
+        }
+
+        fn start_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_str("\\(")?;
+            Ok(())
+        }
+
+        fn end_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            _config: &Self::Config,
+            _format: &Self::Format,
+        ) -> fmt::Result {
+            out.write_char(')')?;
+            Ok(())
+        }
+
         fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
             // From: https://docs.swift.org/swift-book/LanguageGuide/StringsAndCharacters.html
 
@@ -59,7 +99,7 @@ impl_lang! {
             config: &Self::Config,
         ) -> fmt::Result {
             let mut imports = Tokens::new();
-            Self::imports(&mut imports, tokens);
+            Self::imports(&mut imports, out, tokens, config);
             let format = Format::default();
             imports.format(out, config, &format)?;
             tokens.format(out, config, &format)?;
@@ -79,13 +119,66 @@ impl_lang! {
 pub struct Format {}
 
 /// Configuration for formatting Swift code.
-#[derive(Debug, Default)]
-pub struct Config {}
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    module: Option<ItemStr>,
+    naming: Naming,
+}
+
+impl_naming!(Config);
+
+impl Config {
+    /// Configure the module the file is part of.
+    ///
+    /// Names imported from the same module don't need an `import` statement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let url = swift::import("Foundation", "URL");
+    /// let client = swift::import("Networking", "Client");
+    ///
+    /// let toks: swift::Tokens = quote! {
+    ///     let client: $client
+    ///     let url: $url
+    /// };
+    ///
+    /// let config = swift::Config::default().with_module("Networking");
+    /// let fmt = fmt::Config::from_lang::<Swift>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import Foundation",
+    ///         "",
+    ///         "let client: Client",
+    ///         "let url: URL",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_module<M>(self, module: M) -> Self
+    where
+        M: Into<ItemStr>,
+    {
+        Self {
+            module: Some(module.into()),
+            ..self
+        }
+    }
+}
 
 /// The import of a Swift type `import UIKit`.
 ///
 /// Created through the [import()] function.
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
 pub struct Import {
     /// Module of the imported name.
     module: ItemStr,
@@ -93,14 +186,35 @@ pub struct Import {
     name: ItemStr,
 }
 
+impl crate::lang::LangConfig for Config {
+    fn with_namespace<N>(self, namespace: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        self.with_module(namespace)
+    }
+}
+
 impl Swift {
-    fn imports(out: &mut Tokens, tokens: &Tokens) {
+    fn imports(
+        out: &mut Tokens,
+        formatter: &mut fmt::Formatter<'_>,
+        tokens: &Tokens,
+        config: &Config,
+    ) {
         use crate as genco;
         use crate::quote_in;
 
         let mut modules = BTreeSet::new();
 
         for import in tokens.walk_imports() {
+            if Some(&import.module) == config.module.as_ref() {
+                trace!(module = %import.module, "skipping import, same module as file");
+                continue;
+            }
+
+            trace!(module = %import.module, "collecting import");
+            formatter.record_import::<Self>(import);
             modules.insert(&import.module);
         }
 
@@ -116,18 +230,28 @@ impl Swift {
 
 /// The import of a Swift type `import UIKit`.
 ///
+/// Each module is only imported once, regardless of how many names are used
+/// from it.
+///
 /// # Examples
 ///
 /// ```
 /// use genco::prelude::*;
 ///
-/// let toks = quote!($(swift::import("Foo", "Debug")));
+/// let toks = quote! {
+///     $(swift::import("Foundation", "URL"))
+///     $(swift::import("Foo", "Debug"))
+///     $(swift::import("Foundation", "Data"))
+/// };
 ///
 /// assert_eq!(
 ///     vec![
 ///         "import Foo",
+///         "import Foundation",
 ///         "",
+///         "URL",
 ///         "Debug",
+///         "Data",
 ///     ],
 ///     toks.to_file_vec()?
 /// );
@@ -143,3 +267,83 @@ where
         name: name.into(),
     }
 }
+
+/// Define a type alias, like `typealias Name = Type`.
+///
+/// Any imports used by the aliased type are imported as usual.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let data = swift::import("Foundation", "Data");
+/// let response = swift::import("Foundation", "URLResponse");
+///
+/// let toks = quote! {
+///     $(swift::type_alias("Completion", quote!(($data?, $response?) -> Void)))
+///     $(swift::type_alias("Identifier", "String").public())
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import Foundation",
+///         "",
+///         "typealias Completion = (Data?, URLResponse?) -> Void",
+///         "public typealias Identifier = String",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn type_alias<N, T>(name: N, ty: T) -> TypeAlias<T>
+where
+    N: Into<ItemStr>,
+    T: FormatInto<Swift>,
+{
+    TypeAlias {
+        name: name.into(),
+        ty,
+        public: false,
+    }
+}
+
+/// A type alias.
+///
+/// Created through the [type_alias()] function.
+#[derive(Debug, Clone)]
+pub struct TypeAlias<T> {
+    name: ItemStr,
+    ty: T,
+    public: bool,
+}
+
+impl<T> TypeAlias<T> {
+    /// Make the type alias public.
+    pub fn public(self) -> Self {
+        Self {
+            public: true,
+            ..self
+        }
+    }
+}
+
+impl<T> FormatInto<Swift> for TypeAlias<T>
+where
+    T: FormatInto<Swift>,
+{
+    fn format_into(self, tokens: &mut Tokens) {
+        if self.public {
+            tokens.append("public");
+            tokens.space();
+        }
+
+        tokens.append("typealias");
+        tokens.space();
+        tokens.append(self.name);
+        tokens.space();
+        tokens.append("=");
+        tokens.space();
+        tokens.append(self.ty);
+    }
+}
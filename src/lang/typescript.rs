@@ -0,0 +1,644 @@
+//! Specialization for TypeScript code generation.
+//!
+//! This shares string quoting and module resolution with [JavaScript], but
+//! also knows about type-only imports like `import type {Foo} from "./foo"`.
+//!
+//! [JavaScript]: crate::lang::js
+//!
+//! # Examples
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! let user = typescript::type_import(typescript::Module::Path("models.ts".into()), "User");
+//! let fetch = typescript::import("./api", "fetchUser");
+//!
+//! let toks: typescript::Tokens = quote! {
+//!     export async function load(id: number): Promise<$user> {
+//!         return await $fetch(id);
+//!     }
+//! };
+//!
+//! assert_eq!(
+//!     vec![
+//!         "import type {User} from \"models.ts\";",
+//!         "import {fetchUser} from \"./api\";",
+//!         "",
+//!         "export async function load(id: number): Promise<User> {",
+//!         "    return await fetchUser(id);",
+//!         "}",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok::<_, genco::fmt::Error>(())
+//! ```
+
+use core::fmt::Write as _;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use crate::fmt;
+use crate::lang::{JavaScript, Style};
+use crate::tokens::ItemStr;
+
+use relative_path::{RelativePath, RelativePathBuf};
+
+pub use crate::lang::js::Module;
+
+/// Tokens container specialization for TypeScript.
+pub type Tokens = crate::Tokens<TypeScript>;
+
+impl crate::lang::LangSupportsEval for TypeScript {}
+
+impl_lang! {
+    /// TypeScript language specialization.
+    pub TypeScript {
+        type Config = Config;
+        type Format = Format;
+        type Item = Import;
+
+        fn style(config: &Self::Config) -> Style {
+            JavaScript::style(&config.js)
+        }
+
+        fn open_quote(
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+            format: &Self::Format,
+            has_eval: bool,
+        ) -> fmt::Result {
+            JavaScript::open_quote(out, &config.js, &format.js, has_eval)
+        }
+
+        fn close_quote(
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+            format: &Self::Format,
+            has_eval: bool,
+        ) -> fmt::Result {
+            JavaScript::close_quote(out, &config.js, &format.js, has_eval)
+        }
+
+        fn start_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+            format: &Self::Format,
+        ) -> fmt::Result {
+            JavaScript::start_string_eval(out, &config.js, &format.js)
+        }
+
+        fn end_string_eval(
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+            format: &Self::Format,
+        ) -> fmt::Result {
+            JavaScript::end_string_eval(out, &config.js, &format.js)
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            JavaScript::write_quoted(out, input)
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut imports = Tokens::new();
+            Self::imports(&mut imports, out, tokens, config);
+            let format = Format::default();
+            imports.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            let name = match self.kind {
+                ImportKind::Named => self.alias.as_ref().unwrap_or(&self.name),
+                _ => &self.name,
+            };
+
+            out.write_str(name)
+        }
+    }
+}
+
+/// Format state for TypeScript.
+#[derive(Debug, Default)]
+pub struct Format {
+    js: crate::lang::js::Format,
+}
+
+/// Configuration for TypeScript.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    module_path: Option<RelativePathBuf>,
+    js: crate::lang::js::Config,
+}
+
+impl Config {
+    /// Configure the path to the current module being renderer.
+    ///
+    /// This setting will determine what path imports are renderer relative
+    /// towards. So importing a module from `"foo/bar.ts"`, and setting this to
+    /// `"foo/baz.ts"` will cause the import to be rendered relatively as
+    /// `"../bar.ts"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let foo = typescript::import(typescript::Module::Path("foo/bar.ts".into()), "Foo");
+    /// let bar = typescript::type_import(typescript::Module::Path("foo/bar.ts".into()), "Bar");
+    ///
+    /// let toks: typescript::Tokens = quote! {
+    ///     const foo: $bar = new $foo();
+    /// };
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    ///
+    /// let config = typescript::Config::default().with_module_path("foo/baz.ts");
+    /// let fmt = fmt::Config::from_lang::<TypeScript>();
+    ///
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import {Foo} from \"../bar.ts\";",
+    ///         "import type {Bar} from \"../bar.ts\";",
+    ///         "",
+    ///         "const foo: Bar = new Foo();",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_module_path<M>(self, module_path: M) -> Self
+    where
+        M: Into<RelativePathBuf>,
+    {
+        Self {
+            module_path: Some(module_path.into()),
+            ..self
+        }
+    }
+
+    /// Write a trailing comma after the last item of lists which are broken
+    /// onto multiple lines, like prettier does.
+    ///
+    /// See [separated_with_style].
+    ///
+    /// [separated_with_style]: crate::tokens::separated_with_style()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    /// use genco::tokens::{delimited, separated_with_style};
+    ///
+    /// let fields = ["name: string", "email: string", "age: number"];
+    ///
+    /// let toks: typescript::Tokens = quote! {
+    ///     type User = $(delimited("{", "}", separated_with_style(";", fields)).with_padding(true));
+    /// };
+    ///
+    /// let config = typescript::Config::default().with_trailing_commas(true);
+    /// let fmt = fmt::Config::from_lang::<TypeScript>().with_max_width(40);
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "type User = {",
+    ///         "    name: string;",
+    ///         "    email: string;",
+    ///         "    age: number;",
+    ///         "};",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_trailing_commas(self, trailing_commas: bool) -> Self {
+        Self {
+            js: self.js.with_trailing_commas(trailing_commas),
+            ..self
+        }
+    }
+}
+
+/// Internal type to determine the kind of import used.
+#[derive(Debug, Clone, Copy, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+enum ImportKind {
+    Named,
+    Default,
+    Wildcard,
+}
+
+/// The import of a TypeScript value or type, like `import {foo} from
+/// "module"`.
+///
+/// Created through the [import()] and [type_import()] functions.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+pub struct Import {
+    /// The kind of the import.
+    kind: ImportKind,
+    /// Module of the imported name.
+    module: Module,
+    /// Name imported.
+    name: ItemStr,
+    /// Alias of an imported item.
+    alias: Option<ItemStr>,
+    /// If the import is only used in type positions, in which case it's
+    /// imported with `import type`.
+    type_only: bool,
+}
+
+impl Import {
+    /// Change alias of imported item.
+    ///
+    /// This implies that the import is a named import.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let a = typescript::import("collections", "vec");
+    /// let b = typescript::import("collections", "vec").with_alias("list");
+    ///
+    /// let toks = quote! {
+    ///     $a
+    ///     $b
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import {vec, vec as list} from \"collections\";",
+    ///         "",
+    ///         "vec",
+    ///         "list",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_alias<N>(self, alias: N) -> Self
+    where
+        N: Into<ItemStr>,
+    {
+        Self {
+            kind: ImportKind::Named,
+            alias: Some(alias.into()),
+            ..self
+        }
+    }
+
+    /// Convert into a default import.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let react = typescript::import("react", "React").into_default();
+    ///
+    /// let toks = quote!($react);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import React from \"react\";",
+    ///         "",
+    ///         "React",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn into_default(self) -> Self {
+        Self {
+            kind: ImportKind::Default,
+            alias: None,
+            ..self
+        }
+    }
+
+    /// Convert into a namespace import, like `import * as name from
+    /// "module"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let path = typescript::import("node:path", "path").into_wildcard();
+    /// let models = typescript::type_import("./models", "models").into_wildcard();
+    ///
+    /// let toks = quote! {
+    ///     const user: $models.User = load($path.join("a", "b"));
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import type * as models from \"./models\";",
+    ///         "import * as path from \"node:path\";",
+    ///         "",
+    ///         "const user: models.User = load(path.join(\"a\", \"b\"));",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn into_wildcard(self) -> Self {
+        Self {
+            kind: ImportKind::Wildcard,
+            alias: None,
+            ..self
+        }
+    }
+
+    /// Convert into a type-only import, which is imported with `import type`.
+    ///
+    /// See [type_import()] for an example.
+    pub fn into_type_only(self) -> Self {
+        Self {
+            type_only: true,
+            ..self
+        }
+    }
+}
+
+impl TypeScript {
+    /// Translate imports into the necessary tokens.
+    fn imports(
+        out: &mut Tokens,
+        formatter: &mut fmt::Formatter<'_>,
+        tokens: &Tokens,
+        config: &Config,
+    ) {
+        use crate as genco;
+        use crate::prelude::*;
+
+        let mut modules = BTreeMap::<&Module, ResolvedModule<'_>>::new();
+        let mut wildcards = BTreeSet::new();
+
+        for import in tokens.walk_imports() {
+            trace!(module = ?import.module, name = %import.name, kind = ?import.kind, type_only = import.type_only, "collecting import");
+            formatter.record_import::<Self>(import);
+
+            match import.kind {
+                ImportKind::Named => {
+                    let module = modules.entry(&import.module).or_default();
+
+                    let element = match &import.alias {
+                        None => ImportedElement::Plain(&import.name),
+                        Some(alias) => ImportedElement::Aliased(&import.name, alias),
+                    };
+
+                    if import.type_only {
+                        module.types.set.insert(element);
+                    } else {
+                        module.values.set.insert(element);
+                    }
+                }
+                ImportKind::Default => {
+                    let module = modules.entry(&import.module).or_default();
+
+                    if import.type_only {
+                        module.types.default_import = Some(&import.name);
+                    } else {
+                        module.values.default_import = Some(&import.name);
+                    }
+                }
+                ImportKind::Wildcard => {
+                    wildcards.insert((&import.module, import.type_only, &import.name));
+                }
+            }
+        }
+
+        if modules.is_empty() && wildcards.is_empty() {
+            return;
+        }
+
+        // Values which are already imported don't need a separate type-only
+        // import.
+        for module in modules.values_mut() {
+            let ResolvedModule { values, types } = module;
+
+            types.set.retain(|el| !values.set.contains(el));
+
+            if types.default_import == values.default_import {
+                types.default_import = None;
+            }
+        }
+
+        for (module, type_only, name) in wildcards {
+            out.push();
+            quote_in! { *out =>
+                import $(if type_only { type$[' '] })* as $name from $(ref t => render_from(t, config.module_path.as_deref(), module));
+            }
+        }
+
+        for (name, module) in modules {
+            let ResolvedModule { values, mut types } = module;
+
+            if !values.is_empty() {
+                out.push();
+                quote_in! { *out =>
+                    import $(ref t => values.render(t)) from $(ref t => render_from(t, config.module_path.as_deref(), name));
+                }
+            }
+
+            // A type-only import can't specify both a default import and named
+            // bindings, so they are split into separate statements.
+            if let Some(default_import) = types.default_import.take() {
+                out.push();
+                quote_in! { *out =>
+                    import type $default_import from $(ref t => render_from(t, config.module_path.as_deref(), name));
+                }
+            }
+
+            if !types.is_empty() {
+                out.push();
+                quote_in! { *out =>
+                    import type $(ref t => types.render(t)) from $(ref t => render_from(t, config.module_path.as_deref(), name));
+                }
+            }
+        }
+
+        out.line();
+
+        #[derive(Default)]
+        struct ResolvedModule<'a> {
+            values: Bindings<'a>,
+            types: Bindings<'a>,
+        }
+
+        #[derive(Default)]
+        struct Bindings<'a> {
+            default_import: Option<&'a ItemStr>,
+            set: BTreeSet<ImportedElement<'a>>,
+        }
+
+        impl Bindings<'_> {
+            fn is_empty(&self) -> bool {
+                self.default_import.is_none() && self.set.is_empty()
+            }
+
+            fn render(&self, tokens: &mut typescript::Tokens) {
+                if let Some(default) = self.default_import {
+                    tokens.append(ItemStr::from(default));
+
+                    if !self.set.is_empty() {
+                        tokens.append(",");
+                        tokens.space();
+                    }
+                }
+
+                if !self.set.is_empty() {
+                    tokens.append("{");
+
+                    let mut it = self.set.iter().peekable();
+
+                    while let Some(el) = it.next() {
+                        match *el {
+                            ImportedElement::Plain(name) => {
+                                tokens.append(name);
+                            }
+                            ImportedElement::Aliased(name, alias) => {
+                                quote_in!(*tokens => $name as $alias);
+                            }
+                        }
+
+                        if it.peek().is_some() {
+                            tokens.append(",");
+                            tokens.space();
+                        }
+                    }
+
+                    tokens.append("}");
+                }
+            }
+        }
+
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+        enum ImportedElement<'a> {
+            Plain(&'a ItemStr),
+            Aliased(&'a ItemStr, &'a ItemStr),
+        }
+
+        fn render_from(
+            t: &mut typescript::Tokens,
+            module_path: Option<&RelativePath>,
+            module: &Module,
+        ) {
+            quote_in! { *t =>
+                $(match (module_path, module) {
+                    (_, Module::Global(from)) => $(quoted(from)),
+                    (None, Module::Path(path)) => $(quoted(path.as_str())),
+                    (Some(module_path), Module::Path(path)) => $(quoted(module_path.relative(path).as_str())),
+                })
+            }
+        }
+    }
+}
+
+/// Import a value from a module, like `import {foo} from "module"`.
+///
+/// The import can be converted into a default, namespace, or type-only import
+/// through [Import::into_default], [Import::into_wildcard], and
+/// [Import::into_type_only].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let react = typescript::import("react", "React").into_default();
+/// let use_state = typescript::import("react", "useState");
+/// let fs = typescript::import("node:fs", "fs").into_wildcard();
+///
+/// let toks = quote! {
+///     $react
+///     $use_state
+///     $fs
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import * as fs from \"node:fs\";",
+///         "import React, {useState} from \"react\";",
+///         "",
+///         "React",
+///         "useState",
+///         "fs",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<M, N>(module: M, name: N) -> Import
+where
+    M: Into<Module>,
+    N: Into<ItemStr>,
+{
+    Import {
+        kind: ImportKind::Named,
+        module: module.into(),
+        name: name.into(),
+        alias: None,
+        type_only: false,
+    }
+}
+
+/// Import a type from a module with `import type`, which is erased when
+/// compiling to JavaScript.
+///
+/// Names which are also imported as values are only imported once.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let props = typescript::type_import("react", "FC");
+/// let node = typescript::type_import("react", "ReactNode");
+/// let react = typescript::type_import("react", "React").into_default();
+/// let user = typescript::type_import("./user", "User");
+/// let new_user = typescript::import("./user", "User");
+///
+/// let toks = quote! {
+///     type A = $props<$node>;
+///     type B = $react;
+///     const user: $user = new $new_user();
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "import {User} from \"./user\";",
+///         "import type React from \"react\";",
+///         "import type {FC, ReactNode} from \"react\";",
+///         "",
+///         "type A = FC<ReactNode>;",
+///         "type B = React;",
+///         "const user: User = new User();",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn type_import<M, N>(module: M, name: N) -> Import
+where
+    M: Into<Module>,
+    N: Into<ItemStr>,
+{
+    import(module, name).into_type_only()
+}
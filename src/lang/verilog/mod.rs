@@ -0,0 +1,237 @@
+//! Specialization for Verilog and SystemVerilog code generation.
+//!
+//! Compiler directives such as `` `include `` and `` `define `` are always
+//! written at the beginning of their own line, regardless of the indentation
+//! they are used at.
+//!
+//! # String Quoting in Verilog
+//!
+//! Strings are escaped so that they can be used as format strings with system
+//! tasks like `$display`. Since strings are 8-bit, characters outside of
+//! printable ASCII are written as octal escapes of their UTF-8 encoding.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! let toks: verilog::Tokens = quote!($$display("value: %d\n\t\"π\"", value););
+//! assert_eq!(
+//!     "$display(\"value: %d\\n\\t\\\"\\317\\200\\\"\", value);",
+//!     toks.to_string()?
+//! );
+//! # Ok::<_, genco::fmt::Error>(())
+//! ```
+
+mod module;
+pub use self::module::{inout, input, module, output, Direction, Module, Port};
+
+use core::fmt::Write as _;
+
+use alloc::string::String;
+
+use crate::fmt;
+use crate::tokens::ItemStr;
+
+/// Tokens container specialization for Verilog.
+pub type Tokens = crate::Tokens<Verilog>;
+
+impl_lang! {
+    /// Language specialization for Verilog and SystemVerilog.
+    pub Verilog {
+        type Config = Config;
+        type Format = Format;
+        type Item = Directive;
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            for c in input.chars() {
+                match c {
+                    '\n' => out.write_str("\\n")?,
+                    '\t' => out.write_str("\\t")?,
+                    '"' => out.write_str("\\\"")?,
+                    '\\' => out.write_str("\\\\")?,
+                    ' ' => out.write_char(' ')?,
+                    c if c.is_ascii() && !c.is_control() => out.write_char(c)?,
+                    c => {
+                        for b in c.encode_utf8(&mut [0u8; 4]).bytes() {
+                            write!(out, "\\{:03o}", b)?;
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    Directive {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            let mut line = String::new();
+            line.push('`');
+            line.push_str(&self.name);
+
+            if !self.value.is_empty() {
+                line.push(' ');
+
+                if self.quoted {
+                    line.push('"');
+                    line.push_str(&self.value);
+                    line.push('"');
+                } else {
+                    line.push_str(&self.value);
+                }
+            }
+
+            out.write_pinned(&line)
+        }
+    }
+}
+
+/// A compiler directive, like `` `include "defs.vh" `` or
+/// `` `define WIDTH 8 ``.
+///
+/// Directives are written at the beginning of their own line regardless of the
+/// current indentation.
+///
+/// Created through the [include()], [define()] and [directive()] functions.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+pub struct Directive {
+    /// Name of the directive, without the leading backtick.
+    name: ItemStr,
+    /// The value of the directive.
+    value: ItemStr,
+    /// If the value should be quoted.
+    quoted: bool,
+}
+
+/// Format state for Verilog.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Config data for Verilog.
+#[derive(Debug, Clone, Default)]
+pub struct Config {}
+
+/// Include a file with the `` `include `` directive.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: verilog::Tokens = quote! {
+///     $(verilog::include("defs.vh"))
+///
+///     module top;
+///         $(verilog::include("tasks.vh"))
+///     endmodule
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "`include \"defs.vh\"",
+///         "",
+///         "module top;",
+///         "`include \"tasks.vh\"",
+///         "endmodule",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn include<P>(path: P) -> Directive
+where
+    P: Into<ItemStr>,
+{
+    Directive {
+        name: ItemStr::Static("include"),
+        value: path.into(),
+        quoted: true,
+    }
+}
+
+/// Define a text macro with the `` `define `` directive.
+///
+/// The value can be empty to define a macro without a value.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: verilog::Tokens = quote! {
+///     $(verilog::define("SYNTHESIS", ""))
+///     $(verilog::define("WIDTH", "8"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "`define SYNTHESIS",
+///         "`define WIDTH 8",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn define<N, V>(name: N, value: V) -> Directive
+where
+    N: Into<ItemStr>,
+    V: Into<ItemStr>,
+{
+    let name = name.into();
+    let value = value.into();
+
+    let value = if value.is_empty() {
+        name
+    } else {
+        ItemStr::from(alloc::format!("{} {}", name, value))
+    };
+
+    Directive {
+        name: ItemStr::Static("define"),
+        value,
+        quoted: false,
+    }
+}
+
+/// Any other compiler directive, like `` `timescale `` or `` `ifdef ``.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: verilog::Tokens = quote! {
+///     $(verilog::directive("timescale", "1ns / 1ps"))
+///
+///     module top;
+///         $(verilog::directive("ifdef", "SIMULATION"))
+///         initial $$display("simulating");
+///         $(verilog::directive("endif", ""))
+///     endmodule
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "`timescale 1ns / 1ps",
+///         "",
+///         "module top;",
+///         "`ifdef SIMULATION",
+///         "    initial $display(\"simulating\");",
+///         "`endif",
+///         "endmodule",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn directive<N, V>(name: N, value: V) -> Directive
+where
+    N: Into<ItemStr>,
+    V: Into<ItemStr>,
+{
+    Directive {
+        name: name.into(),
+        value: value.into(),
+        quoted: false,
+    }
+}
@@ -0,0 +1,310 @@
+use alloc::vec::Vec;
+
+use crate as genco;
+use crate::lang::Verilog;
+use crate::tokens::{FormatInto, ItemStr};
+use crate::Tokens;
+use crate::{quote, quote_in};
+
+/// The direction of a [Port].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    /// An `input` port.
+    Input,
+    /// An `output` port.
+    Output,
+    /// An `inout` port.
+    Inout,
+}
+
+impl Direction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Input => "input",
+            Self::Output => "output",
+            Self::Inout => "inout",
+        }
+    }
+}
+
+/// A port in the port list of a [Module].
+///
+/// Created through the [input()], [output()] and [inout()] functions.
+#[derive(Debug, Clone)]
+pub struct Port {
+    direction: Direction,
+    name: ItemStr,
+    kind: Option<ItemStr>,
+    range: Option<(ItemStr, ItemStr)>,
+}
+
+impl Port {
+    /// Set the net or variable kind of the port, like `wire`, `reg` or
+    /// `logic`.
+    pub fn with_kind<K>(self, kind: K) -> Self
+    where
+        K: Into<ItemStr>,
+    {
+        Self {
+            kind: Some(kind.into()),
+            ..self
+        }
+    }
+
+    /// Set the width of the port in bits, which is written as the range
+    /// `[width-1:0]`.
+    ///
+    /// A width of one or less doesn't produce a range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let port = verilog::output("data").with_kind("reg").with_width(8);
+    ///
+    /// let toks: verilog::Tokens = quote!($port);
+    /// assert_eq!("output reg [7:0] data", toks.to_string()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_width(self, width: usize) -> Self {
+        if width <= 1 {
+            return Self {
+                range: None,
+                ..self
+            };
+        }
+
+        self.with_range(alloc::format!("{}", width - 1), ItemStr::Static("0"))
+    }
+
+    /// Set the range of the port, which is written as `[msb:lsb]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let port = verilog::input("addr").with_range("ADDR_WIDTH-1", "0");
+    ///
+    /// let toks: verilog::Tokens = quote!($port);
+    /// assert_eq!("input [ADDR_WIDTH-1:0] addr", toks.to_string()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_range<M, L>(self, msb: M, lsb: L) -> Self
+    where
+        M: Into<ItemStr>,
+        L: Into<ItemStr>,
+    {
+        Self {
+            range: Some((msb.into(), lsb.into())),
+            ..self
+        }
+    }
+}
+
+impl FormatInto<Verilog> for Port {
+    fn format_into(self, tokens: &mut Tokens<Verilog>) {
+        tokens.append(self.direction.as_str());
+
+        if let Some(kind) = self.kind {
+            tokens.space();
+            tokens.append(kind);
+        }
+
+        if let Some((msb, lsb)) = self.range {
+            tokens.space();
+            quote_in!(*tokens => [$msb:$lsb]);
+        }
+
+        tokens.space();
+        tokens.append(self.name);
+    }
+}
+
+/// A module declaration.
+///
+/// Created through the [module()] function.
+#[derive(Debug, Clone)]
+pub struct Module {
+    name: ItemStr,
+    parameters: Vec<(ItemStr, ItemStr)>,
+    ports: Vec<Port>,
+    body: Tokens<Verilog>,
+}
+
+impl Module {
+    /// Add a parameter to the module, which is declared in the `#(..)`
+    /// parameter list.
+    pub fn with_parameter<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<ItemStr>,
+        V: Into<ItemStr>,
+    {
+        self.parameters.push((name.into(), value.into()));
+        self
+    }
+
+    /// Add a port to the port list of the module.
+    pub fn with_port(mut self, port: Port) -> Self {
+        self.ports.push(port);
+        self
+    }
+}
+
+impl FormatInto<Verilog> for Module {
+    fn format_into(self, tokens: &mut Tokens<Verilog>) {
+        tokens.append("module");
+        tokens.space();
+        tokens.append(self.name);
+
+        if !self.parameters.is_empty() {
+            tokens.space();
+            tokens.append("#(");
+            tokens.indent();
+
+            let mut it = self.parameters.into_iter().peekable();
+
+            while let Some((name, value)) = it.next() {
+                quote_in!(*tokens => parameter $name = $value);
+
+                if it.peek().is_some() {
+                    tokens.append(",");
+                    tokens.push();
+                }
+            }
+
+            tokens.unindent();
+            tokens.append(")");
+        }
+
+        if !self.ports.is_empty() {
+            tokens.space();
+            tokens.append("(");
+            tokens.indent();
+
+            let mut it = self.ports.into_iter().peekable();
+
+            while let Some(port) = it.next() {
+                tokens.append(port);
+
+                if it.peek().is_some() {
+                    tokens.append(",");
+                    tokens.push();
+                }
+            }
+
+            tokens.unindent();
+            tokens.append(")");
+        }
+
+        tokens.append(";");
+
+        if self.body.is_empty() {
+            tokens.push();
+        } else {
+            tokens.indent();
+            tokens.append(self.body);
+            tokens.unindent();
+        }
+
+        tokens.append("endmodule");
+    }
+}
+
+/// Declare a module with the given name and body.
+///
+/// Ports are written one per line in an ANSI-style port list, separated by
+/// commas.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let counter = verilog::module("counter", quote! {
+///     always @(posedge clk) begin
+///         count <= count + 1;
+///     end
+/// })
+/// .with_parameter("WIDTH", "8")
+/// .with_port(verilog::input("clk").with_kind("wire"))
+/// .with_port(verilog::output("count").with_kind("reg").with_range("WIDTH-1", "0"));
+///
+/// let toks: verilog::Tokens = quote!($counter);
+///
+/// assert_eq!(
+///     vec![
+///         "module counter #(",
+///         "    parameter WIDTH = 8",
+///         ") (",
+///         "    input wire clk,",
+///         "    output reg [WIDTH-1:0] count",
+///         ");",
+///         "    always @(posedge clk) begin",
+///         "        count <= count + 1;",
+///         "    end",
+///         "endmodule",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
+/// A module without ports or a body:
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: verilog::Tokens = quote!($(verilog::module("empty", verilog::Tokens::new())));
+/// assert_eq!(vec!["module empty;", "endmodule"], toks.to_file_vec()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn module<N, B>(name: N, body: B) -> Module
+where
+    N: Into<ItemStr>,
+    B: FormatInto<Verilog>,
+{
+    Module {
+        name: name.into(),
+        parameters: Vec::new(),
+        ports: Vec::new(),
+        body: quote!($body),
+    }
+}
+
+/// Construct an `input` port with the given name.
+pub fn input<N>(name: N) -> Port
+where
+    N: Into<ItemStr>,
+{
+    port(Direction::Input, name)
+}
+
+/// Construct an `output` port with the given name.
+pub fn output<N>(name: N) -> Port
+where
+    N: Into<ItemStr>,
+{
+    port(Direction::Output, name)
+}
+
+/// Construct an `inout` port with the given name.
+pub fn inout<N>(name: N) -> Port
+where
+    N: Into<ItemStr>,
+{
+    port(Direction::Inout, name)
+}
+
+fn port<N>(direction: Direction, name: N) -> Port
+where
+    N: Into<ItemStr>,
+{
+    Port {
+        direction,
+        name: name.into(),
+        kind: None,
+        range: None,
+    }
+}
@@ -0,0 +1,426 @@
+//! Specialization for VHDL code generation.
+//!
+//! # Identifiers in VHDL
+//!
+//! VHDL is case-insensitive, so reserved words are matched regardless of case
+//! and identifiers colliding with them are written as extended identifiers.
+//! The libraries and packages of imports are also normalized to lower case,
+//! so that differently cased imports of the same package are only declared
+//! once.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//! use genco::tokens::Ident;
+//!
+//! let signal = Ident::new("Signal")?;
+//! let toks: vhdl::Tokens = quote!($signal);
+//! assert_eq!("\\Signal\\", toks.to_string()?);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! # String Quoting in VHDL
+//!
+//! Quotes in strings are escaped by doubling them, and line breaks and tabs
+//! are concatenated with the corresponding character constants.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! let toks: vhdl::Tokens = quote!("say \"hi\"\n");
+//! assert_eq!("\"say \"\"hi\"\"\" & LF & \"\"", toks.to_string()?);
+//! # Ok::<_, genco::fmt::Error>(())
+//! ```
+
+mod comment;
+pub use self::comment::Comment;
+
+use core::fmt::Write as _;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use crate as genco;
+use crate::fmt;
+use crate::quote_in;
+use crate::tokens::{CommentKind, ItemStr};
+
+/// Tokens container specialization for VHDL.
+pub type Tokens = crate::Tokens<Vhdl>;
+
+/// Libraries which are implicitly declared and don't need a library clause.
+const IMPLICIT_LIBRARIES: [&str; 2] = ["std", "work"];
+
+/// Reserved words in VHDL-2008.
+const KEYWORDS: &[&str] = &[
+    "abs",
+    "access",
+    "after",
+    "alias",
+    "all",
+    "and",
+    "architecture",
+    "array",
+    "assert",
+    "assume",
+    "assume_guarantee",
+    "attribute",
+    "begin",
+    "block",
+    "body",
+    "buffer",
+    "bus",
+    "case",
+    "component",
+    "configuration",
+    "constant",
+    "context",
+    "cover",
+    "default",
+    "disconnect",
+    "downto",
+    "else",
+    "elsif",
+    "end",
+    "entity",
+    "exit",
+    "fairness",
+    "file",
+    "for",
+    "force",
+    "function",
+    "generate",
+    "generic",
+    "group",
+    "guarded",
+    "if",
+    "impure",
+    "in",
+    "inertial",
+    "inout",
+    "is",
+    "label",
+    "library",
+    "linkage",
+    "literal",
+    "loop",
+    "map",
+    "mod",
+    "nand",
+    "new",
+    "next",
+    "nor",
+    "not",
+    "null",
+    "of",
+    "on",
+    "open",
+    "or",
+    "others",
+    "out",
+    "package",
+    "parameter",
+    "port",
+    "postponed",
+    "procedure",
+    "process",
+    "property",
+    "protected",
+    "pure",
+    "range",
+    "record",
+    "register",
+    "reject",
+    "release",
+    "rem",
+    "report",
+    "restrict",
+    "restrict_guarantee",
+    "return",
+    "rol",
+    "ror",
+    "select",
+    "sequence",
+    "severity",
+    "shared",
+    "signal",
+    "sla",
+    "sll",
+    "sra",
+    "srl",
+    "strong",
+    "subtype",
+    "then",
+    "to",
+    "transport",
+    "type",
+    "unaffected",
+    "units",
+    "until",
+    "use",
+    "variable",
+    "vmode",
+    "vprop",
+    "vunit",
+    "wait",
+    "when",
+    "while",
+    "with",
+    "xnor",
+    "xor",
+];
+
+impl_lang! {
+    /// Language specialization for VHDL.
+    pub Vhdl {
+        type Config = Config;
+        type Format = Format;
+        type Item = Import;
+
+        fn write_comment(out: &mut fmt::Formatter<'_>, kind: CommentKind, text: &str) -> fmt::Result {
+            match kind {
+                CommentKind::Line => crate::lang::write_line_comment(out, "--", text),
+                // NB: Block comments were added in VHDL-2008.
+                CommentKind::Block => crate::lang::write_block_comment(out, "/*", "*/", text),
+            }
+        }
+
+        fn sanitize_ident(ident: ItemStr) -> ItemStr {
+            // Reserved words can be used as extended identifiers, in which
+            // any backslashes are doubled.
+            if KEYWORDS.iter().any(|k| k.eq_ignore_ascii_case(&ident)) {
+                return format!("\\{}\\", ident.replace('\\', "\\\\")).into();
+            }
+
+            ident
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            for c in input.chars() {
+                match c {
+                    '"' => out.write_str("\"\"")?,
+                    '\n' => out.write_str("\" & LF & \"")?,
+                    '\r' => out.write_str("\" & CR & \"")?,
+                    '\t' => out.write_str("\" & HT & \"")?,
+                    c => out.write_char(c)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            let mut header = Tokens::new();
+
+            Self::imports(&mut header, out, tokens);
+            let format = Format::default();
+            header.format(out, config, &format)?;
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+    }
+
+    Import {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            out.write_str(&self.name)?;
+            Ok(())
+        }
+    }
+}
+
+/// An imported name from a package, which causes `library` and `use` clauses
+/// to be added to the file.
+///
+/// Created through the [import()] function.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+pub struct Import {
+    /// The package the name is imported from, like `ieee.std_logic_1164`.
+    package: ItemStr,
+    /// The imported name.
+    name: ItemStr,
+    /// If the whole package should be used with `.all`.
+    all: bool,
+}
+
+impl Import {
+    /// Use everything in the package through `.all`, instead of only the
+    /// imported name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let std_logic = vhdl::import("ieee.std_logic_1164", "std_logic").with_all();
+    /// let unsigned = vhdl::import("IEEE.NUMERIC_STD", "unsigned").with_all();
+    ///
+    /// let toks = quote! {
+    ///     signal a : $std_logic;
+    ///     signal b : $unsigned(7 downto 0);
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "library ieee;",
+    ///         "use ieee.numeric_std.all;",
+    ///         "use ieee.std_logic_1164.all;",
+    ///         "",
+    ///         "signal a : std_logic;",
+    ///         "signal b : unsigned(7 downto 0);",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_all(self) -> Self {
+        Self { all: true, ..self }
+    }
+}
+
+/// Format for VHDL.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Config data for VHDL.
+#[derive(Debug, Clone, Default)]
+pub struct Config {}
+
+impl Vhdl {
+    fn imports(out: &mut Tokens, formatter: &mut fmt::Formatter<'_>, tokens: &Tokens) {
+        // Packages grouped by library, with `None` indicating that the whole
+        // package is used.
+        let mut libraries = BTreeMap::<String, BTreeMap<String, Option<BTreeSet<_>>>>::new();
+
+        for import in tokens.walk_imports() {
+            trace!(package = %import.package, name = %import.name, all = import.all, "collecting use clause");
+            formatter.record_import::<Self>(import);
+            let package = import.package.to_ascii_lowercase();
+
+            let library = match package.split_once('.') {
+                Some((library, _)) => library.to_string(),
+                None => package.clone(),
+            };
+
+            let names = libraries
+                .entry(library)
+                .or_default()
+                .entry(package)
+                .or_insert_with(|| Some(BTreeSet::new()));
+
+            if import.all {
+                *names = None;
+            } else if let Some(names) = names {
+                names.insert(&import.name);
+            }
+        }
+
+        if libraries.is_empty() {
+            return;
+        }
+
+        for (library, packages) in libraries {
+            if !IMPLICIT_LIBRARIES.contains(&library.as_str()) {
+                quote_in!(*out => library $(&library););
+                out.push();
+            }
+
+            for (package, names) in packages {
+                match names {
+                    None => {
+                        quote_in!(*out => use $(&package).all;);
+                        out.push();
+                    }
+                    Some(names) => {
+                        for name in names {
+                            quote_in!(*out => use $(&package).$name;);
+                            out.push();
+                        }
+                    }
+                }
+            }
+        }
+
+        out.line();
+    }
+}
+
+/// Import a name from the given package, like `std_logic` from
+/// `ieee.std_logic_1164`.
+///
+/// This adds a `library` clause for the library of the package unless it's
+/// implicitly declared like `work` or `std`, and a `use` clause for the name.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let std_logic = vhdl::import("ieee.std_logic_1164", "std_logic");
+/// let counter = vhdl::import("work.counters", "counter");
+///
+/// let toks = quote! {
+///     signal clk : $std_logic;
+///     u0 : entity $counter;
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "library ieee;",
+///         "use ieee.std_logic_1164.std_logic;",
+///         "use work.counters.counter;",
+///         "",
+///         "signal clk : std_logic;",
+///         "u0 : entity counter;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn import<P, N>(package: P, name: N) -> Import
+where
+    P: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Import {
+        package: package.into(),
+        name: name.into(),
+        all: false,
+    }
+}
+
+/// Format a comment where each line is preceeded by `--`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: vhdl::Tokens = quote! {
+///     $(vhdl::comment(&["Generated counter.", "Do not edit."]))
+///     entity counter is
+///     end entity;
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "-- Generated counter.",
+///         "-- Do not edit.",
+///         "entity counter is",
+///         "end entity;",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn comment<T>(comment: T) -> Comment<T>
+where
+    T: IntoIterator,
+    T::Item: Into<ItemStr>,
+{
+    Comment(comment)
+}
@@ -0,0 +1,253 @@
+use core::iter;
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate as genco;
+use crate::lang::Xml;
+use crate::tokens::{quoted, FormatInto, ItemStr};
+use crate::Tokens;
+use crate::{quote, quote_in};
+
+use super::text;
+
+/// A child of an [Element].
+#[derive(Debug, Clone)]
+enum Child {
+    /// Escaped text, which is written inline if it's the only child.
+    Text(Tokens<Xml>),
+    /// Any other content, which is written on a line of its own.
+    Block(Tokens<Xml>),
+}
+
+/// An XML element.
+///
+/// An element without children is written as a self-closing tag, an element
+/// with a single text child is written on a single line, and any other
+/// children are indented on lines of their own.
+///
+/// Created through the [element()] function.
+#[derive(Debug, Clone)]
+pub struct Element {
+    name: Tokens<Xml>,
+    attributes: Vec<(Tokens<Xml>, ItemStr)>,
+    children: Vec<Child>,
+    wrap_attributes: bool,
+    namespace_declarations: bool,
+}
+
+impl Element {
+    /// Add an attribute to the element, where the value is escaped.
+    pub fn with_attribute<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: FormatInto<Xml>,
+        V: Into<ItemStr>,
+    {
+        self.attributes.push((quote!($name), value.into()));
+        self
+    }
+
+    /// Add a child to the element, like another [Element].
+    pub fn with_child<C>(mut self, child: C) -> Self
+    where
+        C: FormatInto<Xml>,
+    {
+        self.children.push(Child::Block(quote!($child)));
+        self
+    }
+
+    /// Add text content to the element, which is escaped.
+    pub fn with_text<T>(mut self, content: T) -> Self
+    where
+        T: Into<ItemStr>,
+    {
+        self.children.push(Child::Text(quote!($(text(content)))));
+        self
+    }
+
+    /// Write each attribute on a line of its own, which is how things like
+    /// Android layouts are usually formatted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let button = xml::element("Button")
+    ///     .with_wrapped_attributes(true)
+    ///     .with_attribute("id", "submit")
+    ///     .with_attribute("text", "Submit");
+    ///
+    /// let form = xml::element("Form")
+    ///     .with_wrapped_attributes(true)
+    ///     .with_attribute("id", "form")
+    ///     .with_child(button);
+    ///
+    /// let toks: xml::Tokens = quote!($form);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "<Form",
+    ///         "    id=\"form\">",
+    ///         "    <Button",
+    ///         "        id=\"submit\"",
+    ///         "        text=\"Submit\" />",
+    ///         "</Form>",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_wrapped_attributes(self, wrap_attributes: bool) -> Self {
+        Self {
+            wrap_attributes,
+            ..self
+        }
+    }
+
+    /// Declare the namespaces of all [names][super::Name] used by the element
+    /// and its children as `xmlns:<prefix>` attributes, which is typically
+    /// done on the root element.
+    ///
+    /// See [namespace()][super::namespace] for an example.
+    pub fn with_namespace_declarations(self) -> Self {
+        Self {
+            namespace_declarations: true,
+            ..self
+        }
+    }
+}
+
+impl FormatInto<Xml> for Element {
+    fn format_into(self, tokens: &mut Tokens<Xml>) {
+        let mut attributes = Vec::new();
+
+        if self.namespace_declarations {
+            let mut namespaces = BTreeMap::new();
+
+            let names = self.attributes.iter().map(|(name, _)| name);
+
+            let children = self.children.iter().map(|child| match child {
+                Child::Text(tokens) | Child::Block(tokens) => tokens,
+            });
+
+            for tokens in iter::once(&self.name).chain(names).chain(children) {
+                for name in tokens.walk_imports() {
+                    namespaces.insert(&name.prefix, &name.namespace);
+                }
+            }
+
+            for (prefix, namespace) in namespaces {
+                attributes.push(quote!(xmlns:$prefix=$(quoted(namespace))));
+            }
+        }
+
+        for (name, value) in self.attributes {
+            attributes.push(quote!($name=$(quoted(value))));
+        }
+
+        quote_in!(*tokens => <$(&self.name));
+
+        // NB: Wrapped attributes are indented, and so are any children.
+        let wrapped = self.wrap_attributes && !attributes.is_empty();
+
+        if wrapped {
+            tokens.indent();
+
+            let mut it = attributes.into_iter().peekable();
+
+            while let Some(attribute) = it.next() {
+                tokens.append(attribute);
+
+                if it.peek().is_some() {
+                    tokens.push();
+                }
+            }
+        } else {
+            for attribute in attributes {
+                tokens.space();
+                tokens.append(attribute);
+            }
+        }
+
+        if self.children.is_empty() {
+            tokens.space();
+            tokens.append("/>");
+
+            if wrapped {
+                tokens.unindent();
+            }
+
+            return;
+        }
+
+        tokens.append(">");
+
+        if let [Child::Text(content)] = self.children.as_slice() {
+            tokens.append(content);
+            quote_in!(*tokens => </$(self.name)>);
+
+            if wrapped {
+                tokens.unindent();
+            }
+
+            return;
+        }
+
+        if !wrapped {
+            tokens.indent();
+        }
+
+        for child in self.children {
+            match child {
+                Child::Text(content) | Child::Block(content) => {
+                    tokens.push();
+                    tokens.append(content);
+                }
+            }
+        }
+
+        tokens.unindent();
+        quote_in!(*tokens => </$(self.name)>);
+    }
+}
+
+/// Construct an element with the given name, which can be a plain string or a
+/// [name in a namespace][super::Namespace::name].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: xml::Tokens = quote! {
+///     $(xml::element("br"))
+///     $(xml::element("img").with_attribute("alt", "\"quoted\" & <escaped>"))
+///     $(xml::element("p").with_text("Hello").with_text("World"))
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "<br />",
+///         "<img alt=\"&quot;quoted&quot; &amp; &lt;escaped&gt;\" />",
+///         "<p>",
+///         "    Hello",
+///         "    World",
+///         "</p>",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn element<N>(name: N) -> Element
+where
+    N: FormatInto<Xml>,
+{
+    Element {
+        name: quote!($name),
+        attributes: Vec::new(),
+        children: Vec::new(),
+        wrap_attributes: false,
+        namespace_declarations: false,
+    }
+}
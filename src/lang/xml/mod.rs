@@ -0,0 +1,357 @@
+//! Specialization for XML code generation, like Android layouts or HTML
+//! fragments.
+//!
+//! # String Quoting in XML
+//!
+//! Quoted strings are intended to be used as attribute values, so the
+//! characters which have a special meaning in them are written as entity
+//! references.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! let toks: xml::Tokens = quote!(<a href=$(quoted("/search?q=\"genco\"&page=2"))>);
+//! assert_eq!(
+//!     "<a href=\"/search?q=&quot;genco&quot;&amp;page=2\">",
+//!     toks.to_string()?
+//! );
+//! # Ok::<_, genco::fmt::Error>(())
+//! ```
+//!
+//! Comments are written as `<!-- -->`.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! let mut toks = xml::Tokens::new();
+//! toks.comment("Generated -- do not edit");
+//! toks.push();
+//! toks.append("<resources />");
+//!
+//! assert_eq!(
+//!     vec!["<!-- Generated - - do not edit -->", "<resources />"],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok::<_, genco::fmt::Error>(())
+//! ```
+//!
+//! Text content is escaped with [text()], and can be written verbatim in a
+//! CDATA section with [cdata()]. Elements can be built with [element()], which
+//! takes care of writing empty elements as self-closing tags.
+//!
+//! ```rust
+//! use genco::prelude::*;
+//!
+//! let list = xml::element("ul")
+//!     .with_attribute("class", "items")
+//!     .with_child(xml::element("li").with_text("Fish & Chips"))
+//!     .with_child(xml::element("li").with_child(xml::cdata("<b>bold</b>")))
+//!     .with_child(xml::element("br"));
+//!
+//! let toks: xml::Tokens = quote!($list);
+//!
+//! assert_eq!(
+//!     vec![
+//!         "<ul class=\"items\">",
+//!         "    <li>Fish &amp; Chips</li>",
+//!         "    <li>",
+//!         "        <![CDATA[<b>bold</b>]]>",
+//!         "    </li>",
+//!         "    <br />",
+//!         "</ul>",
+//!     ],
+//!     toks.to_file_vec()?
+//! );
+//! # Ok::<_, genco::fmt::Error>(())
+//! ```
+
+mod element;
+pub use self::element::{element, Element};
+
+use core::fmt::Write as _;
+
+use alloc::string::String;
+
+use crate::fmt;
+use crate::tokens::{CommentKind, FormatInto, Item, ItemStr};
+
+/// Tokens container specialization for XML.
+pub type Tokens = crate::Tokens<Xml>;
+
+impl_lang! {
+    /// Language specialization for XML.
+    pub Xml {
+        type Config = Config;
+        type Format = Format;
+        type Item = Name;
+
+        fn write_comment(out: &mut fmt::Formatter<'_>, _: CommentKind, text: &str) -> fmt::Result {
+            out.write_str("<!--")?;
+
+            if !text.is_empty() {
+                out.write_char(' ')?;
+                out.write_str(&text.replace("--", "- -"))?;
+                out.write_char(' ')?;
+            }
+
+            out.write_str("-->")?;
+            Ok(())
+        }
+
+        fn write_quoted(out: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+            for c in input.chars() {
+                match c {
+                    '&' => out.write_str("&amp;")?,
+                    '<' => out.write_str("&lt;")?,
+                    '>' => out.write_str("&gt;")?,
+                    '"' => out.write_str("&quot;")?,
+                    '\n' => out.write_str("&#10;")?,
+                    '\t' => out.write_str("&#9;")?,
+                    c => out.write_char(c)?,
+                }
+            }
+
+            Ok(())
+        }
+
+        fn format_file(
+            tokens: &Tokens,
+            out: &mut fmt::Formatter<'_>,
+            config: &Self::Config,
+        ) -> fmt::Result {
+            if config.declaration {
+                out.write_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>")?;
+                out.push();
+            }
+
+            let format = Format::default();
+            tokens.format(out, config, &format)?;
+            Ok(())
+        }
+    }
+
+    Name {
+        fn format(&self, out: &mut fmt::Formatter<'_>, _: &Config, _: &Format) -> fmt::Result {
+            out.write_str(&self.prefix)?;
+            out.write_char(':')?;
+            out.write_str(&self.local)?;
+            Ok(())
+        }
+    }
+}
+
+/// A name in an XML namespace, like `android:layout_width`.
+///
+/// Created through [Namespace::name].
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+pub struct Name {
+    /// The prefix the namespace is bound to.
+    prefix: ItemStr,
+    /// The URI of the namespace.
+    namespace: ItemStr,
+    /// The local part of the name.
+    local: ItemStr,
+}
+
+/// An XML namespace bound to a prefix.
+///
+/// Created through the [namespace()] function.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Namespace {
+    prefix: ItemStr,
+    namespace: ItemStr,
+}
+
+impl Namespace {
+    /// Construct a name in this namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let android = xml::namespace("android", "http://schemas.android.com/apk/res/android");
+    ///
+    /// let toks: xml::Tokens = quote!(<TextView $(android.name("text"))="Hello" />);
+    /// assert_eq!("<TextView android:text=\"Hello\" />", toks.to_string()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn name<N>(&self, local: N) -> Name
+    where
+        N: Into<ItemStr>,
+    {
+        Name {
+            prefix: self.prefix.clone(),
+            namespace: self.namespace.clone(),
+            local: local.into(),
+        }
+    }
+}
+
+/// Format state for XML.
+#[derive(Debug, Default)]
+pub struct Format {}
+
+/// Config data for XML.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    declaration: bool,
+}
+
+impl Config {
+    /// Write an XML declaration at the top of the file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let toks: xml::Tokens = quote!(<resources />);
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// let fmt = fmt::Config::from_lang::<Xml>();
+    /// let config = xml::Config::default().with_declaration(true);
+    ///
+    /// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "<?xml version=\"1.0\" encoding=\"utf-8\"?>",
+    ///         "<resources />",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_declaration(self, declaration: bool) -> Self {
+        Self { declaration }
+    }
+}
+
+/// Bind an XML namespace to a prefix.
+///
+/// Names in the namespace are constructed through [Namespace::name], and
+/// [Element::with_namespace_declarations] declares the namespaces used by an
+/// element.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let android = xml::namespace("android", "http://schemas.android.com/apk/res/android");
+///
+/// let layout = xml::element("LinearLayout")
+///     .with_namespace_declarations()
+///     .with_attribute(android.name("orientation"), "vertical")
+///     .with_child(xml::element("TextView").with_attribute(android.name("text"), "Hello"));
+///
+/// let toks: xml::Tokens = quote!($layout);
+///
+/// assert_eq!(
+///     vec![
+///         "<LinearLayout xmlns:android=\"http://schemas.android.com/apk/res/android\" android:orientation=\"vertical\">",
+///         "    <TextView android:text=\"Hello\" />",
+///         "</LinearLayout>",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn namespace<P, N>(prefix: P, namespace: N) -> Namespace
+where
+    P: Into<ItemStr>,
+    N: Into<ItemStr>,
+{
+    Namespace {
+        prefix: prefix.into(),
+        namespace: namespace.into(),
+    }
+}
+
+/// Text content, where the characters which have a special meaning in XML
+/// are written as entity references.
+///
+/// Created through the [text()] function.
+#[derive(Debug, Clone)]
+pub struct Text {
+    text: ItemStr,
+}
+
+impl FormatInto<Xml> for Text {
+    fn format_into(self, tokens: &mut Tokens) {
+        let mut escaped = String::with_capacity(self.text.len());
+
+        for c in self.text.chars() {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                c => escaped.push(c),
+            }
+        }
+
+        tokens.item(Item::Literal(ItemStr::from(escaped)));
+    }
+}
+
+/// Escape text content.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: xml::Tokens = quote!(<p>$(xml::text("1 < 2 && 3 > 2"))</p>);
+/// assert_eq!("<p>1 &lt; 2 &amp;&amp; 3 &gt; 2</p>", toks.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn text<T>(text: T) -> Text
+where
+    T: Into<ItemStr>,
+{
+    Text { text: text.into() }
+}
+
+/// A CDATA section, which contains text that is written as-is.
+///
+/// Created through the [cdata()] function.
+#[derive(Debug, Clone)]
+pub struct Cdata {
+    text: ItemStr,
+}
+
+impl FormatInto<Xml> for Cdata {
+    fn format_into(self, tokens: &mut Tokens) {
+        let mut out = String::with_capacity(self.text.len() + 12);
+        out.push_str("<![CDATA[");
+        // NB: A CDATA section can't contain its terminator, so it's split
+        // across two sections.
+        out.push_str(&self.text.replace("]]>", "]]]]><![CDATA[>"));
+        out.push_str("]]>");
+        tokens.item(Item::Literal(ItemStr::from(out)));
+    }
+}
+
+/// Wrap text in a CDATA section.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let toks: xml::Tokens = quote!(<script>$(xml::cdata("if (a < b && c) {}"))</script>);
+/// assert_eq!("<script><![CDATA[if (a < b && c) {}]]></script>", toks.to_string()?);
+///
+/// let toks: xml::Tokens = quote!($(xml::cdata("a]]>b")));
+/// assert_eq!("<![CDATA[a]]]]><![CDATA[>b]]>", toks.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn cdata<T>(text: T) -> Cdata
+where
+    T: Into<ItemStr>,
+{
+    Cdata { text: text.into() }
+}
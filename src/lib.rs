@@ -83,6 +83,15 @@
 //!
 //! <small>Is your favorite language missing? <b>[Open an issue!]</b></small>
 //!
+//! Each language is behind a feature named after its module, like
+//! `lang-rust` or `lang-java`. They're all enabled by default through the
+//! `all-langs` feature, so to only compile the languages you use, disable the
+//! default features:
+//!
+//! ```toml
+//! genco = { version = "0.17", default-features = false, features = ["std", "lang-rust"] }
+//! ```
+//!
 //! You can run one of the examples by:
 //!
 //! ```bash
@@ -287,6 +296,10 @@ compile_error!("genco: The `alloc` feature must be enabled");
 /// * `$[' ']` — Inserts spacing between tokens. This corresponds to the
 ///   [Tokens::space] function.
 ///
+/// * `$[~]` — Inserts a collapsing space, which is only written between two
+///   tokens on the same line and is absorbed by any other space next to it.
+///   This corresponds to the [Tokens::soft_space] function.
+///
 /// * `$['\r']` — Inserts a push operation. Push operations makes sure that
 ///   any following tokens are on their own dedicated line. This corresponds to
 ///   the [Tokens::push] function.
@@ -306,6 +319,31 @@ compile_error!("genco: The `alloc` feature must be enabled");
 /// # Ok::<_, genco::fmt::Error>(())
 /// ```
 ///
+/// * `$[indent]` — Increases the indentation level. This corresponds to the
+///   [Tokens::indent] function.
+///
+/// * `$[unindent]` — Decreases the indentation level. This corresponds to the
+///   [Tokens::unindent] function.
+///
+/// These give manual control over indentation in places where it can't be
+/// expressed through whitespace, like when all tokens are on the same line.
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let tokens: Tokens<()> = quote!(foo {$[indent]bar();$[unindent]});
+///
+/// assert_eq!(
+///     vec![
+///         "foo {",
+///         "    bar();",
+///         "}",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
 /// <br>
 ///
 /// # String Quoting
@@ -639,6 +677,42 @@ compile_error!("genco: The `alloc` feature must be enabled");
 ///
 /// <br>
 ///
+/// # Whitespace Sensitivity
+///
+/// By default, bodies delimited by braces (`{ <quoted> }`) have their leading
+/// and trailing whitespace trimmed, while match arms delimited by parenthesis
+/// (`=> (<quoted>)`) preserve it. This can instead be declared explicitly with
+/// the `#[ws(preserve)]` and `#[ws(trim)]` attributes.
+///
+/// The attribute can be put in front of `if`, `for`, and `match` expressions,
+/// in which case it applies to all of their bodies, or in front of individual
+/// match arms.
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let numbers = 3..=5;
+///
+/// let tokens: Tokens<()> = quote! {
+///     Numbers:$(#[ws(preserve)] for n in numbers { $n }).
+/// };
+///
+/// assert_eq!("Numbers: 3 4 5 .", tokens.to_string()?);
+///
+/// fn greeting(name: &str) -> Tokens<()> {
+///     quote!(Hello $(match name {
+///         #[ws(trim)]
+///         "John" | "Jane" => ( $("Random Stranger") ),
+///         other => $other,
+///     }))
+/// }
+///
+/// assert_eq!("Hello Random Stranger", greeting("John").to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
+/// <br>
+///
 /// # Variable assignment
 ///
 /// You can use `$(let <binding> = <expr>)` to define variables with their value.
@@ -710,6 +784,19 @@ compile_error!("genco: The `alloc` feature must be enabled");
 /// # Ok::<_, genco::fmt::Error>(())
 /// ```
 ///
+/// A closure which takes the token stream can also be interpolated directly,
+/// which is useful if it needs to take ownership of something.
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let names = vec![String::from("a"), String::from("b")];
+/// let tokens: rust::Tokens = quote!(let all = [$(move |t| t.append_separated(names, ", "))];);
+///
+/// assert_eq!("let all = [a, b];", tokens.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
 /// <br>
 ///
 /// ## Whitespace Detection
@@ -915,6 +1002,97 @@ pub use genco_macros::quote;
 /// ```
 pub use genco_macros::quote_fn;
 
+/// Behaves the same as [quote!], but builds a [StaticTokens] which can be
+/// stored in a `static` or `const`.
+///
+/// This is useful for fixed fragments such as license headers or common
+/// attributes, which can then be shared without being rebuilt each time they
+/// are used. Only static content is supported, so interpolation and control
+/// flow like `$(..)`, `for` and `if` are compile errors.
+///
+/// [StaticTokens]: crate::tokens::StaticTokens
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::StaticTokens;
+///
+/// const LICENSE: StaticTokens<Rust> = static_quote! {
+///     $("// SPDX-License-Identifier: MIT")
+/// };
+///
+/// static DERIVES: StaticTokens<Rust> = static_quote! {
+///     #[derive(Debug, Clone, Copy)]
+/// };
+///
+/// let tokens: rust::Tokens = quote! {
+///     $LICENSE
+///
+///     $DERIVES
+///     struct Foo;
+/// };
+///
+/// assert_eq! {
+///     vec![
+///         "// SPDX-License-Identifier: MIT",
+///         "",
+///         "#[derive(Debug, Clone, Copy)]",
+///         "struct Foo;",
+///     ],
+///     tokens.to_file_vec()?
+/// };
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
+/// Interpolation is rejected:
+///
+/// ```compile_fail
+/// use genco::prelude::*;
+/// use genco::tokens::StaticTokens;
+///
+/// static NAME: &str = "foo";
+/// static TOKENS: StaticTokens<Rust> = static_quote!(fn $NAME() {});
+/// ```
+pub use genco_macros::static_quote;
+
+/// Behaves the same as [quote!], but captures the quoted content as a
+/// `&'static str` instead of building a token stream.
+///
+/// The same whitespace detection as [quote!] is used, so the string is the
+/// same as what formatting the equivalent [rust::Tokens] with the default
+/// configuration would produce. That is four spaces of indentation, and quoted
+/// strings in double quotes which are escaped like Rust string literals. Other
+/// languages might format the same tokens differently. This is useful when the
+/// template itself needs to be emitted, like when generating generators.
+///
+/// [rust::Tokens]: crate::lang::rust::Tokens
+///
+/// Like [static_quote!], only static content is supported.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// const TEMPLATE: &str = quote_literal! {
+///     fn main() {
+///         println!("Hello World");
+///     }
+/// };
+///
+/// let tokens: rust::Tokens = quote! {
+///     fn main() {
+///         println!("Hello World");
+///     }
+/// };
+///
+/// assert_eq!(TEMPLATE, tokens.to_string()?);
+/// assert_eq!(TEMPLATE, "fn main() {\n    println!(\"Hello World\");\n}");
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub use genco_macros::quote_literal;
+
 /// Behaves the same as [quote!] while quoting into an existing token stream
 /// with `<target> => <quoted>`.
 ///
@@ -983,9 +1161,15 @@ pub use genco_macros::quote_in;
 
 #[macro_use]
 mod macros;
+#[cfg(feature = "contrib")]
+pub mod contrib;
+pub mod diff;
 pub mod fmt;
 pub mod lang;
 pub mod prelude;
+pub mod template;
+#[cfg(feature = "std")]
+pub mod testing;
 pub mod tokens;
 
 pub use self::tokens::Tokens;
@@ -129,6 +129,8 @@ macro_rules! impl_lang {
         }
 
         /// A type-erased language item capable of holding any kind.
+        // NB: Unused by languages which use a single item type directly.
+        #[allow(dead_code)]
         #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
         $vis enum Any {
             $(
@@ -156,6 +158,12 @@ macro_rules! impl_lang {
                     $(Self::$ty(lang) => lang.format(out, config, format),)*
                 }
             }
+
+            fn dependencies(&self) -> &[<$lang as $crate::lang::Lang>::Item] {
+                match self {
+                    $(Self::$ty(lang) => lang.dependencies(),)*
+                }
+            }
         }
 
         $(
@@ -189,3 +197,61 @@ macro_rules! impl_lang {
         )*
     }
 }
+
+/// Emit a trace event if the `tracing` feature is enabled.
+macro_rules! trace {
+    ($($tt:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::trace!($($tt)*);
+    };
+}
+
+/// Implement the builders which configure the naming conventions of a
+/// language configuration with a `naming` field.
+///
+/// Additional documentation for `with_field_naming`, like examples, can be
+/// passed after the name of the configuration.
+#[cfg(any(
+    feature = "lang-csharp",
+    feature = "lang-dart",
+    feature = "lang-go",
+    feature = "lang-java",
+    feature = "lang-js",
+    feature = "lang-python",
+    feature = "lang-rust",
+    feature = "lang-swift",
+))]
+macro_rules! impl_naming {
+    ($config:ident $(, $(#[$field_meta:meta])*)?) => {
+        impl $config {
+            /// Configure the naming convention to apply to field and variable
+            /// names constructed through [Ident::field].
+            ///
+            $($(#[$field_meta])*)?
+            ///
+            /// [Ident::field]: crate::tokens::Ident::field
+            pub fn with_field_naming(mut self, case: $crate::tokens::Case) -> Self {
+                self.naming.field = Some(case);
+                self
+            }
+
+            /// Configure the naming convention to apply to function and
+            /// method names constructed through [Ident::function].
+            ///
+            /// [Ident::function]: crate::tokens::Ident::function
+            pub fn with_function_naming(mut self, case: $crate::tokens::Case) -> Self {
+                self.naming.function = Some(case);
+                self
+            }
+
+            /// Configure the naming convention to apply to type names
+            /// constructed through [TypeName].
+            ///
+            /// [TypeName]: crate::tokens::TypeName
+            pub fn with_type_naming(mut self, case: $crate::tokens::Case) -> Self {
+                self.naming.type_name = Some(case);
+                self
+            }
+        }
+    };
+}
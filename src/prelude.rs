@@ -2,4 +2,4 @@
 
 pub use crate::lang::*;
 pub use crate::tokens::{display, quoted, register, FormatInto};
-pub use crate::{quote, quote_fn, quote_in, Tokens};
+pub use crate::{quote, quote_fn, quote_in, quote_literal, static_quote, Tokens};
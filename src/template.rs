@@ -0,0 +1,491 @@
+//! Templates which are parsed at runtime.
+//!
+//! This allows for snippets of code to be provided through something like a
+//! configuration file, while still following the same whitespace rules as the
+//! [quote!] macro:
+//!
+//! * Tokens separated by whitespace on the same line are separated by a
+//!   [space][Tokens::space].
+//! * Each line is [pushed][Tokens::push], and one or more empty lines
+//!   between two lines results in a [line][Tokens::line].
+//! * Increasing the indentation of a line [indents][Tokens::indent] it, and
+//!   decreasing it back to the indentation of an earlier line
+//!   [unindents][Tokens::unindent] it.
+//!
+//! Values are interpolated with `$name` or `$(name)`, and a literal `$` is
+//! written as `$$`.
+//!
+//! Double-quoted strings like `"hello\n"` are unescaped and then quoted for
+//! the target language, like string literals in [quote!]. They support the
+//! escapes `\n`, `\r`, `\t`, `\0`, `\\`, `\"` and `\'`. Unlike in [quote!],
+//! values can be interpolated into them directly, like `"hello $name"`, which
+//! works the same as `$[str](hello $(name))` does there.
+//!
+//! Everything else is added to the token stream as-is.
+//!
+//! [quote!]: crate::quote
+//!
+//! # Examples
+//!
+//! ```
+//! use genco::prelude::*;
+//! use genco::template;
+//!
+//! let template = template::parse::<Rust>("
+//!     fn $name() -> $(ty) {
+//!         $$crate::$name()
+//!     }
+//! ")?;
+//!
+//! let map = rust::import("std::collections", "HashMap");
+//!
+//! let tokens = template.render([
+//!     ("name", quote!(create)),
+//!     ("ty", quote!($map<u32, u32>)),
+//! ])?;
+//!
+//! assert_eq!(
+//!     vec![
+//!         "use std::collections::HashMap;",
+//!         "",
+//!         "fn create() -> HashMap<u32, u32> {",
+//!         "    $crate::create()",
+//!         "}",
+//!     ],
+//!     tokens.to_file_vec()?
+//! );
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+use core::fmt;
+use core::iter::Peekable;
+use core::mem;
+use core::str::CharIndices;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, Item, ItemStr, UnboundParam};
+use crate::Tokens;
+
+/// Parse a template.
+///
+/// See the [module level documentation][self] for the syntax of templates.
+///
+/// # Errors
+///
+/// Errors if a line is unindented to a level which doesn't match any earlier
+/// line, if a `$` isn't followed by a name, or if a string isn't closed or
+/// contains an unknown escape.
+///
+/// ```
+/// use genco::template;
+///
+/// let error = template::parse::<()>("a\n    b\n  c").unwrap_err();
+/// assert_eq!("3:3: unindent does not match any outer indentation level", error.to_string());
+///
+/// let error = template::parse::<()>("a $!").unwrap_err();
+/// assert_eq!("1:3: expected a name after `$`", error.to_string());
+///
+/// let error = template::parse::<()>("é \"$name").unwrap_err();
+/// assert_eq!("1:3: missing closing `\"`", error.to_string());
+/// ```
+pub fn parse<L>(template: &str) -> Result<Template<L>, TemplateError>
+where
+    L: Lang,
+{
+    let mut tokens = Tokens::new();
+    let mut params = Vec::new();
+    // Indentation levels of the enclosing lines, where the first line sets
+    // the base level.
+    let mut levels = Vec::<usize>::new();
+    let mut empty = false;
+
+    for (n, line) in template.lines().enumerate() {
+        let content = line.trim_start();
+
+        if content.trim_end().is_empty() {
+            empty = !levels.is_empty();
+            continue;
+        }
+
+        let indent = line[..line.len() - content.len()].chars().count();
+
+        match levels.last().copied() {
+            None => {
+                levels.push(indent);
+            }
+            Some(last) if indent > last => {
+                levels.push(indent);
+                tokens.indent();
+
+                if empty {
+                    tokens.line();
+                }
+            }
+            Some(last) if indent < last => {
+                while levels.last().map_or(false, |&level| level > indent) {
+                    levels.pop();
+                    tokens.unindent();
+                }
+
+                if levels.last() != Some(&indent) {
+                    return Err(TemplateError::new(
+                        n + 1,
+                        indent + 1,
+                        ErrorKind::UnindentMismatch,
+                    ));
+                }
+
+                if empty {
+                    tokens.line();
+                }
+            }
+            Some(..) => {
+                if empty {
+                    tokens.line();
+                } else {
+                    tokens.push();
+                }
+            }
+        }
+
+        empty = false;
+        parse_line(
+            &mut tokens,
+            &mut params,
+            content.trim_end(),
+            n + 1,
+            indent + 1,
+        )?;
+    }
+
+    Ok(Template { tokens, params })
+}
+
+/// Parse the content of a single line.
+fn parse_line<L>(
+    tokens: &mut Tokens<L>,
+    params: &mut Vec<ItemStr>,
+    line: &str,
+    line_number: usize,
+    column: usize,
+) -> Result<(), TemplateError>
+where
+    L: Lang,
+{
+    // NB: Columns count characters, while `i` is a byte offset into `line`.
+    let error =
+        |i: usize, kind| TemplateError::new(line_number, column + line[..i].chars().count(), kind);
+
+    let mut literal = String::new();
+    let mut it = line.char_indices().peekable();
+
+    while let Some((i, c)) = it.next() {
+        if c.is_whitespace() {
+            flush(tokens, &mut literal);
+
+            while it.peek().map_or(false, |(_, c)| c.is_whitespace()) {
+                it.next();
+            }
+
+            tokens.space();
+            continue;
+        }
+
+        if c == '"' {
+            flush(tokens, &mut literal);
+            parse_string(tokens, params, line, &mut it, i).map_err(|(i, kind)| error(i, kind))?;
+            continue;
+        }
+
+        if c != '$' {
+            literal.push(c);
+            continue;
+        }
+
+        let name = match parse_name(line, &mut it, i).map_err(|kind| error(i, kind))? {
+            Some(name) => name,
+            None => {
+                literal.push('$');
+                continue;
+            }
+        };
+
+        flush(tokens, &mut literal);
+        tokens.item(Item::Param(param(params, name)));
+    }
+
+    flush(tokens, &mut literal);
+    Ok(())
+}
+
+/// Parse a quoted string, after its opening `"` at byte offset `start`.
+///
+/// The string is unescaped and quoted like a string literal in [quote!], and
+/// values interpolated into it are added as evaluations like in `$[str](..)`.
+///
+/// Errors are reported as the byte offset at which they occurred.
+///
+/// [quote!]: crate::quote
+fn parse_string<'a, L>(
+    tokens: &mut Tokens<L>,
+    params: &mut Vec<ItemStr>,
+    line: &'a str,
+    it: &mut Peekable<CharIndices<'a>>,
+    start: usize,
+) -> Result<(), (usize, ErrorKind)>
+where
+    L: Lang,
+{
+    let mut items = Vec::new();
+    let mut literal = String::new();
+    let mut has_eval = false;
+
+    loop {
+        let Some((i, c)) = it.next() else {
+            return Err((start, ErrorKind::UnclosedString));
+        };
+
+        match c {
+            '"' => break,
+            '\\' => {
+                let escaped = match it.next() {
+                    Some((_, 'n')) => '\n',
+                    Some((_, 'r')) => '\r',
+                    Some((_, 't')) => '\t',
+                    Some((_, '0')) => '\0',
+                    Some((_, c @ ('\\' | '"' | '\''))) => c,
+                    _ => return Err((i, ErrorKind::UnknownEscape)),
+                };
+
+                literal.push(escaped);
+            }
+            '$' => match parse_name(line, it, i).map_err(|kind| (i, kind))? {
+                Some(name) => {
+                    if !literal.is_empty() {
+                        items.push(Item::Literal(ItemStr::from(mem::take(&mut literal))));
+                    }
+
+                    items.push(Item::OpenEval);
+                    items.push(Item::Param(param(params, name)));
+                    items.push(Item::CloseEval);
+                    has_eval = true;
+                }
+                None => literal.push('$'),
+            },
+            c => literal.push(c),
+        }
+    }
+
+    if !literal.is_empty() {
+        items.push(Item::Literal(ItemStr::from(literal)));
+    }
+
+    tokens.item(Item::OpenQuote(has_eval));
+
+    for item in items {
+        tokens.item(item);
+    }
+
+    tokens.item(Item::CloseQuote);
+    Ok(())
+}
+
+/// Parse the name of an interpolated value after the `$` at byte offset `i`,
+/// or `None` if it's an escaped `$$`.
+fn parse_name<'a>(
+    line: &'a str,
+    it: &mut Peekable<CharIndices<'a>>,
+    i: usize,
+) -> Result<Option<&'a str>, ErrorKind> {
+    let name = match it.peek().copied() {
+        Some((_, '$')) => {
+            it.next();
+            return Ok(None);
+        }
+        Some((start, '(')) => {
+            it.next();
+
+            let end = loop {
+                match it.next() {
+                    Some((end, ')')) => break end,
+                    Some(..) => (),
+                    None => return Err(ErrorKind::Unclosed),
+                }
+            };
+
+            line[start + 1..end].trim()
+        }
+        _ => {
+            let start = i + 1;
+            let mut end = start;
+
+            while let Some((n, c)) = it.peek().copied() {
+                if !(c == '_' || c.is_alphanumeric()) {
+                    break;
+                }
+
+                end = n + c.len_utf8();
+                it.next();
+            }
+
+            &line[start..end]
+        }
+    };
+
+    let mut chars = name.chars();
+
+    if !(matches!(chars.next(), Some(c) if c == '_' || c.is_alphabetic())
+        && chars.all(|c| c == '_' || c.is_alphanumeric()))
+    {
+        return Err(ErrorKind::ExpectedName);
+    }
+
+    Ok(Some(name))
+}
+
+/// Record the name of an interpolated value.
+fn param(params: &mut Vec<ItemStr>, name: &str) -> ItemStr {
+    let name = ItemStr::from(name);
+
+    if !params.contains(&name) {
+        params.push(name.clone());
+    }
+
+    name
+}
+
+fn flush<L>(tokens: &mut Tokens<L>, literal: &mut String)
+where
+    L: Lang,
+{
+    if !literal.is_empty() {
+        tokens.item(Item::Literal(ItemStr::from(mem::take(literal))));
+    }
+}
+
+/// A template which has been parsed at runtime.
+///
+/// Constructed through [parse()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template<L>
+where
+    L: Lang,
+{
+    tokens: Tokens<L>,
+    params: Vec<ItemStr>,
+}
+
+impl<L> Template<L>
+where
+    L: Lang,
+{
+    /// The names of the values interpolated in the template, in the order
+    /// they first appear.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::template;
+    ///
+    /// let template = template::parse::<()>("$a = $(b) + $a")?;
+    /// assert_eq!(vec!["a", "b"], template.params().collect::<Vec<_>>());
+    /// # Ok::<_, genco::template::TemplateError>(())
+    /// ```
+    pub fn params(&self) -> impl Iterator<Item = &str> + '_ {
+        self.params.iter().map(|name| &**name)
+    }
+
+    /// Render the template by replacing every interpolated name with the
+    /// value of the same name.
+    ///
+    /// Values which are not used are ignored. To use values of different
+    /// types, they can be boxed through [FormatInto::boxed].
+    ///
+    /// # Errors
+    ///
+    /// Errors if the template interpolates a name which has no value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::template;
+    ///
+    /// let template = template::parse::<Rust>("let $name: $ty = $value;")?;
+    ///
+    /// let tokens = template.render([
+    ///     ("name", "answer".boxed()),
+    ///     ("ty", quote!(u32).boxed()),
+    ///     ("value", 42u32.boxed()),
+    /// ])?;
+    ///
+    /// assert_eq!("let answer: u32 = 42;", tokens.to_string()?);
+    ///
+    /// let error = template.render([("name", "answer")]).unwrap_err();
+    /// assert_eq!("ty", error.name());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn render<I, N, T>(&self, values: I) -> Result<Tokens<L>, UnboundParam>
+    where
+        I: IntoIterator<Item = (N, T)>,
+        N: Into<ItemStr>,
+        T: FormatInto<L>,
+    {
+        self.tokens.bind(values)
+    }
+}
+
+/// Error raised when parsing a template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateError {
+    line: usize,
+    column: usize,
+    kind: ErrorKind,
+}
+
+impl TemplateError {
+    fn new(line: usize, column: usize, kind: ErrorKind) -> Self {
+        Self { line, column, kind }
+    }
+
+    /// The one-based line at which the error occurred.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The one-based column at which the error occurred.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+
+        match self.kind {
+            ErrorKind::UnindentMismatch => {
+                write!(f, "unindent does not match any outer indentation level")
+            }
+            ErrorKind::ExpectedName => write!(f, "expected a name after `$`"),
+            ErrorKind::Unclosed => write!(f, "missing closing `)`"),
+            ErrorKind::UnclosedString => write!(f, "missing closing `\"`"),
+            ErrorKind::UnknownEscape => write!(f, "unknown escape in string"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TemplateError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ErrorKind {
+    UnindentMismatch,
+    ExpectedName,
+    Unclosed,
+    UnclosedString,
+    UnknownEscape,
+}
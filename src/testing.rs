@@ -0,0 +1,172 @@
+//! Helpers for testing code generators against golden files.
+//!
+//! A golden file is a checked-in copy of the expected output of a generator.
+//! [golden()] formats a token stream and compares it against such a file,
+//! panicking with a [unified diff][crate::diff::unified] if they differ.
+//!
+//! When the output changes on purpose, run the tests with the
+//! [`UPDATE_GOLDEN`][UPDATE_GOLDEN] environment variable set to write the new
+//! output to the golden files instead:
+//!
+//! ```text
+//! UPDATE_GOLDEN=1 cargo test
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use genco::prelude::*;
+//! use genco::testing;
+//!
+//! # let dir = std::env::temp_dir().join("genco-testing-doc");
+//! # std::fs::create_dir_all(&dir)?;
+//! # std::fs::write(dir.join("hello.rs"), "fn hello() -> u32 {\n    42\n}\n")?;
+//! let tokens: rust::Tokens = quote! {
+//!     fn hello() -> u32 {
+//!         42
+//!     }
+//! };
+//!
+//! testing::golden(dir.join("hello.rs"), &tokens);
+//! # Ok::<_, std::io::Error>(())
+//! ```
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::diff;
+use crate::fmt;
+use crate::lang::Lang;
+use crate::Tokens;
+
+/// The environment variable which causes golden files to be updated instead
+/// of compared against.
+///
+/// Any value other than an empty string or `0` enables updating.
+pub const UPDATE_GOLDEN: &str = "UPDATE_GOLDEN";
+
+/// Format the token stream as a file and compare it against the golden file
+/// at the given path, using the default configuration of the language.
+///
+/// # Panics
+///
+/// Panics if the golden file is missing or doesn't match the formatted
+/// output, unless [`UPDATE_GOLDEN`][UPDATE_GOLDEN] is set in which case the
+/// golden file is written.
+#[track_caller]
+pub fn golden<P, L>(path: P, tokens: &Tokens<L>)
+where
+    P: AsRef<Path>,
+    L: Lang,
+    L::Config: Default,
+{
+    let fmt = fmt::Config::from_lang::<L>();
+    golden_with(path, tokens, &fmt, &L::Config::default());
+}
+
+/// Format the token stream as a file with the given configuration and compare
+/// it against the golden file at the given path.
+///
+/// See [golden()] for details.
+///
+/// # Examples
+///
+/// ```
+/// use genco::fmt;
+/// use genco::prelude::*;
+/// use genco::testing;
+///
+/// # let dir = std::env::temp_dir().join("genco-testing-with-doc");
+/// # std::fs::create_dir_all(&dir)?;
+/// # std::fs::write(dir.join("main.go"), "package main\n\nfunc main() {\n}\n")?;
+/// let tokens: go::Tokens = quote! {
+///     func main() {
+///     }
+/// };
+///
+/// let fmt = fmt::Config::from_lang::<Go>();
+/// let config = go::Config::default().with_package("main");
+///
+/// testing::golden_with(dir.join("main.go"), &tokens, &fmt, &config);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[track_caller]
+pub fn golden_with<P, L>(path: P, tokens: &Tokens<L>, fmt: &fmt::Config, config: &L::Config)
+where
+    P: AsRef<Path>,
+    L: Lang,
+{
+    let path = path.as_ref();
+
+    let mut w = fmt::FmtWriter::new(String::new());
+
+    if tokens
+        .format_file(&mut w.as_formatter(fmt), config)
+        .is_err()
+    {
+        panic!("{}: failed to format token stream", path.display());
+    }
+
+    let actual = w.into_inner();
+
+    if should_update() {
+        if let Err(error) = update(path, &actual) {
+            panic!("{}: failed to update golden file: {error}", path.display());
+        }
+
+        return;
+    }
+
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) => expected,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            panic!(
+                "{}: golden file is missing, run with {UPDATE_GOLDEN}=1 to create it",
+                path.display()
+            );
+        }
+        Err(error) => {
+            panic!("{}: failed to read golden file: {error}", path.display());
+        }
+    };
+
+    if expected == actual {
+        return;
+    }
+
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+    let ops = diff::lines(&expected, &actual);
+
+    if !ops.iter().any(diff::DiffOp::is_change) {
+        panic!(
+            "{}: output differs from golden file in line endings, run with {UPDATE_GOLDEN}=1 to update it",
+            path.display()
+        );
+    }
+
+    panic!(
+        "{}: output doesn't match golden file, run with {UPDATE_GOLDEN}=1 to update it\n{}",
+        path.display(),
+        diff::unified(&ops)
+    );
+}
+
+/// Test if golden files should be updated.
+fn should_update() -> bool {
+    env::var_os(UPDATE_GOLDEN).map_or(false, |value| !value.is_empty() && value != "0")
+}
+
+/// Write the golden file, creating its parent directory if needed.
+fn update(path: &Path, actual: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, actual)
+}
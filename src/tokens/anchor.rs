@@ -0,0 +1,32 @@
+use core::fmt;
+
+use crate::tokens::ItemStr;
+
+/// Error raised by [Tokens::insert_at] if the token stream doesn't contain an
+/// anchor with the given name.
+///
+/// [Tokens::insert_at]: crate::Tokens::insert_at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingAnchor {
+    name: ItemStr,
+}
+
+impl MissingAnchor {
+    pub(crate) fn new(name: ItemStr) -> Self {
+        Self { name }
+    }
+
+    /// The name of the anchor which is missing.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for MissingAnchor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no anchor named `{}`", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MissingAnchor {}
@@ -0,0 +1,124 @@
+use alloc::boxed::Box;
+
+use crate::lang::Lang;
+use crate::tokens::FormatInto;
+use crate::Tokens;
+
+/// A boxed and type-erased [FormatInto] implementation which is [Send].
+///
+/// This allows for fragments of different types, like the ones constructed
+/// through [quote_fn!], to be stored together in collections. Fragments which
+/// aren't [Send] can be boxed as a `Box<dyn DynFormatInto<L> + 'a>` instead,
+/// which a [BoxFormatInto] also coerces into.
+///
+/// Constructed through [FormatInto::boxed].
+///
+/// [quote_fn!]: crate::quote_fn
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use genco::prelude::*;
+/// use genco::tokens::BoxFormatInto;
+///
+/// let mut fields = BTreeMap::<&str, BoxFormatInto<'static, Rust>>::new();
+/// fields.insert("id", quote_fn!(u64).boxed());
+/// fields.insert("name", rust::import("std::borrow", "Cow").boxed());
+///
+/// // Later passes can replace fragments.
+/// fields.insert("name", quote!(String).boxed());
+///
+/// let tokens: rust::Tokens = quote! {
+///     struct User {
+///         $(for (name, ty) in fields => $name: $ty,$['\r'])
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "struct User {",
+///         "    id: u64,",
+///         "    name: String,",
+///         "}",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub type BoxFormatInto<'a, L> = Box<dyn DynFormatInto<L> + Send + 'a>;
+
+/// Object-safe variant of [FormatInto], which takes the value by box.
+///
+/// This is implemented for everything which implements [FormatInto], so that
+/// values of different types can be collected as `Box<dyn DynFormatInto<L>>`
+/// and appended to a token stream. See [BoxFormatInto] for the [Send] variant.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use genco::prelude::*;
+/// use genco::tokens::DynFormatInto;
+///
+/// let name = Rc::new(String::from("name"));
+/// let first = name.clone();
+///
+/// let fragments: Vec<Box<dyn DynFormatInto<Rust>>> = vec![
+///     Box::new(quote_fn!(let $(&*first) = 1;)),
+///     Box::new(quote!(println!("{}", $(&*name));)),
+///     quote_fn!(drop(b);).boxed(),
+/// ];
+///
+/// let tokens: rust::Tokens = quote!($(for f in fragments join ($['\r']) => $f));
+///
+/// assert_eq!(
+///     vec![
+///         "let name = 1;",
+///         "println!(\"{}\", name);",
+///         "drop(b);",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub trait DynFormatInto<L>
+where
+    L: Lang,
+{
+    /// Convert the boxed value into tokens in-place.
+    fn format_into_boxed(self: Box<Self>, tokens: &mut Tokens<L>);
+}
+
+impl<L, T> DynFormatInto<L> for T
+where
+    L: Lang,
+    T: FormatInto<L>,
+{
+    #[inline]
+    fn format_into_boxed(self: Box<Self>, tokens: &mut Tokens<L>) {
+        (*self).format_into(tokens);
+    }
+}
+
+impl<L> FormatInto<L> for Box<dyn DynFormatInto<L> + '_>
+where
+    L: Lang,
+{
+    #[inline]
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        self.format_into_boxed(tokens);
+    }
+}
+
+impl<L> FormatInto<L> for Box<dyn DynFormatInto<L> + Send + '_>
+where
+    L: Lang,
+{
+    #[inline]
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        self.format_into_boxed(tokens);
+    }
+}
@@ -0,0 +1,195 @@
+//! Naming conventions for identifiers.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A naming convention which can be applied to identifiers.
+///
+/// Words in the original identifier are separated by underscores, dashes, or
+/// changes in case, so `user_id`, `userId`, and `UserID` all consist of the
+/// words `user` and `id`.
+///
+/// # Examples
+///
+/// ```
+/// use genco::tokens::Case;
+///
+/// assert_eq!("user_id", Case::Snake.convert("UserId"));
+/// assert_eq!("USER_ID", Case::ScreamingSnake.convert("userId"));
+/// assert_eq!("userId", Case::Camel.convert("user_id"));
+/// assert_eq!("HttpServer", Case::Pascal.convert("HTTPServer"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Case {
+    /// `snake_case`.
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`.
+    ScreamingSnake,
+    /// `camelCase`.
+    Camel,
+    /// `PascalCase`.
+    Pascal,
+}
+
+impl Case {
+    /// Convert the given identifier into this naming convention.
+    ///
+    /// A raw identifier prefix like `r#`, and leading or trailing
+    /// underscores, are kept as-is since they carry meaning of their own,
+    /// like privacy in Dart or dunder names in Python. Only the words in
+    /// between are converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::tokens::Case;
+    ///
+    /// assert_eq!("_privateValue", Case::Camel.convert("_private_value"));
+    /// assert_eq!("__init__", Case::Snake.convert("__init__"));
+    /// assert_eq!("_", Case::Pascal.convert("_"));
+    /// assert_eq!("r#Type", Case::Pascal.convert("r#type"));
+    /// ```
+    pub fn convert(self, ident: &str) -> String {
+        let (raw, rest) = match ident.strip_prefix("r#") {
+            Some(rest) => ("r#", rest),
+            None => ("", ident),
+        };
+
+        let inner = rest.trim_start_matches('_');
+        let leading = &rest[..rest.len() - inner.len()];
+        let inner = inner.trim_end_matches('_');
+        let trailing = &rest[leading.len() + inner.len()..];
+
+        let mut out = String::with_capacity(ident.len());
+        out.push_str(raw);
+        out.push_str(leading);
+
+        for (n, word) in words(inner).into_iter().enumerate() {
+            match self {
+                Case::Snake | Case::ScreamingSnake => {
+                    if n > 0 {
+                        out.push('_');
+                    }
+
+                    for c in word.chars() {
+                        if self == Case::Snake {
+                            out.extend(c.to_lowercase());
+                        } else {
+                            out.extend(c.to_uppercase());
+                        }
+                    }
+                }
+                Case::Camel | Case::Pascal => {
+                    for (i, c) in word.chars().enumerate() {
+                        if i == 0 && (n > 0 || self == Case::Pascal) {
+                            out.extend(c.to_uppercase());
+                        } else {
+                            out.extend(c.to_lowercase());
+                        }
+                    }
+                }
+            }
+        }
+
+        out.push_str(trailing);
+        out
+    }
+}
+
+/// The kind of an identifier, used to look up which naming convention to
+/// apply to it through [Lang::ident_case][crate::lang::Lang::ident_case].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum IdentKind {
+    /// The name of a field or a variable.
+    Field,
+    /// The name of a function or a method.
+    Function,
+    /// The name of a type.
+    Type,
+}
+
+/// Naming conventions configured for a language.
+#[cfg(any(
+    feature = "lang-csharp",
+    feature = "lang-dart",
+    feature = "lang-go",
+    feature = "lang-java",
+    feature = "lang-js",
+    feature = "lang-python",
+    feature = "lang-rust",
+    feature = "lang-swift",
+))]
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Naming {
+    pub(crate) field: Option<Case>,
+    pub(crate) function: Option<Case>,
+    pub(crate) type_name: Option<Case>,
+}
+
+#[cfg(any(
+    feature = "lang-csharp",
+    feature = "lang-dart",
+    feature = "lang-go",
+    feature = "lang-java",
+    feature = "lang-js",
+    feature = "lang-python",
+    feature = "lang-rust",
+    feature = "lang-swift",
+))]
+impl Naming {
+    /// Get the naming convention for the given kind of identifier.
+    pub(crate) fn get(&self, kind: IdentKind) -> Option<Case> {
+        match kind {
+            IdentKind::Field => self.field,
+            IdentKind::Function => self.function,
+            IdentKind::Type => self.type_name,
+        }
+    }
+}
+
+/// Split an identifier into words.
+fn words(ident: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut it = ident.char_indices().peekable();
+    let mut prev: Option<char> = None;
+
+    while let Some((i, c)) = it.next() {
+        if c == '_' || c == '-' {
+            if let Some(s) = start.take() {
+                words.push(&ident[s..i]);
+            }
+
+            prev = None;
+            continue;
+        }
+
+        if let (Some(s), Some(p)) = (start, prev) {
+            let next_lower = it.peek().map_or(false, |(_, n)| n.is_lowercase());
+
+            // Break on `aB` and on the last upper case character in `ABc`.
+            let boundary = c.is_uppercase()
+                && (p.is_lowercase() || p.is_numeric() || (p.is_uppercase() && next_lower));
+
+            if boundary {
+                words.push(&ident[s..i]);
+                start = Some(i);
+            }
+        }
+
+        if start.is_none() {
+            start = Some(i);
+        }
+
+        prev = Some(c);
+    }
+
+    if let Some(s) = start {
+        words.push(&ident[s..]);
+    }
+
+    words
+}
@@ -0,0 +1,75 @@
+use crate::lang::Lang;
+use crate::tokens::{self, FormatInto, Item};
+use crate::Tokens;
+
+/// Format a chain of method calls.
+///
+/// This struct is created by the [chain()] function.
+pub struct Chain<R, C>(R, C);
+
+/// Format a chain of method calls on a receiver, like `a.b().c()`.
+///
+/// The chain is formatted on a single line if it fits within the configured
+/// [max width], otherwise each call is put on its own indented line.
+///
+/// [max width]: crate::fmt::Config::with_max_width
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::chain;
+///
+/// let calls = [
+///     quote!(then((response) => response.json())),
+///     quote!(then((data) => console.log(data))),
+///     quote!(catch((error) => console.error(error))),
+/// ];
+///
+/// let toks: js::Tokens = quote! {
+///     function load() {
+///         $(chain(quote!(fetch("/api")), calls));
+///     }
+///
+///     $(chain(quote!(fetch("/api")), [quote!(then(render))]));
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "function load() {",
+///         "    fetch(\"/api\")",
+///         "        .then((response) => response.json())",
+///         "        .then((data) => console.log(data))",
+///         "        .catch((error) => console.error(error));",
+///         "}",
+///         "",
+///         "fetch(\"/api\").then(render);",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn chain<R, C>(receiver: R, calls: C) -> Chain<R, C> {
+    Chain(receiver, calls)
+}
+
+impl<L, R, C> FormatInto<L> for Chain<R, C>
+where
+    L: Lang,
+    R: FormatInto<L>,
+    C: IntoIterator,
+    C::Item: FormatInto<L>,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.item(Item::OpenGroup);
+        self.0.format_into(tokens);
+
+        for call in self.1 {
+            tokens.item(Item::SoftBreak);
+            tokens.append(tokens::static_literal("."));
+            call.format_into(tokens);
+        }
+
+        tokens.item(Item::CloseGroup);
+    }
+}
@@ -0,0 +1,125 @@
+use core::fmt::{self, Write as _};
+
+use alloc::string::String;
+
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, Item, ItemStr};
+use crate::Tokens;
+
+/// The ellipsis appended to clipped strings.
+const ELLIPSIS: &str = "...";
+
+/// Function to sanitize an untrusted string before it's interpolated.
+///
+/// The value is formatted through its [Display][fmt::Display] implementation,
+/// after which whitespace control characters like newlines and tabs are
+/// replaced with spaces and any other control characters are removed. If the
+/// result is longer than `max_len` characters, it's truncated and ends with an
+/// ellipsis (`...`) so that it fits.
+///
+/// This keeps things like user-provided descriptions on a single line, and
+/// prevents malformed or malicious input from blowing up the size of the
+/// generated file. Note that the result isn't quoted, so it should be wrapped
+/// in [quoted()] when used as a string.
+///
+/// [quoted()]: crate::tokens::quoted()
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::clipped;
+///
+/// let description = "A user\nof the\u{7}system, who has a very long description";
+///
+/// let tokens: rust::Tokens = quote! {
+///     #[doc = $(quoted(clipped(description, 24)))]
+///     struct User;
+///     const NOTE: &str = $(quoted(clipped("short\tenough", 100)));
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "#[doc = \"A user of thesystem, ...\"]",
+///         "struct User;",
+///         "const NOTE: &str = \"short enough\";",
+///     ],
+///     tokens.to_file_vec()?,
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn clipped<T>(inner: T, max_len: usize) -> Clipped<T>
+where
+    T: fmt::Display,
+{
+    Clipped { inner, max_len }
+}
+
+/// Struct containing a sanitized value which is clipped to a maximum length.
+///
+/// This is constructed with the [clipped()] function.
+#[derive(Clone, Copy, Debug)]
+pub struct Clipped<T> {
+    inner: T,
+    max_len: usize,
+}
+
+impl<T, L> FormatInto<L> for Clipped<T>
+where
+    L: Lang,
+    T: fmt::Display,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        let mut out = Sanitize {
+            out: String::new(),
+            len: 0,
+            max_len: self.max_len,
+        };
+
+        // NB: Sanitize never fails, so an error can only come from the display
+        // implementation in which case we use what was written so far.
+        let _ = write!(out, "{}", self.inner);
+
+        let Sanitize { mut out, len, .. } = out;
+
+        if len > self.max_len {
+            let keep = self.max_len.saturating_sub(ELLIPSIS.len());
+
+            if let Some((n, _)) = out.char_indices().nth(keep) {
+                out.truncate(n);
+            }
+
+            out.push_str(&ELLIPSIS[..usize::min(self.max_len, ELLIPSIS.len())]);
+        }
+
+        tokens.item(Item::Literal(ItemStr::from(out)));
+    }
+}
+
+/// Writer which strips control characters and counts characters, only
+/// keeping the first `max_len` of them.
+struct Sanitize {
+    out: String,
+    len: usize,
+    max_len: usize,
+}
+
+impl fmt::Write for Sanitize {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let c = match c {
+                c if c.is_control() && c.is_whitespace() => ' ',
+                c if c.is_control() => continue,
+                c => c,
+            };
+
+            if self.len < self.max_len {
+                self.out.push(c);
+            }
+
+            self.len += 1;
+        }
+
+        Ok(())
+    }
+}
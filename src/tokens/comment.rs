@@ -0,0 +1,11 @@
+/// The kind of a comment, which determines how it's rendered through
+/// [Lang::write_comment][crate::lang::Lang::write_comment].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ir", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum CommentKind {
+    /// A comment which extends to the end of the line, like `// comment`.
+    Line,
+    /// A comment which is delimited on both sides, like `/* comment */`.
+    Block,
+}
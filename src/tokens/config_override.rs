@@ -0,0 +1,191 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use alloc::sync::Arc;
+
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, Item, ItemStr};
+use crate::Tokens;
+
+/// The function which produces the overridden configuration.
+type Patch<L> = dyn Fn(&<L as Lang>::Config) -> <L as Lang>::Config + Send + Sync;
+
+/// A function which overrides the language configuration for a region of a
+/// token stream.
+///
+/// Overrides are compared, ordered and hashed by the key they were constructed
+/// with, since the patch itself can't be compared. So overrides which apply
+/// different patches should use different keys.
+///
+/// See [with_config_override()].
+pub struct ConfigOverride<L>
+where
+    L: Lang,
+{
+    key: ItemStr,
+    patch: Arc<Patch<L>>,
+}
+
+impl<L> ConfigOverride<L>
+where
+    L: Lang,
+{
+    /// Construct the configuration to use from the one currently in effect.
+    pub(crate) fn apply(&self, config: &L::Config) -> L::Config {
+        (self.patch)(config)
+    }
+
+    /// The key identifying the override.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl<L> Clone for ConfigOverride<L>
+where
+    L: Lang,
+{
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            patch: self.patch.clone(),
+        }
+    }
+}
+
+impl<L> fmt::Debug for ConfigOverride<L>
+where
+    L: Lang,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConfigOverride").field(&self.key).finish()
+    }
+}
+
+impl<L> PartialEq for ConfigOverride<L>
+where
+    L: Lang,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<L> Eq for ConfigOverride<L> where L: Lang {}
+
+impl<L> PartialOrd for ConfigOverride<L>
+where
+    L: Lang,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<L> Ord for ConfigOverride<L>
+where
+    L: Lang,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl<L> Hash for ConfigOverride<L>
+where
+    L: Lang,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.key.hash(state);
+    }
+}
+
+/// Format the given body with a language configuration derived from the one
+/// in effect where it's formatted, restoring the previous configuration
+/// afterwards.
+///
+/// The patch receives a copy of the current configuration and returns the one
+/// to use for the body. The `key` identifies the override when token streams
+/// are compared, ordered or hashed, see [ConfigOverride]. Overrides can be nested, in which case the patch of
+/// the inner override receives the configuration produced by the outer one.
+///
+/// Note that this only affects how the body is rendered. Things which are
+/// decided for the whole file, like which imports are emitted by
+/// [Tokens::format_file], still use the configuration of the file.
+///
+/// Token streams containing overrides can't be serialized into IR, since the
+/// patch is an arbitrary function.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::with_config_override;
+///
+/// let map = rust::import("std::collections", "HashMap");
+///
+/// let qualified = with_config_override(
+///     "qualified",
+///     |config: rust::Config| config.with_default_import(rust::ImportMode::Qualified),
+///     quote!(let b = $(&map)::new();),
+/// );
+///
+/// let tokens: rust::Tokens = quote! {
+///     let a = $(&map)::new();
+///     $qualified
+///     let c = $(&map)::new();
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "let a = HashMap::new();",
+///         "let b = collections::HashMap::new();",
+///         "let c = HashMap::new();",
+///     ],
+///     tokens.to_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn with_config_override<L, K, F, B>(key: K, patch: F, body: B) -> WithConfigOverride<L, B>
+where
+    L: Lang,
+    L::Config: Clone,
+    K: Into<ItemStr>,
+    F: 'static + Send + Sync + Fn(L::Config) -> L::Config,
+    B: FormatInto<L>,
+{
+    WithConfigOverride {
+        config: ConfigOverride {
+            key: key.into(),
+            patch: Arc::new(move |config: &L::Config| patch(config.clone())),
+        },
+        body,
+    }
+}
+
+/// A body which is formatted with an overridden language configuration.
+///
+/// Constructed through the [with_config_override()] function.
+pub struct WithConfigOverride<L, B>
+where
+    L: Lang,
+{
+    config: ConfigOverride<L>,
+    body: B,
+}
+
+impl<L, B> FormatInto<L> for WithConfigOverride<L, B>
+where
+    L: Lang,
+    B: FormatInto<L>,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.item(Item::OpenConfig(self.config));
+        self.body.format_into(tokens);
+        tokens.item(Item::CloseConfig);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, Item};
+use crate::Tokens;
+
+/// Format a body between a pair of delimiters.
+///
+/// This struct is created by the [delimited()] function.
+pub struct Delimited<O, C, B> {
+    open: O,
+    close: C,
+    body: B,
+    padding: bool,
+    max_width: Option<usize>,
+}
+
+/// Format a body between a pair of delimiters, like `{` and `}`.
+///
+/// The body is written on the same line as the delimiters if it fits within
+/// the configured [max width]. Otherwise, or if the body spans multiple lines,
+/// it's indented on lines of its own with the closing delimiter on a new line.
+///
+/// [max width]: crate::fmt::Config::with_max_width
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+/// use genco::tokens::delimited;
+///
+/// let fields = [quote!(x: 1,), quote!(y: 2,), quote!(z: 3,)];
+///
+/// let toks: rust::Tokens = quote! {
+///     let a = Point $(delimited("{", "}", quote!(x: 1, y: 2)).with_padding(true));
+///     let b = Point $(delimited("{", "}", quote!($(for f in fields join ($['\r']) => $f))));
+///     let c = vec!$(delimited("[", "]", quote!("a very long string", "which does not fit")));
+/// };
+///
+/// let fmt = fmt::Config::from_lang::<Rust>().with_max_width(40);
+///
+/// let mut w = fmt::VecWriter::new();
+/// toks.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+///
+/// assert_eq!(
+///     vec![
+///         "let a = Point { x: 1, y: 2 };",
+///         "let b = Point {",
+///         "    x: 1,",
+///         "    y: 2,",
+///         "    z: 3,",
+///         "};",
+///         "let c = vec![",
+///         "    \"a very long string\", \"which does not fit\"",
+///         "];",
+///     ],
+///     w.into_vec()
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn delimited<O, C, B>(open: O, close: C, body: B) -> Delimited<O, C, B> {
+    Delimited {
+        open,
+        close,
+        body,
+        padding: false,
+        max_width: None,
+    }
+}
+
+impl<O, C, B> Delimited<O, C, B> {
+    /// Separate the body from the delimiters with a space when written on a
+    /// single line, like `{ a }` instead of `{a}`.
+    pub fn with_padding(self, padding: bool) -> Self {
+        Self { padding, ..self }
+    }
+
+    /// Also put the body on lines of its own if the delimiters and the body
+    /// would be wider than the given number of characters when written on a
+    /// single line, regardless of the configured max width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::tokens::delimited;
+    ///
+    /// let toks: js::Tokens = quote! {
+    ///     const a = $(delimited("[", "]", quote!(1, 2)).with_max_width(8));
+    ///     const b = $(delimited("[", "]", quote!(1, 2, 3, 4)).with_max_width(8));
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "const a = [1, 2];",
+    ///         "const b = [",
+    ///         "    1, 2, 3, 4",
+    ///         "];",
+    ///     ],
+    ///     toks.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_max_width(self, max_width: usize) -> Self {
+        Self {
+            max_width: Some(max_width),
+            ..self
+        }
+    }
+}
+
+impl<L, O, C, B> FormatInto<L> for Delimited<O, C, B>
+where
+    L: Lang,
+    O: FormatInto<L>,
+    C: FormatInto<L>,
+    B: FormatInto<L>,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        match self.max_width {
+            Some(max_width) => tokens.item(Item::OpenLimitedGroup(max_width)),
+            None => tokens.item(Item::OpenGroup),
+        }
+
+        self.open.format_into(tokens);
+
+        if self.padding {
+            tokens.space();
+        }
+
+        tokens.item(Item::SoftIndentation(1));
+        self.body.format_into(tokens);
+
+        if self.padding {
+            tokens.space();
+        }
+
+        tokens.item(Item::SoftIndentation(-1));
+        self.close.format_into(tokens);
+        tokens.item(Item::CloseGroup);
+    }
+}
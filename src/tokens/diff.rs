@@ -0,0 +1,260 @@
+use core::fmt::{self, Write as _};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::diff::{edits, Edit};
+use crate::lang::Lang;
+use crate::tokens::Item;
+use crate::Tokens;
+
+/// The number of items around a change which are used to render its context.
+const CONTEXT: usize = 6;
+
+/// Compute the difference between two token streams on the item level.
+///
+/// This is useful in golden-file tests, where a failure should point out which
+/// item changed rather than showing two large strings which don't match.
+/// Language items are compared by what they refer to, regardless of where
+/// they're placed in the token stream.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::{diff, Item, ItemChange, ItemStr};
+///
+/// let old: rust::Tokens = quote!(let foo = $(quoted("hello")););
+/// let new: rust::Tokens = quote!(let bar = $(quoted("hello")););
+///
+/// assert!(diff(&old, &old).is_empty());
+///
+/// let changes = diff(&old, &new);
+/// assert!(!changes.is_empty());
+///
+/// assert_eq!(
+///     vec![
+///         ItemChange::Removed { index: 2, item: &Item::Literal(ItemStr::Static("foo")) },
+///         ItemChange::Added { index: 2, item: &Item::Literal(ItemStr::Static("bar")) },
+///     ],
+///     changes.changes().collect::<Vec<_>>()
+/// );
+///
+/// assert_eq!(
+///     vec![
+///         "-[2] \"foo\"",
+///         "+[2] \"bar\"",
+///         " old: let foo = \"hello\"",
+///         " new: let bar = \"hello\"",
+///     ],
+///     changes.to_string().lines().collect::<Vec<_>>()
+/// );
+/// ```
+pub fn diff<'a, L>(old: &'a Tokens<L>, new: &'a Tokens<L>) -> TokenDiff<'a, L>
+where
+    L: Lang,
+{
+    let old = old.iter().collect::<Vec<_>>();
+    let new = new.iter().collect::<Vec<_>>();
+    let edits = edits(&old, &new, |a, b| same(a, b));
+    TokenDiff { old, new, edits }
+}
+
+/// The difference between two token streams.
+///
+/// The [Display][fmt::Display] implementation lists each changed item with its
+/// index, followed by the rendered items around the change in both streams.
+///
+/// Created through the [diff()] function.
+pub struct TokenDiff<'a, L>
+where
+    L: Lang,
+{
+    old: Vec<&'a Item<L>>,
+    new: Vec<&'a Item<L>>,
+    edits: Vec<Edit>,
+}
+
+impl<'a, L> TokenDiff<'a, L>
+where
+    L: Lang,
+{
+    /// Test if the token streams are the same.
+    pub fn is_empty(&self) -> bool {
+        self.edits
+            .iter()
+            .all(|edit| matches!(edit, Edit::Equal(..)))
+    }
+
+    /// Iterate over the items which were removed from the old token stream or
+    /// added to the new one, in order.
+    pub fn changes(&self) -> impl Iterator<Item = ItemChange<'a, L>> + '_ {
+        self.edits.iter().filter_map(move |edit| match *edit {
+            Edit::Equal(..) => None,
+            Edit::Delete(index) => Some(ItemChange::Removed {
+                index,
+                item: self.old[index],
+            }),
+            Edit::Insert(index) => Some(ItemChange::Added {
+                index,
+                item: self.new[index],
+            }),
+        })
+    }
+}
+
+impl<L> fmt::Display for TokenDiff<'_, L>
+where
+    L: Lang,
+    L::Config: Default,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut n = 0;
+        // Position in the old and the new stream at the start of `edits[n]`.
+        let mut old_pos = 0;
+        let mut new_pos = 0;
+
+        while n < self.edits.len() {
+            if let Edit::Equal(..) = self.edits[n] {
+                old_pos += 1;
+                new_pos += 1;
+                n += 1;
+                continue;
+            }
+
+            let (old_start, new_start) = (old_pos, new_pos);
+
+            while let Some(edit) = self.edits.get(n) {
+                match *edit {
+                    Edit::Equal(..) => break,
+                    Edit::Delete(index) => {
+                        writeln!(f, "-[{index}] {}", Describe(self.old[index]))?;
+                        old_pos += 1;
+                    }
+                    Edit::Insert(index) => {
+                        writeln!(f, "+[{index}] {}", Describe(self.new[index]))?;
+                        new_pos += 1;
+                    }
+                }
+
+                n += 1;
+            }
+
+            writeln!(f, " old: {}", context(&self.old, old_start, old_pos))?;
+            writeln!(f, " new: {}", context(&self.new, new_start, new_pos))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<L> fmt::Debug for TokenDiff<'_, L>
+where
+    L: Lang,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.changes()).finish()
+    }
+}
+
+/// A single item which differs between two token streams.
+///
+/// Produced by [TokenDiff::changes].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemChange<'a, L>
+where
+    L: Lang,
+{
+    /// An item at the given index in the old token stream which is not in the
+    /// new one.
+    Removed {
+        /// The index of the item in the old token stream.
+        index: usize,
+        /// The item which was removed.
+        item: &'a Item<L>,
+    },
+    /// An item at the given index in the new token stream which is not in the
+    /// old one.
+    Added {
+        /// The index of the item in the new token stream.
+        index: usize,
+        /// The item which was added.
+        item: &'a Item<L>,
+    },
+}
+
+/// Test if two items are the same, ignoring the position of language items
+/// and how strings are stored.
+fn same<L>(a: &Item<L>, b: &Item<L>) -> bool
+where
+    L: Lang,
+{
+    match (a, b) {
+        (Item::Literal(a), Item::Literal(b)) => **a == **b,
+        (Item::Ident(a_kind, a), Item::Ident(b_kind, b)) => a_kind == b_kind && **a == **b,
+        (Item::Lang(_, a), Item::Lang(_, b)) => a == b,
+        (Item::Register(_, a), Item::Register(_, b)) => a == b,
+        (a, b) => a == b,
+    }
+}
+
+/// Render the items around the range `start..end` on a single line.
+fn context<L>(items: &[&Item<L>], start: usize, end: usize) -> String
+where
+    L: Lang,
+    L::Config: Default,
+{
+    let items = &items[start.saturating_sub(CONTEXT)..usize::min(end + CONTEXT, items.len())];
+
+    let mut tokens = Tokens::new();
+
+    for item in items {
+        tokens.item((*item).clone());
+    }
+
+    let mut out = String::new();
+
+    match tokens.to_string() {
+        Ok(rendered) => {
+            for line in rendered.lines().map(str::trim).filter(|l| !l.is_empty()) {
+                if !out.is_empty() {
+                    out.push(' ');
+                }
+
+                out.push_str(line);
+            }
+        }
+        // NB: A part of a token stream can fail to render, like when it ends
+        // in the middle of a quoted string.
+        Err(..) => {
+            for item in items {
+                if !out.is_empty() {
+                    out.push(' ');
+                }
+
+                let _ = write!(out, "{}", Describe(item));
+            }
+        }
+    }
+
+    out
+}
+
+/// Describe a single item.
+struct Describe<'a, L>(&'a Item<L>)
+where
+    L: Lang;
+
+impl<L> fmt::Display for Describe<'_, L>
+where
+    L: Lang,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Item::Literal(literal) => write!(f, "{:?}", &**literal),
+            Item::Lang(_, item) => write!(f, "lang {item:?}"),
+            Item::Register(_, item) => write!(f, "register {item:?}"),
+            item => write!(f, "{item:?}"),
+        }
+    }
+}
@@ -1,9 +1,7 @@
 use core::fmt;
 
-use alloc::string::ToString;
-
 use crate::lang::Lang;
-use crate::tokens::{FormatInto, Item};
+use crate::tokens::{FormatInto, Item, ItemStr};
 use crate::Tokens;
 
 /// Function to build a string literal.
@@ -76,8 +74,6 @@ where
     T: fmt::Display,
 {
     fn format_into(self, tokens: &mut Tokens<L>) {
-        tokens.item(Item::Literal(
-            self.inner.to_string().into_boxed_str().into(),
-        ));
+        tokens.item(Item::Literal(ItemStr::from_display(self.inner)));
     }
 }
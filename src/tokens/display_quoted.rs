@@ -0,0 +1,154 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use alloc::string::ToString;
+use alloc::sync::Arc;
+
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, Item};
+use crate::Tokens;
+
+/// A value which is written through its [Display][fmt::Display]
+/// implementation when the token stream is formatted, rather than when it's
+/// added to the stream.
+///
+/// This avoids allocating a string for every value, since the formatter
+/// writes each value into a buffer which it reuses.
+///
+/// Values are compared, ordered and hashed by what they display as, the same
+/// way as an [Item::Literal] with the same content would be.
+///
+/// See [display_quoted()].
+#[derive(Clone)]
+pub struct DisplayValue {
+    value: Arc<dyn fmt::Display + Send + Sync>,
+}
+
+impl DisplayValue {
+    /// Construct a value which is displayed when it's formatted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::tokens::DisplayValue;
+    ///
+    /// let tokens: rust::Tokens = quote!(let a = $(DisplayValue::new(42u32)););
+    /// assert_eq!("let a = 42;", tokens.to_string()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn new<T>(value: T) -> Self
+    where
+        T: 'static + fmt::Display + Send + Sync,
+    {
+        Self {
+            value: Arc::new(value),
+        }
+    }
+}
+
+impl fmt::Display for DisplayValue {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl fmt::Debug for DisplayValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DisplayValue")
+            .field(&self.value.to_string())
+            .finish()
+    }
+}
+
+impl PartialEq for DisplayValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.to_string() == other.value.to_string()
+    }
+}
+
+impl Eq for DisplayValue {}
+
+impl PartialOrd for DisplayValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DisplayValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.to_string().cmp(&other.value.to_string())
+    }
+}
+
+impl Hash for DisplayValue {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.value.to_string().hash(state);
+    }
+}
+
+impl<L> FormatInto<L> for DisplayValue
+where
+    L: Lang,
+{
+    #[inline]
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.item(Item::Display(self));
+    }
+}
+
+/// Add a value as a quoted string, which is written through its
+/// [Display][fmt::Display] implementation when the token stream is formatted.
+///
+/// This is the same as `quoted(display(value))`, except that no string is
+/// allocated for the value. Which is useful when interpolating many numeric
+/// values into strings. The value has to be `'static`, since it's kept in the
+/// token stream.
+///
+/// See [DisplayValue].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::display_quoted;
+///
+/// let tokens: rust::Tokens = quote! {
+///     let versions = [$(for n in 1..=3 join (, ) => $(display_quoted(n)))];
+/// };
+///
+/// assert_eq!("let versions = [\"1\", \"2\", \"3\"];", tokens.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn display_quoted<T>(value: T) -> DisplayQuoted
+where
+    T: 'static + fmt::Display + Send + Sync,
+{
+    DisplayQuoted {
+        value: DisplayValue::new(value),
+    }
+}
+
+/// A value which is added as a quoted string and displayed when formatted.
+///
+/// Constructed through the [display_quoted()] function.
+#[derive(Debug, Clone)]
+pub struct DisplayQuoted {
+    value: DisplayValue,
+}
+
+impl<L> FormatInto<L> for DisplayQuoted
+where
+    L: Lang,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.item(Item::OpenQuote(false));
+        tokens.item(Item::Display(self.value));
+        tokens.item(Item::CloseQuote);
+    }
+}
@@ -0,0 +1,75 @@
+use alloc::format;
+
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, ItemStr};
+use crate::Tokens;
+
+/// Construct a header comment indicating that the file was generated and
+/// shouldn't be edited by hand.
+///
+/// The comment uses the line comment syntax of the target language, as
+/// determined by [Lang::write_comment], and follows the widely recognized
+/// `Code generated by <tool> <version>. DO NOT EDIT.` convention. It
+/// deliberately doesn't include a timestamp so that regenerating unchanged
+/// files doesn't produce a diff.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::do_not_edit_header;
+///
+/// let tokens: rust::Tokens = quote! {
+///     $(do_not_edit_header("my-generator", "1.0.0"))
+///
+///     fn main() {}
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "// Code generated by my-generator 1.0.0. DO NOT EDIT.",
+///         "",
+///         "fn main() {}",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+///
+/// let tokens: python::Tokens = quote!($(do_not_edit_header("my-generator", "1.0.0")));
+///
+/// assert_eq!(
+///     "# Code generated by my-generator 1.0.0. DO NOT EDIT.",
+///     tokens.to_string()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn do_not_edit_header<N, V>(tool: N, version: V) -> DoNotEditHeader
+where
+    N: Into<ItemStr>,
+    V: Into<ItemStr>,
+{
+    DoNotEditHeader {
+        tool: tool.into(),
+        version: version.into(),
+    }
+}
+
+/// A header comment indicating that a file was generated.
+///
+/// This is created by the [do_not_edit_header()] function.
+#[derive(Debug, Clone)]
+pub struct DoNotEditHeader {
+    tool: ItemStr,
+    version: ItemStr,
+}
+
+impl<L> FormatInto<L> for DoNotEditHeader
+where
+    L: Lang,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.comment(ItemStr::from(format!(
+            "Code generated by {} {}. DO NOT EDIT.",
+            self.tool, self.version
+        )));
+    }
+}
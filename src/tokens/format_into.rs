@@ -1,12 +1,14 @@
 use core::fmt::Arguments;
 
 use alloc::borrow::Cow;
+use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::string::{String, ToString};
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 use crate::lang::Lang;
-use crate::tokens::{Item, ItemStr, Tokens};
+use crate::tokens::{BoxFormatInto, Item, ItemStr, Tokens};
 
 /// Trait for types that can be formatted in-place into a token stream.
 ///
@@ -49,6 +51,33 @@ where
     ///
     /// [from_fn()]: crate::tokens::from_fn()
     fn format_into(self, tokens: &mut Tokens<L>);
+
+    /// Box the value, erasing its type.
+    ///
+    /// This allows for values of different types to be stored together, like
+    /// in a map of fragments which is built up over several passes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::tokens::BoxFormatInto;
+    ///
+    /// let fragments: Vec<BoxFormatInto<'_, Rust>> = vec![
+    ///     quote_fn!(let a = 1;).boxed(),
+    ///     "let b = 2;".boxed(),
+    /// ];
+    ///
+    /// let tokens: rust::Tokens = quote!($(for f in fragments join ($['\r']) => $f));
+    /// assert_eq!(vec!["let a = 1;", "let b = 2;"], tokens.to_file_vec()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    fn boxed<'a>(self) -> BoxFormatInto<'a, L>
+    where
+        Self: Sized + Send + 'a,
+    {
+        Box::new(self)
+    }
 }
 
 impl<L> FormatInto<L> for Tokens<L>
@@ -245,7 +274,8 @@ where
     }
 }
 
-/// Refcounted strings are moved into the token stream without copying.
+/// Refcounted strings are moved into the token stream without copying if this
+/// is the only reference to them, and are copied otherwise.
 ///
 /// # Examples
 ///
@@ -270,8 +300,7 @@ where
     }
 }
 
-/// Refcounted strings are cloned and moved into the token stream without
-/// copying.
+/// Borrowed refcounted strings are copied into the token stream.
 ///
 /// # Examples
 ///
@@ -292,10 +321,76 @@ where
     L: Lang,
 {
     fn format_into(self, tokens: &mut Tokens<L>) {
-        tokens.item(Item::Literal(ItemStr::from(self.clone())));
+        tokens.item(Item::Literal(ItemStr::from(self)));
     }
 }
 
+macro_rules! impl_str {
+    ($($(#[doc = $doc:literal])* $ty:ty),* $(,)?) => {
+        $(
+            $(#[doc = $doc])*
+            impl<L> FormatInto<L> for $ty
+            where
+                L: Lang,
+            {
+                fn format_into(self, tokens: &mut Tokens<L>) {
+                    tokens.item(Item::Literal(ItemStr::from(self)));
+                }
+            }
+        )*
+    };
+}
+
+impl_str! {
+    /// Boxed strings are moved into the token stream without copying.
+    Box<str>,
+    /// Borrowed boxed strings are copied into the token stream.
+    &Box<str>,
+    /// Refcounted string slices are copied into the token stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use genco::prelude::*;
+    ///
+    /// let foo: Rc<str> = Rc::from("foo");
+    ///
+    /// let result: Tokens = quote!($(&foo) $foo $(quoted(Rc::<str>::from("bar"))));
+    ///
+    /// assert_eq!("foo foo \"bar\"", result.to_string()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    Rc<str>,
+    /// Borrowed refcounted string slices are copied into the token stream.
+    &Rc<str>,
+    /// Atomically refcounted strings are moved into the token stream without
+    /// copying if this is the only reference to them, and are copied otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use genco::prelude::*;
+    ///
+    /// let foo = Arc::new(String::from("foo"));
+    /// let bar: Arc<str> = Arc::from("bar");
+    ///
+    /// let result: Tokens = quote!($(&foo) $foo $(&bar) $bar);
+    ///
+    /// assert_eq!("foo foo bar bar", result.to_string()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    Arc<String>,
+    /// Borrowed atomically refcounted strings are copied into the token stream.
+    &Arc<String>,
+    /// Atomically refcounted string slices are copied into the token stream.
+    Arc<str>,
+    /// Borrowed atomically refcounted string slices are copied into the token
+    /// stream.
+    &Arc<str>,
+}
+
 /// Implementation for [Arguments] which allows for arbitrary and efficient
 /// literal formatting.
 ///
@@ -481,7 +576,7 @@ macro_rules! impl_display {
                 L: Lang,
             {
                 fn format_into(self, tokens: &mut Tokens<L>) {
-                    tokens.append(self.to_string());
+                    tokens.append(ItemStr::from_display(self));
                 }
             }
         )*
@@ -489,3 +584,44 @@ macro_rules! impl_display {
 }
 
 impl_display!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize, usize);
+
+/// Closures which take a token stream are formatted by calling them with the
+/// token stream being formatted into.
+///
+/// This is the same as wrapping the closure in [from_fn()], and allows for
+/// custom logic to be used directly in [quote!] without a
+/// [scope](macro.quote.html#scopes). In [quote!], closures which are
+/// interpolated don't need to annotate the type of their argument.
+///
+/// [from_fn()]: crate::tokens::from_fn()
+/// [quote!]: macro.quote.html
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let fields = vec!["a", "b", "c"];
+///
+/// let tokens: rust::Tokens = quote! {
+///     let sum = $(move |t| t.append_separated(fields, " + "));
+/// };
+///
+/// assert_eq!("let sum = a + b + c;", tokens.to_string()?);
+///
+/// let mut tokens = rust::Tokens::new();
+/// tokens.append(|t: &mut rust::Tokens| quote_in!(*t => let answer = 42;));
+///
+/// assert_eq!("let answer = 42;", tokens.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+impl<L, F> FormatInto<L> for F
+where
+    L: Lang,
+    F: FnOnce(&mut Tokens<L>),
+{
+    #[inline]
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        self(tokens);
+    }
+}
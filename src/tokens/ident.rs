@@ -0,0 +1,298 @@
+//! Validated identifiers.
+
+use core::fmt;
+use core::ops::Deref;
+
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, IdentKind, Item, ItemStr, Tokens};
+
+/// Error raised when constructing an [Ident] or a [TypeName] from a string
+/// containing characters which are not permitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidIdent {
+    kind: InvalidIdentKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum InvalidIdentKind {
+    Empty,
+    IllegalStart(char),
+    IllegalCharacter(char),
+    NotUppercase(char),
+}
+
+impl fmt::Display for InvalidIdent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            InvalidIdentKind::Empty => write!(f, "identifier is empty"),
+            InvalidIdentKind::IllegalStart(c) => {
+                write!(f, "identifier may not start with {c:?}")
+            }
+            InvalidIdentKind::IllegalCharacter(c) => {
+                write!(f, "identifier may not contain {c:?}")
+            }
+            InvalidIdentKind::NotUppercase(c) => {
+                write!(
+                    f,
+                    "type name must start with an uppercase character, found {c:?}"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidIdent {}
+
+/// Validate that the given string only contains characters which are valid in
+/// an identifier in most languages.
+fn validate(s: &str) -> Result<char, InvalidIdent> {
+    let mut it = s.chars();
+
+    let first = match it.next() {
+        Some(c) => c,
+        None => {
+            return Err(InvalidIdent {
+                kind: InvalidIdentKind::Empty,
+            })
+        }
+    };
+
+    if !(first.is_alphabetic() || first == '_') {
+        return Err(InvalidIdent {
+            kind: InvalidIdentKind::IllegalStart(first),
+        });
+    }
+
+    for c in it {
+        if !(c.is_alphanumeric() || c == '_') {
+            return Err(InvalidIdent {
+                kind: InvalidIdentKind::IllegalCharacter(c),
+            });
+        }
+    }
+
+    Ok(first)
+}
+
+/// A language-neutral identifier, like the name of a variable or a function.
+///
+/// Identifiers are validated when they are constructed. They must start with an
+/// alphabetic character or an underscore (`_`), and may only contain
+/// alphanumeric characters and underscores. This makes it safe to use them
+/// when passing user-provided strings into generated code.
+///
+/// When formatted, the identifier is passed through [Lang::sanitize_ident],
+/// which for example takes care of escaping reserved keywords.
+///
+/// An identifier can also be given a kind through [Ident::field] or
+/// [Ident::function], in which case the naming convention configured for that
+/// kind in the language configuration is applied before it is sanitized.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::Ident;
+///
+/// let name = Ident::new("in")?;
+/// assert!(Ident::new("drop table;").is_err());
+///
+/// let tokens: rust::Tokens = quote!(let $(&name) = 42;);
+/// assert_eq!("let r#in = 42;", tokens.to_string()?);
+///
+/// let tokens: python::Tokens = quote!($name = 42);
+/// assert_eq!("in_ = 42", tokens.to_string()?);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ident(ItemStr, Option<IdentKind>);
+
+impl Ident {
+    /// Construct a new validated identifier.
+    pub fn new<S>(ident: S) -> Result<Self, InvalidIdent>
+    where
+        S: Into<ItemStr>,
+    {
+        let ident = ident.into();
+        validate(&ident)?;
+        Ok(Self(ident, None))
+    }
+
+    /// Mark the identifier as the name of a field or a variable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    /// use genco::tokens::{Case, Ident};
+    ///
+    /// let name = Ident::new("user_id")?.field();
+    ///
+    /// let tokens: java::Tokens = quote!(int $(&name) = 42;);
+    ///
+    /// let config = java::Config::default().with_field_naming(Case::Camel);
+    /// let fmt = fmt::Config::from_lang::<Java>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(vec!["int userId = 42;"], w.into_vec());
+    ///
+    /// // Without a configured naming convention, the identifier is left as-is.
+    /// assert_eq!("int user_id = 42;", tokens.to_string()?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn field(self) -> Self {
+        Self(self.0, Some(IdentKind::Field))
+    }
+
+    /// Mark the identifier as the name of a function or a method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    /// use genco::tokens::{Case, Ident};
+    ///
+    /// let name = Ident::new("getUser")?.function();
+    ///
+    /// let tokens: python::Tokens = quote!($(&name)());
+    ///
+    /// let config = python::Config::default().with_function_naming(Case::Snake);
+    /// let fmt = fmt::Config::from_lang::<Python>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(vec!["get_user()"], w.into_vec());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn function(self) -> Self {
+        Self(self.0, Some(IdentKind::Function))
+    }
+
+    /// Access the underlying identifier.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for Ident {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Ident {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<L> FormatInto<L> for Ident
+where
+    L: Lang,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        match self.1 {
+            Some(kind) => tokens.item(Item::Ident(kind, self.0)),
+            None => tokens.item(Item::Literal(L::sanitize_ident(self.0))),
+        }
+    }
+}
+
+impl<L> FormatInto<L> for &Ident
+where
+    L: Lang,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        self.clone().format_into(tokens);
+    }
+}
+
+/// A language-neutral type name, like the name of a class or a struct.
+///
+/// Type names are validated like an [Ident], but must in addition start with
+/// an uppercase character.
+///
+/// When formatted, the naming convention configured for types is applied
+/// through [Lang::ident_case], after which the type name is passed through
+/// [Lang::sanitize_ident].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::TypeName;
+///
+/// let name = TypeName::new("Request")?;
+/// assert!(TypeName::new("request").is_err());
+/// assert!(TypeName::new("Request<T>").is_err());
+///
+/// let tokens: java::Tokens = quote!(public class $name {});
+/// assert_eq!("public class Request {}", tokens.to_string()?);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TypeName(ItemStr);
+
+impl TypeName {
+    /// Construct a new validated type name.
+    pub fn new<S>(name: S) -> Result<Self, InvalidIdent>
+    where
+        S: Into<ItemStr>,
+    {
+        let name = name.into();
+        let first = validate(&name)?;
+
+        if !first.is_uppercase() {
+            return Err(InvalidIdent {
+                kind: InvalidIdentKind::NotUppercase(first),
+            });
+        }
+
+        Ok(Self(name))
+    }
+
+    /// Access the underlying type name.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for TypeName {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for TypeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<L> FormatInto<L> for TypeName
+where
+    L: Lang,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.item(Item::Ident(IdentKind::Type, self.0));
+    }
+}
+
+impl<L> FormatInto<L> for &TypeName
+where
+    L: Lang,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.item(Item::Ident(IdentKind::Type, self.0.clone()));
+    }
+}
@@ -0,0 +1,291 @@
+//! Stable, machine-readable serialization of token streams.
+
+use core::fmt;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Error as _, Serialize, SerializeSeq, Serializer};
+
+use crate::fmt::Indentation;
+use crate::lang::Lang;
+use crate::tokens::{CommentKind, IdentKind, Item, ItemStr};
+use crate::Tokens;
+
+/// The current version of the IR format.
+///
+/// This is bumped whenever the format changes in an incompatible manner.
+pub const IR_VERSION: u32 = 1;
+
+/// Error raised when decoding a token stream from IR.
+#[derive(Debug)]
+pub struct IrError {
+    kind: IrErrorKind,
+}
+
+#[derive(Debug)]
+enum IrErrorKind {
+    Json(serde_json::Error),
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for IrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            IrErrorKind::Json(error) => error.fmt(f),
+            IrErrorKind::UnsupportedVersion(version) => {
+                write!(f, "unsupported IR version {version}, expected {IR_VERSION}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IrError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            IrErrorKind::Json(error) => Some(error),
+            IrErrorKind::UnsupportedVersion(..) => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for IrError {
+    fn from(error: serde_json::Error) -> Self {
+        Self {
+            kind: IrErrorKind::Json(error),
+        }
+    }
+}
+
+/// The serialized form of a single item.
+///
+/// Note that the position of language items is not part of the format, since
+/// it is reconstructed when the stream is decoded.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+enum IrItem<S, T> {
+    Literal(S),
+    Lang(T),
+    Register(T),
+    Push,
+    Line,
+    Space,
+    Indentation(i16),
+    OpenQuote(bool),
+    CloseQuote,
+    OpenEval,
+    CloseEval,
+    Ident(IdentKind, S),
+    OpenGroup,
+    SoftBreak,
+    CloseGroup,
+    Declare(S),
+    Param(S),
+    Origin(S),
+    ImportsHere,
+    Comment(CommentKind, S),
+    SoftSpace,
+    Anchor(S),
+    OpenIndentation(Indentation),
+    CloseIndentation,
+    OpenLimitedGroup(usize),
+    OpenNestedGroup,
+    SoftIndentation(i16),
+    TrailingSeparator(S),
+}
+
+/// The versioned envelope of a serialized token stream.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct IrFile<T> {
+    version: u32,
+    items: T,
+}
+
+impl<L> Serialize for Tokens<L>
+where
+    L: Lang,
+    L::Item: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(None)?;
+
+        for item in self.iter() {
+            let item: IrItem<&str, &L::Item> = match item {
+                Item::Literal(literal) => IrItem::Literal(literal.as_ref()),
+                Item::Lang(_, item) => IrItem::Lang(item),
+                Item::Register(_, item) => IrItem::Register(item),
+                Item::Push => IrItem::Push,
+                Item::Line => IrItem::Line,
+                Item::Space => IrItem::Space,
+                Item::SoftSpace => IrItem::SoftSpace,
+                Item::Anchor(name) => IrItem::Anchor(name.as_ref()),
+                Item::OpenIndentation(indentation) => IrItem::OpenIndentation(*indentation),
+                Item::CloseIndentation => IrItem::CloseIndentation,
+                Item::Indentation(n) => IrItem::Indentation(*n),
+                Item::OpenQuote(has_eval) => IrItem::OpenQuote(*has_eval),
+                Item::CloseQuote => IrItem::CloseQuote,
+                Item::OpenEval => IrItem::OpenEval,
+                Item::CloseEval => IrItem::CloseEval,
+                Item::Ident(kind, ident) => IrItem::Ident(*kind, ident.as_ref()),
+                Item::OpenGroup => IrItem::OpenGroup,
+                Item::SoftBreak => IrItem::SoftBreak,
+                Item::CloseGroup => IrItem::CloseGroup,
+                Item::OpenLimitedGroup(width) => IrItem::OpenLimitedGroup(*width),
+                Item::OpenNestedGroup => IrItem::OpenNestedGroup,
+                Item::SoftIndentation(n) => IrItem::SoftIndentation(*n),
+                Item::TrailingSeparator(separator) => IrItem::TrailingSeparator(separator.as_ref()),
+                Item::Declare(name) => IrItem::Declare(name.as_ref()),
+                Item::Param(name) => IrItem::Param(name.as_ref()),
+                Item::Origin(origin) => IrItem::Origin(origin.as_ref()),
+                Item::ImportsHere => IrItem::ImportsHere,
+                Item::Comment(kind, text) => IrItem::Comment(*kind, text.as_ref()),
+                Item::Display(value) => {
+                    // NB: Displayed values are stored as the literal they
+                    // display as.
+                    seq.serialize_element(&IrItem::<&str, &L::Item>::Literal(&value.to_string()))?;
+                    continue;
+                }
+                Item::OpenConfig(..) | Item::CloseConfig => {
+                    return Err(S::Error::custom(
+                        "configuration overrides can't be serialized",
+                    ));
+                }
+            };
+
+            seq.serialize_element(&item)?;
+        }
+
+        seq.end()
+    }
+}
+
+impl<'de, L> Deserialize<'de> for Tokens<L>
+where
+    L: Lang,
+    L::Item: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let items = Vec::<IrItem<String, L::Item>>::deserialize(deserializer)?;
+        let mut tokens = Tokens::with_capacity(items.len());
+
+        for item in items {
+            let item = match item {
+                IrItem::Literal(literal) => Item::Literal(ItemStr::from(literal)),
+                IrItem::Lang(item) => Item::Lang(0, item.into()),
+                IrItem::Register(item) => Item::Register(0, item.into()),
+                IrItem::Push => Item::Push,
+                IrItem::Line => Item::Line,
+                IrItem::Space => Item::Space,
+                IrItem::SoftSpace => Item::SoftSpace,
+                IrItem::Anchor(name) => Item::Anchor(ItemStr::from(name)),
+                IrItem::OpenIndentation(indentation) => Item::OpenIndentation(indentation),
+                IrItem::CloseIndentation => Item::CloseIndentation,
+                IrItem::Indentation(n) => Item::Indentation(n),
+                IrItem::OpenQuote(has_eval) => Item::OpenQuote(has_eval),
+                IrItem::CloseQuote => Item::CloseQuote,
+                IrItem::OpenEval => Item::OpenEval,
+                IrItem::CloseEval => Item::CloseEval,
+                IrItem::Ident(kind, ident) => Item::Ident(kind, ItemStr::from(ident)),
+                IrItem::OpenGroup => Item::OpenGroup,
+                IrItem::SoftBreak => Item::SoftBreak,
+                IrItem::CloseGroup => Item::CloseGroup,
+                IrItem::OpenLimitedGroup(width) => Item::OpenLimitedGroup(width),
+                IrItem::OpenNestedGroup => Item::OpenNestedGroup,
+                IrItem::SoftIndentation(n) => Item::SoftIndentation(n),
+                IrItem::TrailingSeparator(separator) => {
+                    Item::TrailingSeparator(ItemStr::from(separator))
+                }
+                IrItem::Declare(name) => Item::Declare(ItemStr::from(name)),
+                IrItem::Param(name) => Item::Param(ItemStr::from(name)),
+                IrItem::Origin(origin) => Item::Origin(ItemStr::from(origin)),
+                IrItem::ImportsHere => Item::ImportsHere,
+                IrItem::Comment(kind, text) => Item::Comment(kind, ItemStr::from(text)),
+            };
+
+            tokens.item(item);
+        }
+
+        Ok(tokens)
+    }
+}
+
+impl<L> Tokens<L>
+where
+    L: Lang,
+{
+    /// Serialize the token stream into genco IR.
+    ///
+    /// The IR is a versioned JSON document, which can be stored or sent to
+    /// another process and turned back into a token stream using
+    /// [Tokens::from_ir]. Language items like imports are preserved, so the
+    /// decoded token stream formats identically to the original.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let map = rust::import("std::collections", "HashMap");
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     let m = $map::new();
+    /// };
+    ///
+    /// let ir = tokens.to_ir()?;
+    /// let decoded = rust::Tokens::from_ir(&ir)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use std::collections::HashMap;",
+    ///         "",
+    ///         "let m = HashMap::new();",
+    ///     ],
+    ///     decoded.to_file_vec()?
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_ir(&self) -> Result<String, IrError>
+    where
+        L::Item: Serialize,
+    {
+        Ok(serde_json::to_string(&IrFile {
+            version: IR_VERSION,
+            items: self,
+        })?)
+    }
+
+    /// Deserialize a token stream from genco IR produced by [Tokens::to_ir].
+    ///
+    /// # Errors
+    ///
+    /// Errors if the input isn't valid IR, or if it was produced by an
+    /// incompatible version of the format.
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// assert!(rust::Tokens::from_ir(r#"{"version":0,"items":[]}"#).is_err());
+    /// ```
+    pub fn from_ir(input: &str) -> Result<Self, IrError>
+    where
+        L::Item: for<'de> Deserialize<'de>,
+    {
+        let file: IrFile<serde_json::Value> = serde_json::from_str(input)?;
+
+        if file.version != IR_VERSION {
+            return Err(IrError {
+                kind: IrErrorKind::UnsupportedVersion(file.version),
+            });
+        }
+
+        Ok(serde_json::from_value(file.items)?)
+    }
+}
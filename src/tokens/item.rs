@@ -2,8 +2,11 @@
 
 use alloc::boxed::Box;
 
+use crate::fmt::Indentation;
 use crate::lang::Lang;
-use crate::tokens::{FormatInto, ItemStr, Tokens};
+use crate::tokens::{
+    CommentKind, ConfigOverride, DisplayValue, FormatInto, IdentKind, ItemStr, Tokens,
+};
 
 /// A single item in a stream of tokens.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -28,6 +31,11 @@ where
     /// Multiple spacings in sequence are collapsed into one.
     /// A spacing does nothing if at the beginning of a line.
     Space,
+    /// A space which is only written if it's surrounded by non-whitespace on
+    /// the same line, and which is absorbed by any other space next to it.
+    ///
+    /// See [Tokens::soft_space].
+    SoftSpace,
     /// Manage indentation.
     ///
     /// An indentation of 0 has no effect.
@@ -46,6 +54,88 @@ where
     OpenEval,
     /// Close evaluation.
     CloseEval,
+    /// An identifier of the given kind, which has the naming convention
+    /// configured for its kind applied when formatted.
+    Ident(IdentKind, ItemStr),
+    /// Open a group which is formatted on a single line if it fits within the
+    /// configured [max width], or with each [Item::SoftBreak] in it turned
+    /// into an indented line break if it doesn't.
+    ///
+    /// [max width]: crate::fmt::Config::with_max_width
+    OpenGroup,
+    /// A line break which is only realized if the surrounding group doesn't
+    /// fit on one line.
+    SoftBreak,
+    /// Close the current group.
+    CloseGroup,
+    /// Use the given indentation style for the lines up until the matching
+    /// [Item::CloseIndentation], instead of the configured one.
+    ///
+    /// See [Tokens::with_indentation].
+    OpenIndentation(Indentation),
+    /// Close the current indentation style.
+    CloseIndentation,
+    /// A symbol declared by the token stream, which is not rendered.
+    ///
+    /// See [Tokens::declare].
+    Declare(ItemStr),
+    /// A named placeholder which is replaced through [Tokens::bind].
+    ///
+    /// Formatting a token stream containing a placeholder is an error.
+    Param(ItemStr),
+    /// Marks the origin of the items which follow it, which is not rendered.
+    ///
+    /// See [Tokens::append_with_origin].
+    Origin(ItemStr),
+    /// A comment, which is rendered through [Lang::write_comment].
+    ///
+    /// See [Tokens::comment] and [Tokens::block_comment].
+    Comment(CommentKind, ItemStr),
+    /// A named position in the token stream, which is not rendered.
+    ///
+    /// See [Tokens::anchor] and [Tokens::insert_at].
+    Anchor(ItemStr),
+    /// Placeholder for the imports of a file, which is not rendered.
+    ///
+    /// See [Tokens::imports_here].
+    ImportsHere,
+    /// Use the language configuration produced by the override for the items
+    /// up until the matching [Item::CloseConfig].
+    ///
+    /// See [with_config_override][crate::tokens::with_config_override()].
+    OpenConfig(ConfigOverride<L>),
+    /// Restore the language configuration which was in effect before the
+    /// matching [Item::OpenConfig].
+    CloseConfig,
+    /// Like [Item::OpenGroup], but the group is also broken if it's wider
+    /// than the given number of characters when formatted on a single line.
+    ///
+    /// The group is closed by [Item::CloseGroup].
+    OpenLimitedGroup(usize),
+    /// Like [Item::OpenGroup], but if the group is inside of another group
+    /// which doesn't fit on one line, it doesn't fit either.
+    ///
+    /// The group is closed by [Item::CloseGroup].
+    ///
+    /// See [separated_with_style][crate::tokens::separated_with_style()].
+    OpenNestedGroup,
+    /// An indentation which is only realized if the surrounding group doesn't
+    /// fit on one line, in which case it behaves like [Item::Indentation].
+    ///
+    /// See [delimited][crate::tokens::delimited()].
+    SoftIndentation(i16),
+    /// A separator which is only written after the last item of a list if
+    /// the surrounding group doesn't fit on one line, and the language is
+    /// configured to use trailing commas through [Lang::style].
+    ///
+    /// See [separated_with_style][crate::tokens::separated_with_style()].
+    TrailingSeparator(ItemStr),
+    /// A value which is written through its [Display][core::fmt::Display]
+    /// implementation when formatted, like an [Item::Literal] with what it
+    /// displays as.
+    ///
+    /// See [display_quoted][crate::tokens::display_quoted()].
+    Display(DisplayValue),
 }
 
 /// Formatting an item is the same as simply adding that item to the token
@@ -1,18 +1,47 @@
 //! Helper trait to take ownership of strings.
 
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::ops::Deref;
 
 use alloc::borrow::{Cow, ToOwned};
 use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::string::String;
+use alloc::sync::Arc;
 
 use crate::lang::Lang;
 use crate::tokens::{FormatInto, Item, Tokens};
 
 /// A managed string that permits immutable borrowing.
-#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+///
+/// Strings are either static or owned by the item. Which conversions copy the
+/// string:
+///
+/// * [String] and [`Box<str>`] are moved without copying, although a [String]
+///   with excess capacity is reallocated to fit.
+/// * [`Rc<String>`] and [`Arc<String>`] are moved without copying if they are
+///   the only reference to the string, and are copied otherwise.
+/// * [`Cow<str>`] is moved without copying if it's owned.
+/// * Everything else is copied, like `&str`, `&String`, `&Box<str>`,
+///   [`Rc<str>`] and [`Arc<str>`].
+///
+/// Static strings can be used without allocating through [ItemStr::Static],
+/// or the [static_literal()][crate::tokens::static_literal()] function.
+///
+/// Strings are compared, ordered and hashed by their contents regardless of how
+/// they are stored. Ordering is byte-wise like [str], so it doesn't depend on
+/// locale or collation, which is what makes the ordering of imports stable.
+///
+/// ```
+/// use genco::tokens::ItemStr;
+///
+/// assert_eq!(ItemStr::Static("a"), ItemStr::from("a"));
+/// assert!(ItemStr::from("Zeta") < ItemStr::Static("alpha"));
+/// assert!(ItemStr::Static("zeta") < ItemStr::from("Ärger"));
+/// ```
+#[derive(Debug, Clone)]
 pub enum ItemStr {
     /// A boxed string.
     Box(Box<str>),
@@ -20,6 +49,89 @@ pub enum ItemStr {
     Static(&'static str),
 }
 
+impl ItemStr {
+    /// Construct a string from something implementing [Display][fmt::Display].
+    ///
+    /// Short values are formatted into a buffer on the stack, so that only a
+    /// single exactly-sized allocation is needed. This avoids the intermediate
+    /// [String] otherwise produced by [ToString::to_string][alloc::string::ToString::to_string],
+    /// which matters when interpolating many numeric values. Longer values
+    /// are moved to the heap as soon as they don't fit, so the value is only
+    /// formatted once.
+    ///
+    /// To avoid allocating entirely, values can be displayed when the token
+    /// stream is formatted through
+    /// [display_quoted()][crate::tokens::display_quoted()] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::tokens::ItemStr;
+    ///
+    /// assert_eq!("42", &*ItemStr::from_display(42));
+    /// assert_eq!("3.5", &*ItemStr::from_display(3.5f32));
+    /// assert_eq!("x".repeat(100), &*ItemStr::from_display("x".repeat(100)));
+    /// ```
+    pub fn from_display<T>(value: T) -> Self
+    where
+        T: fmt::Display,
+    {
+        use core::fmt::Write as _;
+
+        let mut buf = DisplayBuf::Stack {
+            data: [0; STACK_BUF],
+            len: 0,
+        };
+
+        write!(buf, "{value}").expect("a Display implementation returned an error unexpectedly");
+
+        match buf {
+            DisplayBuf::Stack { data, len } => Self::Box(Box::from(stack_str(&data, len))),
+            DisplayBuf::Heap(string) => Self::Box(string.into_boxed_str()),
+        }
+    }
+}
+
+/// Size of the stack buffer used by [ItemStr::from_display].
+const STACK_BUF: usize = 64;
+
+/// Buffer used to format values, which starts out on the stack and is moved
+/// to the heap if the value doesn't fit.
+enum DisplayBuf {
+    Stack { data: [u8; STACK_BUF], len: usize },
+    Heap(String),
+}
+
+/// The string stored in a stack buffer, which only ever has whole strings
+/// written to it.
+fn stack_str(data: &[u8; STACK_BUF], len: usize) -> &str {
+    core::str::from_utf8(&data[..len]).unwrap_or_default()
+}
+
+impl fmt::Write for DisplayBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self {
+            Self::Stack { data, len } => {
+                if let Some(target) = data.get_mut(*len..*len + s.len()) {
+                    target.copy_from_slice(s.as_bytes());
+                    *len += s.len();
+                    return Ok(());
+                }
+
+                let mut string = String::with_capacity((*len + s.len()) * 2);
+                string.push_str(stack_str(data, *len));
+                string.push_str(s);
+                *self = Self::Heap(string);
+            }
+            Self::Heap(string) => {
+                string.push_str(s);
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Convert stringy things.
 impl<L> FormatInto<L> for ItemStr
 where
@@ -39,6 +151,39 @@ where
     }
 }
 
+impl PartialEq for ItemStr {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl Eq for ItemStr {}
+
+impl PartialOrd for ItemStr {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ItemStr {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ref().cmp(other.as_ref())
+    }
+}
+
+impl Hash for ItemStr {
+    #[inline]
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.as_ref().hash(state);
+    }
+}
+
 impl AsRef<str> for ItemStr {
     fn as_ref(&self) -> &str {
         match self {
@@ -65,6 +210,12 @@ impl From<Box<str>> for ItemStr {
     }
 }
 
+impl<'a> From<&'a Box<str>> for ItemStr {
+    fn from(value: &'a Box<str>) -> Self {
+        Self::Box(value.clone())
+    }
+}
+
 impl<'a> From<&'a ItemStr> for ItemStr {
     fn from(value: &'a ItemStr) -> Self {
         value.clone()
@@ -115,7 +266,51 @@ impl<'a, 'b> From<&'b Cow<'a, str>> for ItemStr {
 
 impl From<Rc<String>> for ItemStr {
     fn from(value: Rc<String>) -> Self {
-        Self::Box((*value).clone().into())
+        let value = Rc::try_unwrap(value).unwrap_or_else(|value| (*value).clone());
+        Self::Box(value.into_boxed_str())
+    }
+}
+
+impl<'a> From<&'a Rc<String>> for ItemStr {
+    fn from(value: &'a Rc<String>) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<Rc<str>> for ItemStr {
+    fn from(value: Rc<str>) -> Self {
+        Self::from(&*value)
+    }
+}
+
+impl<'a> From<&'a Rc<str>> for ItemStr {
+    fn from(value: &'a Rc<str>) -> Self {
+        Self::from(&**value)
+    }
+}
+
+impl From<Arc<String>> for ItemStr {
+    fn from(value: Arc<String>) -> Self {
+        let value = Arc::try_unwrap(value).unwrap_or_else(|value| (*value).clone());
+        Self::Box(value.into_boxed_str())
+    }
+}
+
+impl<'a> From<&'a Arc<String>> for ItemStr {
+    fn from(value: &'a Arc<String>) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<Arc<str>> for ItemStr {
+    fn from(value: Arc<str>) -> Self {
+        Self::from(&*value)
+    }
+}
+
+impl<'a> From<&'a Arc<str>> for ItemStr {
+    fn from(value: &'a Arc<str>) -> Self {
+        Self::from(&**value)
     }
 }
 
@@ -124,3 +319,23 @@ impl fmt::Display for ItemStr {
         self.as_ref().fmt(fmt)
     }
 }
+
+#[cfg(feature = "ir")]
+impl serde::Serialize for ItemStr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_ref())
+    }
+}
+
+#[cfg(feature = "ir")]
+impl<'de> serde::Deserialize<'de> for ItemStr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(ItemStr::from(String::deserialize(deserializer)?))
+    }
+}
@@ -56,26 +56,172 @@
 //! # }
 //! ```
 
+mod anchor;
+mod boxed;
+mod case;
+mod chain;
+mod clipped;
+mod comment;
+mod config_override;
+mod delimited;
+mod diff;
 mod display;
+mod display_quoted;
+mod do_not_edit;
 mod format_into;
 mod from_fn;
+mod ident;
 mod internal;
+#[cfg(feature = "ir")]
+mod ir;
 mod item;
 mod item_str;
+mod param;
+mod parse_lines;
 mod quoted;
 mod register;
+mod separated;
 mod static_literal;
+mod static_tokens;
+mod stats;
 mod tokens;
+pub mod visit;
+mod with_config;
 
+pub use self::anchor::MissingAnchor;
+pub use self::boxed::{BoxFormatInto, DynFormatInto};
+#[cfg(any(
+    feature = "lang-csharp",
+    feature = "lang-dart",
+    feature = "lang-go",
+    feature = "lang-java",
+    feature = "lang-js",
+    feature = "lang-python",
+    feature = "lang-rust",
+    feature = "lang-swift",
+))]
+pub(crate) use self::case::Naming;
+pub use self::case::{Case, IdentKind};
+pub use self::chain::{chain, Chain};
+pub use self::clipped::{clipped, Clipped};
+pub use self::comment::CommentKind;
+pub use self::config_override::{with_config_override, ConfigOverride, WithConfigOverride};
+pub use self::delimited::{delimited, Delimited};
+pub use self::diff::{diff, ItemChange, TokenDiff};
 pub use self::display::{display, Display};
+pub use self::display_quoted::{display_quoted, DisplayQuoted, DisplayValue};
+pub use self::do_not_edit::{do_not_edit_header, DoNotEditHeader};
 pub use self::format_into::FormatInto;
 pub use self::from_fn::{from_fn, FromFn};
+pub use self::ident::{Ident, InvalidIdent, TypeName};
+#[cfg(feature = "ir")]
+pub use self::ir::{IrError, IR_VERSION};
 pub use self::item::Item;
 pub use self::item_str::ItemStr;
+pub use self::param::{param, Param, UnboundParam};
+pub use self::parse_lines::parse_lines;
 pub use self::quoted::{quoted, QuotedFn};
 pub use self::register::{register, Register, RegisterFn};
+pub use self::separated::{separated_with_style, SeparatedWithStyle};
 pub use self::static_literal::static_literal;
+pub use self::static_tokens::StaticTokens;
+pub use self::stats::TokenStats;
 pub use self::tokens::Tokens;
+pub use self::with_config::WithConfig;
+/// Derive [FormatInto] for a type from a template written with the same
+/// syntax as [quote!][crate::quote].
+///
+/// The template is specified with `#[genco(template = { ... })]` on a struct,
+/// or on each variant of an enum. Fields are bound by name, while the fields
+/// of tuples are bound as `_0`, `_1`, and so on.
+///
+/// By default [FormatInto] is implemented for every language, and any type
+/// parameter is required to implement [FormatInto] as well. A specific
+/// language is picked with `#[genco(lang = ...)]`, which is needed if the
+/// template uses language items like imports.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// #[derive(FormatInto)]
+/// #[genco(template = { $name: $ty })]
+/// struct Arg {
+///     name: &'static str,
+///     ty: &'static str,
+/// }
+///
+/// #[derive(FormatInto)]
+/// #[genco(template = {
+///     fn $name($(for arg in args join (, ) => $arg)) {
+///         $body
+///     }
+/// })]
+/// struct Method {
+///     name: &'static str,
+///     args: Vec<Arg>,
+///     body: Statement,
+/// }
+///
+/// #[derive(FormatInto)]
+/// enum Statement {
+///     #[genco(template = { return $_0; })]
+///     Return(u32),
+///     #[genco(template = { todo!() })]
+///     Todo,
+/// }
+///
+/// let method = Method {
+///     name: "answer",
+///     args: vec![
+///         Arg { name: "a", ty: "u32" },
+///         Arg { name: "b", ty: "u32" },
+///     ],
+///     body: Statement::Return(42),
+/// };
+///
+/// let tokens: rust::Tokens = quote!($method);
+///
+/// assert_eq!(
+///     vec![
+///         "fn answer(a: u32, b: u32) {",
+///         "    return 42;",
+///         "}",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
+/// Picking a language to use its imports:
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// #[derive(FormatInto)]
+/// #[genco(lang = Rust, template = {
+///     let $name = $(rust::import("std::collections", "HashMap"))::<$key, $value>::new();
+/// })]
+/// struct NewMap<K, V> {
+///     name: &'static str,
+///     key: K,
+///     value: V,
+/// }
+///
+/// let tokens: rust::Tokens = quote!($(NewMap { name: "map", key: "u32", value: "String" }));
+///
+/// assert_eq!(
+///     vec![
+///         "use std::collections::HashMap;",
+///         "",
+///         "let map = HashMap::<u32, String>::new();",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub use genco_macros::FormatInto;
 
 #[doc(hidden)]
 pub use self::internal::__lang_item;
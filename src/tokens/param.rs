@@ -0,0 +1,90 @@
+use core::fmt;
+
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, Item, ItemStr};
+use crate::Tokens;
+
+/// Function to add a named placeholder to a token stream, which is later
+/// replaced with a fragment through [Tokens::bind].
+///
+/// Formatting a token stream which still contains placeholders is an error.
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::param;
+///
+/// let template: rust::Tokens = quote! {
+///     fn $(param("name"))() -> u32 {
+///         $(param("body"))
+///     }
+/// };
+///
+/// assert!(template.to_string().is_err());
+///
+/// let tokens = template.bind([
+///     ("name", quote!(answer)),
+///     ("body", quote!(42)),
+/// ])?;
+///
+/// assert_eq!(
+///     vec![
+///         "fn answer() -> u32 {",
+///         "    42",
+///         "}",
+///     ],
+///     tokens.to_file_vec()?
+/// );
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn param<N>(name: N) -> Param
+where
+    N: Into<ItemStr>,
+{
+    Param { name: name.into() }
+}
+
+/// A named placeholder in a token stream.
+///
+/// This is constructed with the [param()] function.
+#[derive(Debug, Clone)]
+pub struct Param {
+    name: ItemStr,
+}
+
+impl<L> FormatInto<L> for Param
+where
+    L: Lang,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.item(Item::Param(self.name));
+    }
+}
+
+/// Error raised by [Tokens::bind] when a placeholder in the token stream has
+/// no value bound to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnboundParam {
+    name: ItemStr,
+}
+
+impl UnboundParam {
+    pub(crate) fn new(name: ItemStr) -> Self {
+        Self { name }
+    }
+
+    /// The name of the placeholder which has no value bound to it.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for UnboundParam {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no value bound to parameter `{}`", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnboundParam {}
@@ -0,0 +1,134 @@
+use alloc::vec::Vec;
+
+use crate::lang::Lang;
+use crate::tokens::ItemStr;
+use crate::Tokens;
+
+/// Parse formatted source back into a token stream, so that it can be
+/// combined with newly generated tokens.
+///
+/// Each line is added as a single literal, and the whitespace between lines
+/// is reconstructed as pushes, empty lines and changes in indentation. So the
+/// result is formatted with the indentation of where it's used and the
+/// configured indentation style. Indentation levels are determined by how far
+/// lines are indented compared to the lines around them, so any consistent
+/// indentation width can be parsed. Indentation which is shared by all lines
+/// is removed.
+///
+/// Note that everything else is kept as-is. Language items like imports can't
+/// be recovered from formatted source, so a parsed file should not contain the
+/// imports which are written by [Tokens::format_file].
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::parse_lines;
+///
+/// let source = "fn preserved() {\n  if a {\n    b();\n  }\n\n  c();\n}\n";
+/// let preserved: rust::Tokens = parse_lines(source);
+///
+/// let toks: rust::Tokens = quote! {
+///     mod generated {
+///         fn generated() {}
+///
+///         $preserved
+///     }
+/// };
+///
+/// assert_eq!(
+///     vec![
+///         "mod generated {",
+///         "    fn generated() {}",
+///         "",
+///         "    fn preserved() {",
+///         "        if a {",
+///         "            b();",
+///         "        }",
+///         "",
+///         "        c();",
+///         "    }",
+///         "}",
+///     ],
+///     toks.to_file_vec()?
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
+/// Output which is formatted with the default configuration is parsed into a
+/// token stream which formats the same.
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::parse_lines;
+///
+/// let toks: python::Tokens = quote! {
+///     class Foo:
+///         def bar(self):
+///             pass
+///
+///     def baz():
+///         return 42
+/// };
+///
+/// let source = toks.to_file_string()?;
+/// let parsed: python::Tokens = parse_lines(&source);
+///
+/// assert_eq!(source, parsed.to_file_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn parse_lines<L>(input: &str) -> Tokens<L>
+where
+    L: Lang,
+{
+    let base = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(indentation)
+        .min()
+        .unwrap_or_default();
+
+    let mut tokens = Tokens::new();
+    // The widths of the indentation levels which are currently open.
+    let mut levels = Vec::new();
+    let mut empty = false;
+
+    for line in input.lines() {
+        let content = line.trim();
+
+        if content.is_empty() {
+            empty = !tokens.is_empty();
+            continue;
+        }
+
+        let width = indentation(line) - base;
+
+        while levels.last().map_or(false, |&last| width < last) {
+            levels.pop();
+            tokens.unindent();
+        }
+
+        if width > levels.last().copied().unwrap_or(0) {
+            levels.push(width);
+            tokens.indent();
+        }
+
+        // NB: Empty lines are added after indentation changes, since they
+        // would otherwise be absorbed by them.
+        if core::mem::take(&mut empty) {
+            tokens.line();
+        } else {
+            tokens.push();
+        }
+
+        tokens.append(ItemStr::from(content));
+    }
+
+    tokens
+}
+
+/// The width of the indentation of a line, where each whitespace character
+/// counts as one.
+fn indentation(line: &str) -> usize {
+    line.chars().take_while(|c| c.is_whitespace()).count()
+}
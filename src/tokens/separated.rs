@@ -0,0 +1,113 @@
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, Item, ItemStr};
+use crate::Tokens;
+
+/// Format a list of items separated by a separator.
+///
+/// This struct is created by the [separated_with_style()] function.
+pub struct SeparatedWithStyle<I> {
+    separator: ItemStr,
+    items: I,
+}
+
+/// Format a list of items separated by the given separator, like `,`, which
+/// consults the [style] of the language while formatting.
+///
+/// The items are written on a single line if the surrounding group fits on
+/// one line. Otherwise each item is put on a line of its own, and the last
+/// item is followed by a trailing separator if the language is configured to
+/// use trailing commas.
+///
+/// The items form a group of their own, so they are kept on one line when used
+/// on their own and they fit. Inside of a group like the one opened by
+/// [delimited()][crate::tokens::delimited()], they are put on lines of their
+/// own whenever that group doesn't fit.
+///
+/// [style]: crate::lang::Style
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::fmt;
+/// use genco::tokens::{delimited, separated_with_style};
+///
+/// let short = ["a", "b"];
+/// let long = ["first_argument", "second_argument", "third_argument"];
+///
+/// let toks: rust::Tokens = quote! {
+///     foo$(delimited("(", ")", separated_with_style(",", short)));
+///     foo$(delimited("(", ")", separated_with_style(",", long)));
+/// };
+///
+/// let fmt = fmt::Config::from_lang::<Rust>().with_max_width(40);
+///
+/// let mut w = fmt::VecWriter::new();
+/// toks.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+///
+/// assert_eq!(
+///     vec![
+///         "foo(a, b);",
+///         "foo(",
+///         "    first_argument,",
+///         "    second_argument,",
+///         "    third_argument",
+///         ");",
+///     ],
+///     w.into_vec()
+/// );
+///
+/// let config = rust::Config::default().with_trailing_commas(true);
+///
+/// let mut w = fmt::VecWriter::new();
+/// toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+///
+/// assert_eq!(
+///     vec![
+///         "foo(a, b);",
+///         "foo(",
+///         "    first_argument,",
+///         "    second_argument,",
+///         "    third_argument,",
+///         ");",
+///     ],
+///     w.into_vec()
+/// );
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+pub fn separated_with_style<S, I>(separator: S, items: I) -> SeparatedWithStyle<I>
+where
+    S: Into<ItemStr>,
+{
+    SeparatedWithStyle {
+        separator: separator.into(),
+        items,
+    }
+}
+
+impl<L, I> FormatInto<L> for SeparatedWithStyle<I>
+where
+    L: Lang,
+    I: IntoIterator,
+    I::Item: FormatInto<L>,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        tokens.item(Item::OpenNestedGroup);
+
+        let mut it = self.items.into_iter().peekable();
+
+        while let Some(item) = it.next() {
+            item.format_into(tokens);
+
+            if it.peek().is_some() {
+                tokens.append(self.separator.clone());
+                tokens.space();
+                tokens.item(Item::SoftIndentation(0));
+            } else {
+                tokens.item(Item::TrailingSeparator(self.separator.clone()));
+            }
+        }
+
+        tokens.item(Item::CloseGroup);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::lang::Lang;
+use crate::tokens::{FormatInto, Item};
+use crate::Tokens;
+
+/// A token stream which is fully known at compile time.
+///
+/// Created through the [static_quote!] macro, which allows fixed fragments to
+/// be stored in a `static` or `const` and shared without being rebuilt every
+/// time they are used.
+///
+/// [static_quote!]: crate::static_quote
+///
+/// # Examples
+///
+/// ```
+/// use genco::prelude::*;
+/// use genco::tokens::StaticTokens;
+///
+/// static HEADER: StaticTokens<Rust> = static_quote! {
+///     #![allow(dead_code)]
+///     #![allow(unused_imports)]
+/// };
+///
+/// let tokens: rust::Tokens = quote! {
+///     $HEADER
+///
+///     fn main() {}
+/// };
+///
+/// assert_eq! {
+///     vec![
+///         "#![allow(dead_code)]",
+///         "#![allow(unused_imports)]",
+///         "",
+///         "fn main() {}",
+///     ],
+///     tokens.to_file_vec()?
+/// };
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct StaticTokens<L>
+where
+    L: Lang,
+{
+    items: &'static [Item<L>],
+}
+
+impl<L> StaticTokens<L>
+where
+    L: Lang,
+{
+    /// Construct a static token stream from the given items.
+    ///
+    /// This is used by the [static_quote!] macro and is typically not called
+    /// directly.
+    ///
+    /// [static_quote!]: crate::static_quote
+    pub const fn new(items: &'static [Item<L>]) -> Self {
+        Self { items }
+    }
+
+    /// Test if the static token stream is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<L> FormatInto<L> for StaticTokens<L>
+where
+    L: Lang,
+{
+    fn format_into(self, tokens: &mut Tokens<L>) {
+        for item in self.items {
+            tokens.item(item.clone());
+        }
+    }
+}
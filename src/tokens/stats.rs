@@ -0,0 +1,114 @@
+use crate::lang::Lang;
+use crate::tokens::{Item, ItemStr};
+
+/// Statistics over the items in a token stream.
+///
+/// This is useful to find out which parts of a large generator produce
+/// streams that are expensive to build or keep around.
+///
+/// Created through [Tokens::stats][crate::Tokens::stats].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TokenStats {
+    items: usize,
+    capacity: usize,
+    lang_items: usize,
+    static_bytes: usize,
+    boxed_bytes: usize,
+    depth: usize,
+}
+
+impl TokenStats {
+    /// Compute statistics over the given items.
+    pub(crate) fn new<L>(items: &[Item<L>], capacity: usize) -> Self
+    where
+        L: Lang,
+    {
+        let mut stats = Self {
+            items: items.len(),
+            capacity,
+            ..Self::default()
+        };
+
+        let mut depth = 0isize;
+
+        for item in items {
+            match item {
+                Item::Literal(s)
+                | Item::Ident(_, s)
+                | Item::Declare(s)
+                | Item::Param(s)
+                | Item::Origin(s)
+                | Item::Comment(_, s)
+                | Item::Anchor(s)
+                | Item::TrailingSeparator(s) => match s {
+                    ItemStr::Static(s) => stats.static_bytes += s.len(),
+                    ItemStr::Box(s) => stats.boxed_bytes += s.len(),
+                },
+                Item::Lang(..) | Item::Register(..) => {
+                    stats.lang_items += 1;
+                }
+                Item::Indentation(n) | Item::SoftIndentation(n) => {
+                    depth += isize::from(*n);
+                }
+                Item::OpenQuote(..)
+                | Item::OpenEval
+                | Item::OpenGroup
+                | Item::OpenLimitedGroup(..)
+                | Item::OpenNestedGroup
+                | Item::OpenIndentation(..)
+                | Item::OpenConfig(..) => {
+                    depth += 1;
+                }
+                Item::CloseQuote
+                | Item::CloseEval
+                | Item::CloseGroup
+                | Item::CloseIndentation
+                | Item::CloseConfig => {
+                    depth -= 1;
+                }
+                _ => {}
+            }
+
+            stats.depth = stats.depth.max(depth.max(0) as usize);
+        }
+
+        stats
+    }
+
+    /// The number of items in the token stream.
+    pub fn items(&self) -> usize {
+        self.items
+    }
+
+    /// The number of items the token stream has allocated space for.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of language items, like imports, in the token stream.
+    ///
+    /// Each language item is boxed, so this is also the number of separate
+    /// allocations made for them.
+    pub fn lang_items(&self) -> usize {
+        self.lang_items
+    }
+
+    /// The number of bytes of strings in the token stream which are static,
+    /// and as such don't need to be allocated.
+    pub fn static_bytes(&self) -> usize {
+        self.static_bytes
+    }
+
+    /// The number of bytes of strings in the token stream which have been
+    /// allocated.
+    pub fn boxed_bytes(&self) -> usize {
+        self.boxed_bytes
+    }
+
+    /// The deepest level of nesting in the token stream, where each level of
+    /// indentation and each open quote, evaluation, group or similar region
+    /// counts as one level.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
@@ -16,12 +16,16 @@ use core::mem;
 use core::slice;
 
 use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
 use alloc::string::String;
 use alloc::vec::{self, Vec};
 
 use crate::fmt;
 use crate::lang::{Lang, LangSupportsEval};
-use crate::tokens::{FormatInto, Item, Register};
+use crate::tokens::{
+    CommentKind, FormatInto, Item, ItemStr, MissingAnchor, Register, TokenStats, UnboundParam,
+    WithConfig,
+};
 
 /// A stream of tokens.
 ///
@@ -66,6 +70,25 @@ use crate::tokens::{FormatInto, Item, Register};
 /// assert_eq!(vec![Item::Line::<()>], tokens);
 /// ```
 ///
+/// # Thread Safety
+///
+/// A stream of tokens is [Send] and [Sync] as long as the language items it
+/// stores are. This holds for all languages provided by genco, so token streams
+/// can be built on worker threads and sent elsewhere to be formatted.
+///
+/// ```
+/// use genco::prelude::*;
+///
+/// let handle = std::thread::spawn(|| -> rust::Tokens {
+///     let map = rust::import("std::collections", "HashMap");
+///     quote!(let m = $map::new();)
+/// });
+///
+/// let tokens = handle.join().unwrap();
+/// assert_eq!("let m = HashMap::new();", tokens.to_string()?);
+/// # Ok::<_, genco::fmt::Error>(())
+/// ```
+///
 /// [`space`]: Self::space
 /// [`push`]: Self::push
 /// [`line`]: Self::line
@@ -171,109 +194,1040 @@ where
     ///
     /// assert_eq!(quote!($(4u32)), tokens);
     /// ```
-    pub fn append<T>(&mut self, tokens: T)
+    pub fn append<T>(&mut self, tokens: T)
+    where
+        T: FormatInto<L>,
+    {
+        tokens.format_into(self)
+    }
+
+    /// Append each item produced by an iterator, with the given separator
+    /// appended between them.
+    ///
+    /// This is the same as `$(for item in items join (<separator>) => $item)`
+    /// in the [quote!] macro.
+    ///
+    /// [quote!]: macro.quote.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let args = ["a", "b", "c"];
+    ///
+    /// let mut tokens = rust::Tokens::new();
+    /// tokens.append("call(");
+    /// tokens.append_separated(args, quote!(,$[' ']));
+    /// tokens.append(")");
+    ///
+    /// assert_eq!("call(a, b, c)", tokens.to_string()?);
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     let sum = $(ref t => t.append_separated(1..=3, " + "));
+    /// };
+    ///
+    /// assert_eq!("let sum = 1 + 2 + 3;", tokens.to_string()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn append_separated<I, S>(&mut self, items: I, separator: S)
+    where
+        I: IntoIterator,
+        I::Item: FormatInto<L>,
+        S: FormatInto<L> + Clone,
+    {
+        let mut first = true;
+
+        for item in items {
+            if !core::mem::take(&mut first) {
+                self.append(separator.clone());
+            }
+
+            self.append(item);
+        }
+    }
+
+    /// Append the given tokens, marked with an origin like the location of
+    /// the code which generated them.
+    ///
+    /// The origin isn't rendered, but when the tokens are formatted the
+    /// position in the output where they start is recorded as a
+    /// [Mapping][crate::fmt::Mapping] in the writer. This can be used to
+    /// build a source map from the generated code back to the generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::fmt;
+    /// use genco::prelude::*;
+    ///
+    /// let body: js::Tokens = quote!(console.log("hello"););
+    ///
+    /// let function: js::Tokens = quote! {
+    ///     function greet() {
+    ///         $(ref t => t.append_with_origin(body, "gen.rs:10"))
+    ///     }
+    /// };
+    ///
+    /// let mut tokens = js::Tokens::new();
+    /// tokens.append_with_origin(function, "gen.rs:20");
+    ///
+    /// let fmt = fmt::Config::from_lang::<JavaScript>();
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &js::Config::default())?;
+    ///
+    /// let mappings = w
+    ///     .mappings()
+    ///     .iter()
+    ///     .map(|m| (m.line(), m.column(), m.origin()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(vec![(0, 0, "gen.rs:20"), (1, 4, "gen.rs:10")], mappings);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "function greet() {",
+    ///         "    console.log(\"hello\");",
+    ///         "}",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn append_with_origin<T, O>(&mut self, tokens: T, origin: O)
+    where
+        T: FormatInto<L>,
+        O: Into<ItemStr>,
+    {
+        self.items.push(Item::Origin(origin.into()));
+        tokens.format_into(self)
+    }
+
+    /// Extend with another stream of tokens.
+    ///
+    /// This respects the structural requirements of adding one element at a
+    /// time, like you would get by calling [`space`], [`push`], or [`line`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::tokens::{Item, ItemStr};
+    ///
+    /// let mut tokens: Tokens<()> = quote!(foo bar);
+    /// tokens.extend::<Tokens<()>>(quote!($[' ']baz));
+    ///
+    /// assert_eq!(tokens, quote!(foo bar baz));
+    /// ```
+    ///
+    /// [`space`]: Self::space
+    /// [`push`]: Self::push
+    /// [`line`]: Self::line
+    pub fn extend<I>(&mut self, it: I)
+    where
+        I: IntoIterator<Item = Item<L>>,
+    {
+        let it = it.into_iter();
+        let (low, high) = it.size_hint();
+        self.items.reserve(high.unwrap_or(low));
+
+        for item in it {
+            self.item(item);
+        }
+    }
+
+    /// Walk over all imports.
+    ///
+    /// This includes the [dependencies] of every language item, so that they
+    /// are collected as imports even though they are not part of the stream.
+    ///
+    /// The order in which the imports are returned is *not* defined. So if you
+    /// need them in some particular order you need to sort them.
+    ///
+    /// [dependencies]: crate::lang::LangItem::dependencies
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let debug = rust::import("std::fmt", "Debug");
+    /// let ty = rust::import("std::collections", "HashMap");
+    ///
+    /// let tokens = quote!(foo $ty<u32, dyn $debug> baz);
+    ///
+    /// for import in tokens.walk_imports() {
+    ///     println!("{:?}", import);
+    /// }
+    /// ```
+    pub fn walk_imports(&self) -> WalkImports<'_, L> {
+        WalkImports {
+            items: &self.items,
+            pos: self.last_lang_item,
+            dependencies: Vec::new(),
+        }
+    }
+
+    /// Add an registered custom element that is _not_ rendered.
+    ///
+    /// Registration can be used to generate imports that do not render a
+    /// visible result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let write_bytes_ext = rust::import("byteorder", "WriteBytesExt").with_alias("_");
+    ///
+    /// let tokens = quote!($(register(write_bytes_ext)));
+    ///
+    /// assert_eq!("use byteorder::WriteBytesExt as _;\n", tokens.to_file_string()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    ///
+    /// [quote!]: macro.quote.html
+    pub fn register<T>(&mut self, tokens: T)
+    where
+        T: Register<L>,
+    {
+        tokens.register(self);
+    }
+
+    /// Iterate over the language items which were [registered][Self::register]
+    /// and aren't rendered, in the order they were registered.
+    ///
+    /// Registered items are stored as [Item::Register], which tells them apart
+    /// from the [Item::Lang] items that are rendered. Unlike
+    /// [walk_imports][Self::walk_imports], this doesn't include any
+    /// dependencies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let write_bytes_ext = rust::import("byteorder", "WriteBytesExt").with_alias("_");
+    /// let map = rust::import("std::collections", "HashMap");
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     $(register(write_bytes_ext.clone()))
+    ///     let m = $map::new();
+    /// };
+    ///
+    /// assert_eq!(vec![&write_bytes_ext], tokens.iter_registered().collect::<Vec<_>>());
+    /// assert_eq!(2, tokens.walk_imports().count());
+    /// ```
+    pub fn iter_registered(&self) -> IterRegistered<'_, L> {
+        IterRegistered {
+            iter: self.items.iter(),
+        }
+    }
+
+    /// Declare that the token stream defines the given top-level symbol.
+    ///
+    /// Declarations are not rendered, but are kept when the token stream is
+    /// appended to another one. They can be queried with
+    /// [declarations][Self::declarations], which is useful when generating
+    /// index files like `mod.rs` or `__init__.py`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let mut user = rust::Tokens::new();
+    /// user.declare("User");
+    /// quote_in!(user => pub struct User;);
+    ///
+    /// let tokens = quote! {
+    ///     $user
+    ///     $(ref t => t.declare("Group"))
+    ///     pub struct Group;
+    /// };
+    ///
+    /// assert_eq!(vec!["User", "Group"], tokens.declarations().collect::<Vec<_>>());
+    /// assert_eq!(vec!["pub struct User;", "pub struct Group;"], tokens.to_file_vec()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn declare<N>(&mut self, name: N)
+    where
+        N: Into<ItemStr>,
+    {
+        self.items.push(Item::Declare(name.into()));
+    }
+
+    /// Mark the position where [format_file][Self::format_file] writes the
+    /// file header, which contains things like the package declaration and
+    /// imports of the file.
+    ///
+    /// By default the file header is written before everything else, but
+    /// this allows for it to be written after something like a license
+    /// banner. Everything before the placeholder is formatted as-is, without
+    /// the language-specific file handling. Only the first placeholder is
+    /// used and the placeholder is not rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let list = java::import("java.util", "List");
+    ///
+    /// let mut tokens = java::Tokens::new();
+    /// quote_in!(tokens => $("// Copyright (c) Example Authors"));
+    /// tokens.line();
+    /// tokens.imports_here();
+    /// quote_in!(tokens => public class Items { $list<String> items; });
+    ///
+    /// let config = java::Config::default().with_package("com.example");
+    /// let fmt = fmt::Config::from_lang::<Java>();
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "// Copyright (c) Example Authors",
+    ///         "",
+    ///         "package com.example;",
+    ///         "",
+    ///         "import java.util.List;",
+    ///         "",
+    ///         "public class Items { List<String> items; }",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn imports_here(&mut self) {
+        self.items.push(Item::ImportsHere);
+    }
+
+    /// Use the given indentation style for the lines added by the closure,
+    /// instead of the one configured in [fmt::Config].
+    ///
+    /// The indentation level is unaffected, so a nested region is indented
+    /// as deep as the surrounding code but written in a different style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let mut tokens = Tokens::<()>::new();
+    ///
+    /// quote_in! { tokens =>
+    ///     config:
+    ///         name: app
+    /// };
+    ///
+    /// tokens.push();
+    ///
+    /// tokens.with_indentation(fmt::Indentation::Tab, |t| {
+    ///     quote_in! { *t =>
+    ///         build:
+    ///             cc -o app main.c
+    ///     }
+    /// });
+    ///
+    /// let fmt = fmt::Config::from_lang::<()>().with_indentation(fmt::Indentation::Space(2));
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// tokens.format_file(&mut w.as_formatter(&fmt), &())?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "config:",
+    ///         "  name: app",
+    ///         "build:",
+    ///         "\tcc -o app main.c",
+    ///     ],
+    ///     w.into_vec()
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_indentation<F>(&mut self, indentation: fmt::Indentation, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.items.push(Item::OpenIndentation(indentation));
+        f(self);
+        self.items.push(Item::CloseIndentation);
+    }
+
+    /// Record a named position in the token stream, which other tokens can
+    /// later be inserted at through [insert_at][Self::insert_at].
+    ///
+    /// Anchors are not rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let mut tokens: java::Tokens = quote! {
+    ///     public class Foo {
+    ///         private int value;
+    ///         $(ref t => t.anchor("methods"))
+    ///     }
+    /// };
+    ///
+    /// let list = &java::import("java.util", "List");
+    ///
+    /// tokens.insert_at("methods", quote! {
+    ///     $['\n']
+    ///     public int value() {
+    ///         return value;
+    ///     }
+    /// })?;
+    ///
+    /// tokens.insert_at("methods", quote! {
+    ///     $['\n']
+    ///     public $list<Integer> values() {
+    ///         return $list.of(value);
+    ///     }
+    /// })?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "import java.util.List;",
+    ///         "",
+    ///         "public class Foo {",
+    ///         "    private int value;",
+    ///         "",
+    ///         "    public int value() {",
+    ///         "        return value;",
+    ///         "    }",
+    ///         "",
+    ///         "    public List<Integer> values() {",
+    ///         "        return List.of(value);",
+    ///         "    }",
+    ///         "}",
+    ///     ],
+    ///     tokens.to_file_vec()?
+    /// );
+    ///
+    /// let error = tokens.insert_at("fields", quote!(int other;)).unwrap_err();
+    /// assert_eq!("fields", error.name());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn anchor<N>(&mut self, name: N)
+    where
+        N: Into<ItemStr>,
+    {
+        self.items.push(Item::Anchor(name.into()));
+    }
+
+    /// Insert tokens at the first [anchor][Self::anchor] with the given name.
+    ///
+    /// The tokens are inserted right before the anchor, so inserting at the
+    /// same anchor multiple times keeps the inserted tokens in order.
+    ///
+    /// # Errors
+    ///
+    /// Errors if there is no anchor with the given name, in which case the
+    /// token stream is left unchanged.
+    pub fn insert_at<N, T>(&mut self, name: N, tokens: T) -> Result<(), MissingAnchor>
+    where
+        N: Into<ItemStr>,
+        T: FormatInto<L>,
+    {
+        let name = name.into();
+
+        let position = self
+            .items
+            .iter()
+            .position(|item| matches!(item, Item::Anchor(anchor) if **anchor == *name));
+
+        let position = match position {
+            Some(position) => position,
+            None => return Err(MissingAnchor::new(name)),
+        };
+
+        // NB: Rebuild the tail of the stream to link language items and
+        // normalize whitespace around the inserted tokens.
+        let tail = self.items.split_off(position);
+
+        self.last_lang_item = self
+            .items
+            .iter()
+            .rposition(|item| matches!(item, Item::Lang(..) | Item::Register(..)))
+            .map_or(0, |n| n + 1);
+
+        tokens.format_into(self);
+
+        for item in tail {
+            self.item(item);
+        }
+
+        Ok(())
+    }
+
+    /// Add a line comment using the syntax of the target language.
+    ///
+    /// Each line in the text becomes a separate comment. Since a line comment
+    /// extends to the end of the line, anything added after it is moved onto
+    /// the next line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let mut tokens = rust::Tokens::new();
+    /// quote_in!(tokens => let a = 1;);
+    /// tokens.space();
+    /// tokens.comment("the answer");
+    /// quote_in!(tokens => let b = 2;);
+    /// tokens.push();
+    /// tokens.comment("first\nsecond");
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "let a = 1; // the answer",
+    ///         "let b = 2;",
+    ///         "// first",
+    ///         "// second",
+    ///     ],
+    ///     tokens.to_file_vec()?
+    /// );
+    ///
+    /// let mut tokens = python::Tokens::new();
+    /// tokens.comment("the answer");
+    /// quote_in!(tokens => a = 42);
+    ///
+    /// assert_eq!(vec!["# the answer", "a = 42"], tokens.to_file_vec()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn comment<T>(&mut self, text: T)
+    where
+        T: Into<ItemStr>,
+    {
+        let text = text.into();
+
+        if !text.contains('\n') {
+            self.item(Item::Comment(CommentKind::Line, text));
+            return;
+        }
+
+        for (n, line) in text.lines().enumerate() {
+            if n > 0 {
+                self.push();
+            }
+
+            self.item(Item::Comment(
+                CommentKind::Line,
+                ItemStr::from(line.trim_end()),
+            ));
+        }
+    }
+
+    /// Add a block comment using the syntax of the target language.
+    ///
+    /// Languages without block comments render it as a line comment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let tokens: rust::Tokens = quote!(fn foo(a: u32, $(ref t => t.block_comment("unused")) b: u32));
+    /// assert_eq!("fn foo(a: u32, /* unused */ b: u32)", tokens.to_string()?);
+    ///
+    /// let tokens: python::Tokens = quote!($(ref t => t.block_comment("unused")) a = 42);
+    /// assert_eq!(vec!["# unused", "a = 42"], tokens.to_file_vec()?);
+    ///
+    /// let tokens: julia::Tokens = quote!(f(a, $(ref t => t.block_comment("=# unused")) b));
+    /// assert_eq!("f(a, #= = # unused =# b)", tokens.to_string()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn block_comment<T>(&mut self, text: T)
+    where
+        T: Into<ItemStr>,
+    {
+        self.item(Item::Comment(CommentKind::Block, text.into()));
+    }
+
+    /// Iterate over the symbols declared in the token stream with
+    /// [declare][Self::declare], in the order they were declared.
+    pub fn declarations(&self) -> Declarations<'_, L> {
+        Declarations {
+            iter: self.items.iter(),
+        }
+    }
+
+    /// Check if tokens contain no items.
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let tokens: Tokens<()> = quote!();
+    ///
+    /// assert!(tokens.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Compute statistics over the items in the token stream, like how many
+    /// bytes of strings have been allocated for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let map = rust::import("std::collections", "HashMap");
+    /// let name = String::from("names");
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     fn new() {
+    ///         let $name = $map::new();
+    ///     }
+    /// };
+    ///
+    /// let stats = tokens.stats();
+    ///
+    /// assert_eq!(1, stats.lang_items());
+    /// assert_eq!("fnnew(){let=::new();}".len(), stats.static_bytes());
+    /// assert_eq!("names".len(), stats.boxed_bytes());
+    /// assert_eq!(1, stats.depth());
+    /// ```
+    pub fn stats(&self) -> TokenStats {
+        TokenStats::new(&self.items, self.items.capacity())
+    }
+
+    /// Shrink the capacity of the token stream as much as possible.
+    ///
+    /// Language items are stored alongside all other items, so this also
+    /// releases any excess space which was reserved for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let mut tokens = rust::Tokens::with_capacity(100);
+    /// quote_in!(tokens => let a = $(rust::import("std::rc", "Rc"))::new(););
+    ///
+    /// assert!(tokens.stats().capacity() >= 100);
+    /// tokens.shrink_to_fit();
+    /// assert_eq!(tokens.stats().items(), tokens.stats().capacity());
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.items.shrink_to_fit();
+    }
+
+    /// Get the first item in the token stream.
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::tokens::{Item, ItemStr};
+    ///
+    /// let tokens: Tokens<()> = quote!(foo bar);
+    ///
+    /// assert_eq!(Some(&Item::Literal(ItemStr::Static("foo"))), tokens.first());
+    /// ```
+    pub fn first(&self) -> Option<&Item<L>> {
+        self.items.first()
+    }
+
+    /// Get the last item in the token stream.
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::tokens::Item;
+    ///
+    /// let tokens: Tokens<()> = quote!(foo bar$['\r']);
+    ///
+    /// assert_eq!(Some(&Item::Push), tokens.last());
+    /// ```
+    pub fn last(&self) -> Option<&Item<L>> {
+        self.items.last()
+    }
+
+    /// Test if the token stream ends with whitespace, like a space, a push, a
+    /// line, or a change in indentation.
+    ///
+    /// Items which are not rendered, like the ones added through
+    /// [register][Self::register], are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let a: rust::Tokens = quote!(let a = 1;);
+    /// let b: rust::Tokens = quote!(let b = 2;$['\n']);
+    ///
+    /// let mut tokens = rust::Tokens::new();
+    ///
+    /// for fragment in [a, b] {
+    ///     tokens.append(fragment);
+    ///
+    ///     if !tokens.ends_with_whitespace() {
+    ///         tokens.push();
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "let a = 1;",
+    ///         "let b = 2;",
+    ///     ],
+    ///     tokens.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn ends_with_whitespace(&self) -> bool {
+        matches!(
+            self.rendered().next_back(),
+            Some(Item::Space | Item::SoftSpace | Item::Push | Item::Line | Item::Indentation(..))
+        )
+    }
+
+    /// Test if the first rendered item in the token stream is a literal which
+    /// starts with the given string.
+    ///
+    /// Items which are not rendered, like the ones added through
+    /// [register][Self::register], are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let tokens: rust::Tokens = quote!(.map(f));
+    ///
+    /// assert!(tokens.starts_with_literal("."));
+    /// assert!(!tokens.starts_with_literal("map"));
+    /// ```
+    pub fn starts_with_literal(&self, s: &str) -> bool {
+        matches!(self.rendered().next(), Some(Item::Literal(literal)) if literal.starts_with(s))
+    }
+
+    /// Test if the last rendered item in the token stream is a literal which
+    /// ends with the given string.
+    ///
+    /// Items which are not rendered, like the ones added through
+    /// [register][Self::register], are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let tokens: rust::Tokens = quote!(let a = 1;);
+    ///
+    /// assert!(tokens.ends_with_literal(";"));
+    /// assert!(!tokens.ends_with_literal(","));
+    /// ```
+    pub fn ends_with_literal(&self, s: &str) -> bool {
+        matches!(self.rendered().next_back(), Some(Item::Literal(literal)) if literal.ends_with(s))
+    }
+
+    /// Iterate over the items which are rendered.
+    fn rendered(&self) -> impl DoubleEndedIterator<Item = &Item<L>> {
+        self.items.iter().filter(|item| {
+            !matches!(
+                item,
+                Item::Register(..)
+                    | Item::Declare(..)
+                    | Item::Origin(..)
+                    | Item::Anchor(..)
+                    | Item::ImportsHere
+            )
+        })
+    }
+
+    /// Construct a new token stream where every placeholder created through
+    /// [param()] is replaced with the fragment bound to its name.
+    ///
+    /// Placeholders can be used any number of times, and fragments which are
+    /// not used are ignored. An error is returned if a placeholder has no
+    /// fragment bound to it.
+    ///
+    /// [param()]: crate::tokens::param()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::tokens::param;
+    ///
+    /// let map = &rust::import("std::collections", "HashMap");
+    ///
+    /// let template: rust::Tokens = quote! {
+    ///     let $(param("name")): $map<$(param("key")), u32> = $map::new();
+    /// };
+    ///
+    /// let tokens = template.bind([
+    ///     ("name", quote!(counts)),
+    ///     ("key", quote!(&'static str)),
+    /// ])?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use std::collections::HashMap;",
+    ///         "",
+    ///         "let counts: HashMap<&'static str, u32> = HashMap::new();",
+    ///     ],
+    ///     tokens.to_file_vec()?
+    /// );
+    ///
+    /// let error = template.bind([("name", quote!(counts))]).unwrap_err();
+    /// assert_eq!("key", error.name());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bind<I, N, T>(&self, params: I) -> Result<Tokens<L>, UnboundParam>
+    where
+        I: IntoIterator<Item = (N, T)>,
+        N: Into<ItemStr>,
+        T: FormatInto<L>,
+    {
+        let params = params
+            .into_iter()
+            .map(|(name, value)| {
+                let mut tokens = Tokens::new();
+                value.format_into(&mut tokens);
+                (name.into(), tokens)
+            })
+            .collect::<BTreeMap<ItemStr, Tokens<L>>>();
+
+        let mut out = Tokens::with_capacity(self.items.len());
+
+        for item in &self.items {
+            match item {
+                Item::Param(name) => match params.get(name) {
+                    Some(value) => out.append(value),
+                    None => return Err(UnboundParam::new(name.clone())),
+                },
+                item => out.item(item.clone()),
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Replace every literal item which is exactly equal to `from` with `to`.
+    ///
+    /// This operates on the token level, so only whole literals are matched.
+    /// Language items and the content of quoted strings are never touched,
+    /// but literals inside of an evaluated part of a quoted string are.
+    ///
+    /// Returns the number of literals which were replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let map = &rust::import("std::collections", "HashMap");
+    ///
+    /// let mut tokens: rust::Tokens = quote! {
+    ///     let foo: $map<u32, u32> = $map::new();
+    ///     println!("foo");
+    /// };
+    ///
+    /// assert_eq!(1, tokens.replace_literal("foo:", "bar:"));
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "use std::collections::HashMap;",
+    ///         "",
+    ///         "let bar: HashMap<u32, u32> = HashMap::new();",
+    ///         "println!(\"foo\");",
+    ///     ],
+    ///     tokens.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn replace_literal<F, T>(&mut self, from: F, to: T) -> usize
     where
-        T: FormatInto<L>,
+        F: AsRef<str>,
+        T: Into<ItemStr>,
     {
-        tokens.format_into(self)
+        let from = from.as_ref();
+        let to = to.into();
+
+        self.replace_literals_with(|literal| {
+            if literal == from {
+                Some(to.clone())
+            } else {
+                None
+            }
+        })
     }
 
-    /// Extend with another stream of tokens.
+    /// Replace all matches of `regex` inside of literal items with the given
+    /// replacement.
     ///
-    /// This respects the structural requirements of adding one element at a
-    /// time, like you would get by calling [`space`], [`push`], or [`line`].
+    /// Like [replace_literal()][Self::replace_literal], this never touches
+    /// language items or the content of quoted strings.
+    ///
+    /// Returns the number of literals which were modified.
     ///
     /// # Examples
     ///
     /// ```
     /// use genco::prelude::*;
-    /// use genco::tokens::{Item, ItemStr};
+    /// use regex::Regex;
     ///
-    /// let mut tokens: Tokens<()> = quote!(foo bar);
-    /// tokens.extend::<Tokens<()>>(quote!($[' ']baz));
+    /// let mut tokens: rust::Tokens = quote! {
+    ///     let foo = foo_bar(foo.len());
+    ///     println!("foo");
+    /// };
     ///
-    /// assert_eq!(tokens, quote!(foo bar baz));
-    /// ```
+    /// let regex = Regex::new(r"\bfoo\b").unwrap();
+    /// assert_eq!(2, tokens.replace_literal_regex(&regex, "baz"));
     ///
-    /// [`space`]: Self::space
-    /// [`push`]: Self::push
-    /// [`line`]: Self::line
-    pub fn extend<I>(&mut self, it: I)
+    /// assert_eq!(
+    ///     vec![
+    ///         "let baz = foo_bar(baz.len());",
+    ///         "println!(\"foo\");",
+    ///     ],
+    ///     tokens.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn replace_literal_regex<R>(&mut self, regex: &regex::Regex, mut replacement: R) -> usize
     where
-        I: IntoIterator<Item = Item<L>>,
+        R: regex::Replacer,
     {
-        let it = it.into_iter();
-        let (low, high) = it.size_hint();
-        self.items.reserve(high.unwrap_or(low));
+        self.replace_literals_with(|literal| {
+            if !regex.is_match(literal) {
+                return None;
+            }
 
-        for item in it {
-            self.item(item);
-        }
+            let replaced = regex.replace_all(literal, replacement.by_ref());
+            Some(ItemStr::from(replaced))
+        })
     }
 
-    /// Walk over all imports.
+    /// Retain only the items for which the given predicate returns `true`.
     ///
-    /// The order in which the imports are returned is *not* defined. So if you
-    /// need them in some particular order you need to sort them.
+    /// The token stream is rebuilt from the retained items, so the structural
+    /// guarantees of whitespace still hold after items have been removed. Like
+    /// no two spaces or line breaks in a row.
     ///
     /// # Examples
     ///
     /// ```
     /// use genco::prelude::*;
+    /// use genco::tokens::Item;
     ///
-    /// let debug = rust::import("std::fmt", "Debug");
-    /// let ty = rust::import("std::collections", "HashMap");
+    /// let mut tokens = rust::Tokens::new();
+    /// tokens.append("// The first value.");
+    /// tokens.push();
+    /// quote_in!(tokens => let a = 1;);
+    /// tokens.line();
+    /// tokens.append("// The second value.");
+    /// tokens.push();
+    /// quote_in!(tokens => let b = 2;);
     ///
-    /// let tokens = quote!(foo $ty<u32, dyn $debug> baz);
+    /// tokens.retain(|item| !matches!(item, Item::Literal(literal) if literal.starts_with("//")));
     ///
-    /// for import in tokens.walk_imports() {
-    ///     println!("{:?}", import);
-    /// }
+    /// assert_eq!(
+    ///     vec![
+    ///         "let a = 1;",
+    ///         "",
+    ///         "let b = 2;",
+    ///     ],
+    ///     tokens.to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
     /// ```
-    pub fn walk_imports(&self) -> WalkImports<'_, L> {
-        WalkImports {
-            items: &self.items,
-            pos: self.last_lang_item,
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&Item<L>) -> bool,
+    {
+        let items = mem::take(&mut self.items);
+        self.last_lang_item = 0;
+
+        for item in items {
+            if f(&item) {
+                self.item(item);
+            }
         }
     }
 
-    /// Add an registered custom element that is _not_ rendered.
+    /// Replace every item with the item returned by the given function.
     ///
-    /// Registration can be used to generate imports that do not render a
-    /// visible result.
+    /// The token stream is rebuilt from the returned items, so the structural
+    /// guarantees of whitespace still hold.
     ///
     /// # Examples
     ///
     /// ```
     /// use genco::prelude::*;
+    /// use genco::tokens::{Item, ItemStr};
     ///
-    /// let write_bytes_ext = rust::import("byteorder", "WriteBytesExt").with_alias("_");
+    /// let mut tokens: rust::Tokens = quote! {
+    ///     fn add(a: i32, b: i32) -> i32 {
+    ///         a + b
+    ///     }
+    /// };
     ///
-    /// let tokens = quote!($(register(write_bytes_ext)));
+    /// tokens.map_items(|item| match item {
+    ///     Item::Literal(literal) => Item::Literal(ItemStr::from(literal.replace("i32", "i64"))),
+    ///     item => item,
+    /// });
     ///
-    /// assert_eq!("use byteorder::WriteBytesExt as _;\n", tokens.to_file_string()?);
+    /// assert_eq!(
+    ///     vec![
+    ///         "fn add(a: i64, b: i64) -> i64 {",
+    ///         "    a + b",
+    ///         "}",
+    ///     ],
+    ///     tokens.to_file_vec()?
+    /// );
     /// # Ok::<_, genco::fmt::Error>(())
     /// ```
-    ///
-    /// [quote!]: macro.quote.html
-    pub fn register<T>(&mut self, tokens: T)
+    pub fn map_items<F>(&mut self, mut f: F)
     where
-        T: Register<L>,
+        F: FnMut(Item<L>) -> Item<L>,
     {
-        tokens.register(self);
+        let items = mem::take(&mut self.items);
+        self.last_lang_item = 0;
+
+        for item in items {
+            self.item(f(item));
+        }
     }
 
-    /// Check if tokens contain no items.
-    ///
-    /// ```
-    /// use genco::prelude::*;
-    ///
-    /// let tokens: Tokens<()> = quote!();
-    ///
-    /// assert!(tokens.is_empty());
-    /// ```
-    pub fn is_empty(&self) -> bool {
-        self.items.is_empty()
+    /// Apply the given replacement function to all literals which are not part
+    /// of a quoted string.
+    fn replace_literals_with<F>(&mut self, mut f: F) -> usize
+    where
+        F: FnMut(&str) -> Option<ItemStr>,
+    {
+        // Tracks whether we are currently inside of a quoted string. Evaluated
+        // sections inside of quotes are treated as code again.
+        let mut in_quote = smallvec::SmallVec::<[bool; 4]>::new();
+        let mut count = 0;
+
+        for item in &mut self.items {
+            match item {
+                Item::OpenQuote(..) => in_quote.push(true),
+                Item::OpenEval => in_quote.push(false),
+                Item::CloseQuote | Item::CloseEval => {
+                    in_quote.pop();
+                }
+                Item::Literal(literal) if !in_quote.last().copied().unwrap_or_default() => {
+                    if let Some(replaced) = f(literal) {
+                        *literal = replaced;
+                        count += 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        count
     }
 
     /// Add a single spacing to the token stream.
@@ -307,13 +1261,73 @@ where
     /// # Ok::<_, genco::fmt::Error>(())
     /// ```
     pub fn space(&mut self) {
-        if let Some(Item::Space) = self.items.last() {
-            return;
+        match self.items.last_mut() {
+            Some(Item::Space) => return,
+            Some(last @ Item::SoftSpace) => {
+                *last = Item::Space;
+                return;
+            }
+            _ => {}
         }
 
         self.items.push(Item::Space);
     }
 
+    /// Add a collapsing space to the token stream.
+    ///
+    /// Unlike a [space][Self::space], a collapsing space is never written at
+    /// the start or the end of a line, and is absorbed by any other space next
+    /// to it. This makes it suitable for joining fragments which might be
+    /// empty, like optional modifiers.
+    ///
+    /// This is also available through the `$[~]` escape sequence in
+    /// [quote!][crate::quote].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// fn function(public: bool, asyncness: bool) -> rust::Tokens {
+    ///     let vis = public.then(|| quote!(pub));
+    ///     let asyncness = asyncness.then(|| quote!(async));
+    ///
+    ///     quote!($vis$[~]$asyncness$[~]fn run() {})
+    /// }
+    ///
+    /// assert_eq!("pub async fn run() {}", function(true, true).to_string()?);
+    /// assert_eq!("pub fn run() {}", function(true, false).to_string()?);
+    /// assert_eq!("async fn run() {}", function(false, true).to_string()?);
+    /// assert_eq!("fn run() {}", function(false, false).to_string()?);
+    ///
+    /// let mut tokens = rust::Tokens::new();
+    /// tokens.soft_space();
+    /// tokens.append("a");
+    /// tokens.soft_space();
+    /// tokens.space();
+    /// tokens.soft_space();
+    /// tokens.append("b");
+    /// tokens.soft_space();
+    /// tokens.push();
+    /// tokens.soft_space();
+    /// tokens.append("c");
+    ///
+    /// assert_eq!(vec!["a b", "c"], tokens.to_file_vec()?);
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn soft_space(&mut self) {
+        if matches!(
+            self.items.last(),
+            None | Some(
+                Item::Space | Item::SoftSpace | Item::Push | Item::Line | Item::Indentation(..)
+            )
+        ) {
+            return;
+        }
+
+        self.items.push(Item::SoftSpace);
+    }
+
     /// Add a single push operation.
     ///
     /// Push operations ensure that any following tokens are added to their own
@@ -352,7 +1366,7 @@ where
                     self.items.push(Item::Line);
                     return;
                 }
-                Some(Item::Space | Item::Push) => continue,
+                Some(Item::Space | Item::SoftSpace | Item::Push) => continue,
                 item => break item,
             }
         };
@@ -509,7 +1523,7 @@ where
     ///
     /// * [fmt::VecWriter] - To write result into a vector.
     /// * [fmt::FmtWriter] - To write the result into something implementing
-    ///   [fmt::Write][std::fmt::Write].
+    ///   [fmt::Write][core::fmt::Write].
     /// * [fmt::IoWriter]- To write the result into something implementing
     ///   [io::Write][std::io::Write].
     ///
@@ -574,6 +1588,7 @@ where
             Item::Push => self.push(),
             Item::Line => self.line(),
             Item::Space => self.space(),
+            Item::SoftSpace => self.soft_space(),
             Item::Indentation(n) => self.indentation(n),
             Item::Lang(_, item) => self.lang_item(item),
             Item::Register(_, item) => self.lang_item_register(item),
@@ -607,7 +1622,7 @@ where
     ///
     /// * [fmt::VecWriter] - To write result into a vector.
     /// * [fmt::FmtWriter] - To write the result into something implementing
-    ///   [fmt::Write][std::fmt::Write].
+    ///   [fmt::Write][core::fmt::Write].
     /// * [fmt::IoWriter]- To write the result into something implementing
     ///   [io::Write][std::io::Write].
     ///
@@ -636,18 +1651,129 @@ where
     /// # Ok::<_, genco::fmt::Error>(())
     /// ```
     pub fn format_file(&self, out: &mut fmt::Formatter<'_>, config: &L::Config) -> fmt::Result {
-        L::format_file(self, out, config)?;
+        trace!(items = self.items.len(), "format file");
+        out.write_prologue()?;
+
+        match self
+            .items
+            .iter()
+            .position(|item| matches!(item, Item::ImportsHere))
+        {
+            Some(n) => {
+                let mut head = Tokens::with_capacity(n);
+                let mut tail = Tokens::with_capacity(self.items.len() - n);
+
+                for item in &self.items[..n] {
+                    // NB: Language items in the head still need to be
+                    // imported.
+                    if let Item::Lang(_, lang) = item {
+                        tail.lang_item_register(lang.clone());
+                    }
+
+                    head.item(item.clone());
+                }
+
+                for item in &self.items[n + 1..] {
+                    tail.item(item.clone());
+                }
+
+                head.format(out, config, &L::Format::default())?;
+                L::format_file(&tail, out, config)?;
+            }
+            None => {
+                L::format_file(self, out, config)?;
+            }
+        }
+
         out.write_trailing_line()?;
         Ok(())
     }
 
+    /// Format the token stream as a file like [format_file][Self::format_file],
+    /// and report what was written.
+    ///
+    /// This is useful for tooling which records the dependencies between
+    /// generated files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let map = rust::import("std::collections", "HashMap");
+    /// let debug = rust::import("std::fmt", "Debug");
+    ///
+    /// let tokens: rust::Tokens = quote! {
+    ///     fn new<T: $(&debug)>() -> $(&map)<u32, T> {
+    ///         $(&map)::new()
+    ///     }
+    /// };
+    ///
+    /// let mut w = fmt::VecWriter::new();
+    /// let fmt = fmt::Config::from_lang::<Rust>();
+    /// let report = tokens.format_file_with_report(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    ///
+    /// assert_eq!(&[map, debug], report.imports());
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn format_file_with_report(
+        &self,
+        out: &mut fmt::Formatter<'_>,
+        config: &L::Config,
+    ) -> fmt::Result<fmt::FormatReport<L>> {
+        // NB: Only report warnings from this file.
+        out.take_warnings();
+        out.record_imports();
+
+        let result = self.format_file(out, config);
+        let mut imports = out.take_imports::<L>();
+        result?;
+
+        imports.sort();
+        imports.dedup();
+        Ok(fmt::FormatReport::new(imports, out.take_warnings()))
+    }
+
+    /// Use the given language configuration with the shorthand formatting
+    /// methods, like [to_file_string][WithConfig::to_file_string].
+    ///
+    /// This avoids having to set up a writer and formatter to use things like
+    /// a package or namespace which is configured for the language.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let optional = java::import("java.util", "Optional");
+    ///
+    /// let tokens: java::Tokens = quote!($optional<String> name;);
+    /// let config = java::Config::default().with_package("com.example");
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "package com.example;",
+    ///         "",
+    ///         "import java.util.Optional;",
+    ///         "",
+    ///         "Optional<String> name;",
+    ///     ],
+    ///     tokens.with_config(config).to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_config(&self, config: L::Config) -> WithConfig<'_, L> {
+        WithConfig::new(self, config)
+    }
+
     /// Internal function to modify the indentation of the token stream.
     fn indentation(&mut self, mut n: i16) {
         let item = loop {
             // flush all whitespace preceeding the indentation change.
             match self.items.pop() {
                 Some(Item::Push) => continue,
-                Some(Item::Space) => continue,
+                Some(Item::Space | Item::SoftSpace) => continue,
                 Some(Item::Line) => continue,
                 Some(Item::Indentation(u)) => n += u,
                 item => break item,
@@ -824,6 +1950,42 @@ where
         Ok(w.into_vec())
     }
 
+    /// Format the token stream as a file for the given target language into
+    /// the given vector of lines, reusing the allocations of the lines it
+    /// already contains.
+    ///
+    /// This is the same as [to_file_vec][Self::to_file_vec], but avoids
+    /// allocating a string for each line when a vector is reused across runs.
+    /// The previous content of the vector is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    ///
+    /// let mut lines = Vec::new();
+    ///
+    /// for n in 0..3u32 {
+    ///     let tokens: rust::Tokens = quote! {
+    ///         fn value() -> u32 {
+    ///             $n
+    ///         }
+    ///     };
+    ///
+    ///     tokens.to_file_vec_into(&mut lines)?;
+    ///     assert_eq!(tokens.to_file_vec()?, lines);
+    /// }
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn to_file_vec_into(&self, lines: &mut Vec<String>) -> fmt::Result {
+        let mut w = fmt::VecWriter::from_vec(mem::take(lines));
+        let fmt = fmt::Config::from_lang::<L>();
+        let config = L::Config::default();
+        let result = self.format_file(&mut w.as_formatter(&fmt), &config);
+        *lines = w.into_vec();
+        result
+    }
+
     /// Helper function to format tokens into a vector, where each entry equals
     /// a line using the default configuration.
     ///
@@ -1021,6 +2183,60 @@ where
     }
 }
 
+/// An iterator over the symbols declared in a token stream.
+///
+/// Constructed using the [Tokens::declarations] method.
+pub struct Declarations<'a, L>
+where
+    L: Lang,
+{
+    iter: slice::Iter<'a, Item<L>>,
+}
+
+impl<'a, L> Iterator for Declarations<'a, L>
+where
+    L: Lang,
+{
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in self.iter.by_ref() {
+            if let Item::Declare(name) = item {
+                return Some(name);
+            }
+        }
+
+        None
+    }
+}
+
+/// An iterator over the registered language items in a token stream.
+///
+/// Constructed using the [Tokens::iter_registered] method.
+pub struct IterRegistered<'a, L>
+where
+    L: Lang,
+{
+    iter: slice::Iter<'a, Item<L>>,
+}
+
+impl<'a, L> Iterator for IterRegistered<'a, L>
+where
+    L: Lang,
+{
+    type Item = &'a L::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in self.iter.by_ref() {
+            if let Item::Register(_, item) = item {
+                return Some(item);
+            }
+        }
+
+        None
+    }
+}
+
 /// An iterator over language-specific imported items.
 ///
 /// Constructed using the [Tokens::walk_imports] method.
@@ -1030,6 +2246,8 @@ where
 {
     items: &'a [Item<L>],
     pos: usize,
+    /// Dependencies of already visited items which are yet to be visited.
+    dependencies: Vec<&'a [L::Item]>,
 }
 
 impl<'a, L> Iterator for WalkImports<'a, L>
@@ -1039,6 +2257,20 @@ where
     type Item = &'a L::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
+        while let Some(dependencies) = self.dependencies.last_mut() {
+            let (item, rest) = match dependencies.split_first() {
+                Some(split) => split,
+                None => {
+                    self.dependencies.pop();
+                    continue;
+                }
+            };
+
+            *dependencies = rest;
+            self.visit(item);
+            return Some(item);
+        }
+
         let pos = mem::take(&mut self.pos);
 
         if pos == 0 {
@@ -1055,10 +2287,27 @@ where
         };
 
         self.pos = *prev;
+        self.visit(item);
         Some(item)
     }
 }
 
+impl<'a, L> WalkImports<'a, L>
+where
+    L: Lang,
+{
+    /// Queue up the dependencies of a visited item.
+    fn visit(&mut self, item: &'a L::Item) {
+        use crate::lang::LangItem as _;
+
+        let dependencies = item.dependencies();
+
+        if !dependencies.is_empty() {
+            self.dependencies.push(dependencies);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::fmt::Write as _;
@@ -1105,4 +2354,52 @@ mod tests {
 
         assert_eq!(expected, output);
     }
+
+    mod dependencies {
+        use core::fmt::Write as _;
+
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        use crate as genco;
+        use crate::fmt;
+        use crate::{quote, Tokens};
+
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        struct Import(u32, Vec<Import>);
+
+        impl_lang! {
+            Lang {
+                type Config = ();
+                type Format = ();
+                type Item = Import;
+            }
+
+            Import {
+                fn format(&self, out: &mut fmt::Formatter<'_>, _: &(), _: &()) -> fmt::Result {
+                    write!(out, "{}", self.0)
+                }
+
+                fn dependencies(&self) -> &[Import] {
+                    &self.1
+                }
+            }
+        }
+
+        #[test]
+        fn test_walk_dependencies() {
+            let a = Import(
+                1,
+                vec![Import(2, vec![Import(3, vec![])]), Import(4, vec![])],
+            );
+            let b = Import(5, vec![]);
+
+            let toks: Tokens<Lang> = quote!($a $b);
+
+            let mut output: Vec<_> = toks.walk_imports().map(|i| i.0).collect();
+            output.sort();
+
+            assert_eq!(vec![1, 2, 3, 4, 5], output);
+        }
+    }
 }
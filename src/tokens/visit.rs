@@ -0,0 +1,158 @@
+//! Walking the logical structure of a token stream.
+//!
+//! Implement [Visitor] and pass it to [Tokens::accept] to be notified of the
+//! items in a token stream along with the structure they're in, like whether
+//! they're part of a quoted string and what indentation they're at.
+//!
+//! # Examples
+//!
+//! Finding literals which are written at a given indentation outside of
+//! strings:
+//!
+//! ```
+//! use genco::prelude::*;
+//! use genco::tokens::visit::Visitor;
+//!
+//! #[derive(Default)]
+//! struct Lint {
+//!     quotes: usize,
+//!     indentation: i16,
+//!     nested: Vec<String>,
+//!     strings: Vec<String>,
+//!     imports: Vec<rust::Import>,
+//! }
+//!
+//! impl Visitor<Rust> for Lint {
+//!     fn enter_quote(&mut self, _: bool) {
+//!         self.quotes += 1;
+//!     }
+//!
+//!     fn exit_quote(&mut self) {
+//!         self.quotes -= 1;
+//!     }
+//!
+//!     fn indentation(&mut self, level: i16) {
+//!         self.indentation = level;
+//!     }
+//!
+//!     fn literal(&mut self, literal: &str) {
+//!         if self.quotes > 0 {
+//!             self.strings.push(literal.to_string());
+//!         } else if self.indentation > 0 {
+//!             self.nested.push(literal.to_string());
+//!         }
+//!     }
+//!
+//!     fn lang(&mut self, item: &rust::Import) {
+//!         self.imports.push(item.clone());
+//!     }
+//! }
+//!
+//! let map = rust::import("std::collections", "HashMap");
+//!
+//! let tokens: rust::Tokens = quote! {
+//!     fn main() {
+//!         let m = $(map.clone())::new();
+//!         println!("hello");
+//!     }
+//! };
+//!
+//! let mut lint = Lint::default();
+//! tokens.accept(&mut lint);
+//!
+//! assert_eq!(vec!["let", "m", "=", "::new();", "println!(", ");"], lint.nested);
+//! assert_eq!(vec!["hello"], lint.strings);
+//! assert_eq!(vec![map], lint.imports);
+//! assert_eq!(0, lint.indentation);
+//! ```
+
+use crate::lang::Lang;
+use crate::tokens::{CommentKind, IdentKind, Item};
+use crate::Tokens;
+
+/// A visitor over the items in a token stream.
+///
+/// Every method has a default implementation which does nothing, so only the
+/// methods of interest need to be implemented.
+///
+/// See the [module level documentation][self] for an example.
+#[allow(unused_variables)]
+pub trait Visitor<L>
+where
+    L: Lang,
+{
+    /// A literal, which is part of a string if it's between
+    /// [enter_quote][Self::enter_quote] and [exit_quote][Self::exit_quote].
+    fn literal(&mut self, literal: &str) {}
+
+    /// An identifier of the given kind, before any naming convention has been
+    /// applied to it.
+    fn ident(&mut self, kind: IdentKind, ident: &str) {}
+
+    /// A language item which is rendered.
+    ///
+    /// The dependencies of the item are available through
+    /// [LangItem::dependencies][crate::lang::LangItem::dependencies].
+    fn lang(&mut self, item: &L::Item) {}
+
+    /// A language item which is registered, but not rendered.
+    fn register(&mut self, item: &L::Item) {}
+
+    /// A comment of the given kind.
+    fn comment(&mut self, kind: CommentKind, text: &str) {}
+
+    /// A quoted string is opened, where `has_eval` indicates if it contains
+    /// any interpolated values.
+    fn enter_quote(&mut self, has_eval: bool) {}
+
+    /// The most recently opened quoted string is closed.
+    fn exit_quote(&mut self) {}
+
+    /// An interpolated value in a quoted string is opened.
+    fn enter_eval(&mut self) {}
+
+    /// The most recently opened interpolated value is closed.
+    fn exit_eval(&mut self) {}
+
+    /// The indentation changed to the given level, relative to the start of
+    /// the token stream.
+    fn indentation(&mut self, level: i16) {}
+
+    /// Any other item, like whitespace or groups.
+    fn item(&mut self, item: &Item<L>) {}
+}
+
+impl<L> Tokens<L>
+where
+    L: Lang,
+{
+    /// Walk the token stream with the given [Visitor].
+    ///
+    /// See the [visit module][crate::tokens::visit] for an example.
+    pub fn accept<V>(&self, visitor: &mut V)
+    where
+        V: ?Sized + Visitor<L>,
+    {
+        let mut level = 0i16;
+
+        for item in self {
+            match item {
+                Item::Literal(literal) => visitor.literal(literal),
+                Item::Ident(kind, ident) => visitor.ident(*kind, ident),
+                Item::Lang(_, item) => visitor.lang(item),
+                Item::Register(_, item) => visitor.register(item),
+                Item::Comment(kind, text) => visitor.comment(*kind, text),
+                Item::OpenQuote(has_eval) => visitor.enter_quote(*has_eval),
+                Item::CloseQuote => visitor.exit_quote(),
+                Item::OpenEval => visitor.enter_eval(),
+                Item::CloseEval => visitor.exit_eval(),
+                Item::Indentation(0) => (),
+                Item::Indentation(n) => {
+                    level += *n;
+                    visitor.indentation(level);
+                }
+                item => visitor.item(item),
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::fmt;
+use crate::lang::Lang;
+use crate::Tokens;
+
+/// A token stream paired with the configuration to use when formatting it.
+///
+/// This struct is created by [Tokens::with_config], and provides the same
+/// shorthand formatting methods as [Tokens], but using the given
+/// configuration instead of the default one.
+pub struct WithConfig<'a, L>
+where
+    L: Lang,
+{
+    tokens: &'a Tokens<L>,
+    config: L::Config,
+    fmt: fmt::Config,
+}
+
+impl<'a, L> WithConfig<'a, L>
+where
+    L: Lang,
+{
+    pub(super) fn new(tokens: &'a Tokens<L>, config: L::Config) -> Self {
+        Self {
+            tokens,
+            config,
+            fmt: fmt::Config::from_lang::<L>(),
+        }
+    }
+
+    /// Use the given formatter configuration, instead of the default one for
+    /// the language.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genco::prelude::*;
+    /// use genco::fmt;
+    ///
+    /// let tokens: csharp::Tokens = quote! {
+    ///     class Foo {
+    ///         int bar;
+    ///     }
+    /// };
+    ///
+    /// let config = csharp::Config::default().with_namespace("Example");
+    /// let fmt = fmt::Config::from_lang::<Csharp>().with_indentation(fmt::Indentation::Space(2));
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "namespace Example {",
+    ///         "  class Foo {",
+    ///         "    int bar;",
+    ///         "  }",
+    ///         "}",
+    ///     ],
+    ///     tokens.with_config(config).with_fmt(fmt).to_file_vec()?
+    /// );
+    /// # Ok::<_, genco::fmt::Error>(())
+    /// ```
+    pub fn with_fmt(self, fmt: fmt::Config) -> Self {
+        Self { fmt, ..self }
+    }
+
+    /// Format the token stream as a file to a string.
+    ///
+    /// See [Tokens::to_file_string].
+    pub fn to_file_string(&self) -> fmt::Result<String> {
+        let mut w = fmt::FmtWriter::new(String::new());
+        self.tokens
+            .format_file(&mut w.as_formatter(&self.fmt), &self.config)?;
+        Ok(w.into_inner())
+    }
+
+    /// Format only the token stream to a string, without imports.
+    ///
+    /// See [Tokens::to_string].
+    pub fn to_string(&self) -> fmt::Result<String> {
+        let mut w = fmt::FmtWriter::new(String::new());
+        let format = L::Format::default();
+        self.tokens
+            .format(&mut w.as_formatter(&self.fmt), &self.config, &format)?;
+        Ok(w.into_inner())
+    }
+
+    /// Format the token stream as a file into a vector of lines.
+    ///
+    /// See [Tokens::to_file_vec].
+    pub fn to_file_vec(&self) -> fmt::Result<Vec<String>> {
+        let mut w = fmt::VecWriter::new();
+        self.tokens
+            .format_file(&mut w.as_formatter(&self.fmt), &self.config)?;
+        Ok(w.into_vec())
+    }
+
+    /// Format only the token stream into a vector of lines, without imports.
+    ///
+    /// See [Tokens::to_vec].
+    pub fn to_vec(&self) -> fmt::Result<Vec<String>> {
+        let mut w = fmt::VecWriter::new();
+        let format = L::Format::default();
+        self.tokens
+            .format(&mut w.as_formatter(&self.fmt), &self.config, &format)?;
+        Ok(w.into_vec())
+    }
+}
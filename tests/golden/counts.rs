@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+
+pub fn counts(words: &[&str]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+
+    for word in words {
+        *counts.entry(word.to_string()).or_default() += 1;
+    }
+
+    counts
+}
@@ -0,0 +1,32 @@
+use genco::prelude::*;
+
+#[test]
+fn test_insert_at_links_imports() -> genco::fmt::Result {
+    let map = rust::import("std::collections", "HashMap");
+    let set = rust::import("std::collections", "HashSet");
+    let rc = rust::import("std::rc", "Rc");
+
+    let mut tokens: rust::Tokens = quote! {
+        type A = $map<u32, u32>;
+        $(ref t => t.anchor("types"))
+        type C = $rc<u32>;
+    };
+
+    tokens
+        .insert_at("types", quote!(type B = $set<u32>;))
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            "use std::collections::{HashMap, HashSet};",
+            "use std::rc::Rc;",
+            "",
+            "type A = HashMap<u32, u32>;",
+            "type B = HashSet<u32>;",
+            "type C = Rc<u32>;",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "tokio")]
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use genco::fmt;
+use genco::prelude::*;
+use tokio::io::AsyncWrite;
+
+/// A sink which accepts at most `capacity` bytes per write, and records how
+/// much was offered to it in each write.
+struct Sink {
+    capacity: usize,
+    offered: Vec<usize>,
+    data: Vec<u8>,
+}
+
+impl AsyncWrite for Sink {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let n = buf.len().min(self.capacity);
+        self.offered.push(buf.len());
+        self.data.extend_from_slice(&buf[..n]);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn test_writes_chunks_while_formatting() -> io::Result<()> {
+    let mut tokens = rust::Tokens::new();
+
+    for n in 0..100 {
+        quote_in!(tokens => const N$n: u32 = $n;);
+        tokens.push();
+    }
+
+    let expected = tokens.to_file_string().unwrap();
+
+    let sink = Sink {
+        capacity: 16,
+        offered: Vec::new(),
+        data: Vec::new(),
+    };
+
+    let mut w = fmt::AsyncIoWriter::new(sink).with_chunk_size(64);
+
+    let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+
+    runtime.block_on(w.write_file(
+        &tokens,
+        &fmt::Config::from_lang::<Rust>(),
+        &rust::Config::default(),
+    ))?;
+
+    let sink = w.into_inner();
+    assert_eq!(expected.as_bytes(), &sink.data[..]);
+
+    // Nothing larger than a chunk and the line which filled it up was ever
+    // buffered, so the output was written before formatting finished.
+    let largest = sink.offered.iter().copied().max().unwrap();
+    assert!(largest < 64 + 32, "{} bytes were buffered", largest);
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use genco::tokens::Case;
+
+#[test]
+fn test_leading_and_trailing_underscores() {
+    assert_eq!("_private", Case::Snake.convert("_private"));
+    assert_eq!("_privateValue", Case::Camel.convert("_private_value"));
+    assert_eq!("_PrivateValue", Case::Pascal.convert("_privateValue"));
+    assert_eq!("__init__", Case::Snake.convert("__init__"));
+    assert_eq!("__Init__", Case::Pascal.convert("__init__"));
+    assert_eq!("value_", Case::Camel.convert("value_"));
+}
+
+#[test]
+fn test_only_underscores() {
+    assert_eq!("_", Case::Pascal.convert("_"));
+    assert_eq!("_", Case::Snake.convert("_"));
+    assert_eq!("__", Case::Camel.convert("__"));
+}
+
+#[test]
+fn test_raw_identifiers() {
+    assert_eq!("r#Type", Case::Pascal.convert("r#type"));
+    assert_eq!("r#type", Case::Snake.convert("r#type"));
+    assert_eq!("r#_matchArm", Case::Camel.convert("r#_match_arm"));
+}
@@ -0,0 +1,96 @@
+use genco::fmt;
+use genco::prelude::*;
+use genco::tokens::chain;
+
+fn format(tokens: &rust::Tokens, fmt: &fmt::Config) -> fmt::Result<Vec<String>> {
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(fmt), &rust::Config::default())?;
+    Ok(w.into_vec())
+}
+
+#[test]
+fn test_nested_chains() -> fmt::Result {
+    let inner = chain(quote!(items.iter()), [quote!(map(f)), quote!(sum::<u32>())]);
+
+    let tokens: rust::Tokens = quote! {
+        let value = $(chain(quote!(Builder::new()), [quote!(total($inner)), quote!(build())]));
+    };
+
+    let fmt = fmt::Config::from_lang::<Rust>();
+
+    assert_eq!(
+        vec!["let value = Builder::new().total(items.iter().map(f).sum::<u32>()).build();"],
+        format(&tokens, &fmt)?
+    );
+
+    let fmt = fmt::Config::from_lang::<Rust>().with_max_width(60);
+
+    assert_eq!(
+        vec![
+            "let value = Builder::new()",
+            "    .total(items.iter().map(f).sum::<u32>())",
+            "    .build();",
+        ],
+        format(&tokens, &fmt)?
+    );
+
+    let fmt = fmt::Config::from_lang::<Rust>().with_max_width(30);
+
+    assert_eq!(
+        vec![
+            "let value = Builder::new()",
+            "    .total(items.iter()",
+            "        .map(f)",
+            "        .sum::<u32>())",
+            "    .build();",
+        ],
+        format(&tokens, &fmt)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_chain_with_tabs() -> fmt::Result {
+    let tokens: rust::Tokens = quote! {
+        fn f() {
+            $(chain(quote!(a), [quote!(bbbbbbbb()), quote!(cccccccc())]));
+        }
+    };
+
+    let fmt = fmt::Config::from_lang::<Rust>()
+        .with_indentation(fmt::Indentation::Tab)
+        .with_max_width(20);
+
+    assert_eq!(
+        vec![
+            "fn f() {",
+            "\ta",
+            "\t\t.bbbbbbbb()",
+            "\t\t.cccccccc();",
+            "}",
+        ],
+        format(&tokens, &fmt)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_chain_with_multiline_call() -> fmt::Result {
+    let mut call = rust::Tokens::new();
+    call.append("map(|x| {");
+    call.indent();
+    call.append("x + 1");
+    call.unindent();
+    call.append("})");
+
+    let tokens: rust::Tokens = quote!($(chain(quote!(a), [call])));
+
+    assert_eq!(
+        vec!["a", "    .map(|x| {", "        x + 1", "    })"],
+        format(&tokens, &fmt::Config::from_lang::<Rust>())?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use genco::prelude::*;
+
+fn genco(args: &[&str], input: &str) -> (bool, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_genco"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to spawn genco");
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn test_render_file() -> Result<(), Box<dyn std::error::Error>> {
+    let map = rust::import("std::collections", "HashMap");
+
+    let tokens: rust::Tokens = quote! {
+        fn new() -> $(&map)<u32, u32> {
+            $(&map)::new()
+        }
+    };
+
+    let (ok, output) = genco(&["rust", "--indent", "2"], &tokens.to_ir()?);
+    assert!(ok);
+
+    assert_eq!(
+        "use std::collections::HashMap;\n\nfn new() -> HashMap<u32, u32> {\n  HashMap::new()\n}\n",
+        output
+    );
+
+    let (ok, output) = genco(&["rust", "--fragment"], &tokens.to_ir()?);
+    assert!(ok);
+    assert_eq!(
+        "fn new() -> HashMap<u32, u32> {\n    HashMap::new()\n}",
+        output
+    );
+    Ok(())
+}
+
+#[test]
+fn test_errors() {
+    assert!(!genco(&[], "").0);
+    assert!(!genco(&["cobol"], r#"{"version":1,"items":[]}"#).0);
+    assert!(!genco(&["rust"], "not ir").0);
+}
+
+#[test]
+fn test_render_template() -> Result<(), Box<dyn std::error::Error>> {
+    let context = std::env::temp_dir().join(format!("genco-context-{}.json", std::process::id()));
+    std::fs::write(&context, r#"{"name": "answer", "ty": "u32", "value": 42}"#)?;
+    let context = context.to_str().unwrap();
+
+    let template = "fn $name() -> $ty {\n    $value\n}\n";
+
+    let (ok, output) = genco(
+        &["--lang", "rust", "--template", "-", "--context", context],
+        template,
+    );
+    assert!(ok);
+    assert_eq!("fn answer() -> u32 {\n    42\n}\n", output);
+
+    // Unbound names in the template are errors.
+    assert!(!genco(&["rust", "--template", "-"], template).0);
+    assert!(!genco(&["rust", "--context", context], "").0);
+
+    std::fs::remove_file(context)?;
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use genco::prelude::*;
+use genco::tokens::with_config_override;
+
+#[test]
+fn test_nested_config_override() -> genco::fmt::Result {
+    let map = rust::import("std::collections", "HashMap");
+
+    let direct = with_config_override(
+        "direct",
+        |c: rust::Config| c.with_default_import(rust::ImportMode::Direct),
+        quote!(let c = $(&map)::new();),
+    );
+
+    let qualified = with_config_override(
+        "qualified",
+        |c: rust::Config| c.with_default_import(rust::ImportMode::Qualified),
+        quote! {
+            let b = $(&map)::new();
+            $direct
+            let d = $(&map)::new();
+        },
+    );
+
+    let tokens: rust::Tokens = quote! {
+        let a = $(&map)::new();
+        $qualified
+        let e = $(&map)::new();
+    };
+
+    assert_eq!(
+        vec![
+            "let a = HashMap::new();",
+            "let b = collections::HashMap::new();",
+            "let c = HashMap::new();",
+            "let d = collections::HashMap::new();",
+            "let e = HashMap::new();",
+        ],
+        tokens.to_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_config_override_is_ordered_by_key() {
+    let a: rust::Tokens = quote!($(with_config_override("a", |c: rust::Config| c, quote!(x))));
+    let b: rust::Tokens = quote!($(with_config_override("b", |c: rust::Config| c, quote!(x))));
+    let a2: rust::Tokens = quote!($(with_config_override("a", |c: rust::Config| c, quote!(x))));
+
+    // Separately constructed overrides with the same key are equal, and
+    // ordering doesn't depend on where they're allocated.
+    assert_eq!(a, a2);
+    assert!(a < b);
+    assert!(b > a2);
+}
@@ -0,0 +1,24 @@
+use genco::fmt;
+use genco::prelude::*;
+use genco::tokens::delimited;
+
+#[test]
+fn test_nested_delimited() -> fmt::Result {
+    let inner = || delimited("(", ")", quote!(a, b));
+
+    let toks: js::Tokens = quote! {
+        call$(delimited("(", ")", quote!(first$(inner()), second$(inner()), third$(inner()))));
+    };
+
+    let fmt = fmt::Config::from_lang::<JavaScript>().with_max_width(42);
+
+    let mut w = fmt::VecWriter::new();
+    toks.format_file(&mut w.as_formatter(&fmt), &js::Config::default())?;
+
+    assert_eq!(
+        vec!["call(", "    first(a, b), second(a, b), third(a, b)", ");",],
+        w.into_vec()
+    );
+
+    Ok(())
+}
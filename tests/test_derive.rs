@@ -0,0 +1,26 @@
+use genco::prelude::*;
+
+#[derive(FormatInto)]
+#[genco(template = { $(quoted(_0)) })]
+struct Quoted(&'static str);
+
+#[derive(FormatInto)]
+#[genco(template = { [$(for item in items join (, ) => $item)] })]
+struct List<T> {
+    items: Vec<T>,
+}
+
+#[test]
+fn test_derive_any_lang() -> genco::fmt::Result {
+    let list = || List {
+        items: vec![Quoted("a"), Quoted("b")],
+    };
+
+    let tokens: rust::Tokens = quote!(let a = $(list()););
+    assert_eq!("let a = [\"a\", \"b\"];", tokens.to_string()?);
+
+    let tokens: python::Tokens = quote!(a = $(list()));
+    assert_eq!("a = [\"a\", \"b\"]", tokens.to_string()?);
+
+    Ok(())
+}
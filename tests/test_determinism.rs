@@ -0,0 +1,136 @@
+use genco::fmt;
+use genco::prelude::*;
+use genco::tokens::ItemStr;
+
+const NAMES: &[(&str, &str)] = &[
+    ("b.c", "Zeta"),
+    ("a.b", "Alpha"),
+    ("c.d", "Beta"),
+    ("a.b", "Gamma"),
+    ("b.c", "Delta"),
+];
+
+#[test]
+fn test_imports_are_deterministic() -> fmt::Result {
+    assert!(fmt::is_deterministic(|| -> rust::Tokens {
+        let names = NAMES
+            .iter()
+            .map(|(m, n)| rust::import(m.replace('.', "::"), *n));
+        quote!($(for n in names => $n$['\r']))
+    })?);
+
+    assert!(fmt::is_deterministic(|| -> java::Tokens {
+        let names = NAMES.iter().map(|(m, n)| java::import(*m, *n));
+        quote!($(for n in names => $n$['\r']))
+    })?);
+
+    assert!(fmt::is_deterministic(|| -> csharp::Tokens {
+        let names = NAMES.iter().map(|(m, n)| csharp::import(*m, *n));
+        quote!($(for n in names => $n$['\r']))
+    })?);
+
+    assert!(fmt::is_deterministic(|| -> python::Tokens {
+        let names = NAMES.iter().map(|(m, n)| python::import(*m, *n));
+        quote!($(for n in names => $n$['\r']))
+    })?);
+
+    assert!(fmt::is_deterministic(|| -> js::Tokens {
+        let names = NAMES.iter().map(|(m, n)| js::import(*m, *n));
+        quote!($(for n in names => $n$['\r']))
+    })?);
+
+    assert!(fmt::is_deterministic(|| -> go::Tokens {
+        let names = NAMES.iter().map(|(m, n)| go::import(*m, *n));
+        quote!($(for n in names => $n$['\r']))
+    })?);
+
+    Ok(())
+}
+
+#[test]
+fn test_import_order_is_sorted() -> fmt::Result {
+    let names = NAMES.iter().map(|(m, n)| java::import(*m, *n));
+    let tokens: java::Tokens = quote!($(for n in names => $n$['\r']));
+
+    assert_eq!(
+        vec![
+            "import a.b.Alpha;",
+            "import a.b.Gamma;",
+            "import b.c.Delta;",
+            "import b.c.Zeta;",
+            "import c.d.Beta;",
+            "",
+            "Zeta",
+            "Alpha",
+            "Beta",
+            "Gamma",
+            "Delta",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_rust_conflicts_are_deterministic() -> fmt::Result {
+    let json = rust::import("serde_json", "Value");
+    let toml = rust::import("toml", "Value");
+
+    let a: rust::Tokens = quote!($(&toml) $(&json));
+    let b: rust::Tokens = quote!($(&json) $(&toml));
+
+    assert_eq!(
+        vec!["use serde_json::Value;", "", "toml::Value Value"],
+        a.to_file_vec()?
+    );
+
+    assert_eq!(
+        vec!["use serde_json::Value;", "", "Value toml::Value"],
+        b.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_non_ascii_import_order_is_byte_wise() -> fmt::Result {
+    const MODULES: &[&str] = &["ärger", "zeta", "Éclair", "alpha", "Zulu", "Ωmega", "beta"];
+
+    let mut expected = Vec::new();
+
+    // NB: Mix static and owned strings, since they should order the same.
+    let mut imports = Vec::new();
+
+    for (n, module) in MODULES.iter().enumerate() {
+        let name = format!("T{n}");
+        expected.push(format!("import {module}.{name};"));
+
+        if n % 2 == 0 {
+            imports.push(java::import(ItemStr::Static(module), name));
+        } else {
+            imports.push(java::import(module.to_string(), name));
+        }
+    }
+
+    expected.sort();
+
+    let tokens: java::Tokens = quote!($(for n in imports => $n$['\r']));
+    let lines = tokens.to_file_vec()?;
+
+    assert_eq!(
+        vec![
+            "import Zulu.T4;",
+            "import alpha.T3;",
+            "import beta.T6;",
+            "import zeta.T1;",
+            "import Éclair.T2;",
+            "import ärger.T0;",
+            "import Ωmega.T5;",
+        ],
+        expected
+    );
+
+    assert_eq!(expected, lines[..expected.len()]);
+    Ok(())
+}
@@ -0,0 +1,230 @@
+use genco::diff::{self, DiffOp};
+
+/// Reconstruct the old and the new lines from a diff.
+fn apply<'a>(ops: &[DiffOp<'a>]) -> (Vec<&'a str>, Vec<&'a str>) {
+    let mut old = Vec::new();
+    let mut new = Vec::new();
+
+    for op in ops {
+        match *op {
+            DiffOp::Equal(line) => {
+                old.push(line);
+                new.push(line);
+            }
+            DiffOp::Delete(line) => old.push(line),
+            DiffOp::Insert(line) => new.push(line),
+        }
+    }
+
+    (old, new)
+}
+
+#[test]
+fn test_empty() {
+    let empty: [&str; 0] = [];
+    assert!(diff::lines(&empty, &empty).is_empty());
+    assert_eq!("", diff::unified(&diff::lines(&empty, &empty)).to_string());
+
+    assert_eq!(
+        vec![DiffOp::Insert("a"), DiffOp::Insert("b")],
+        diff::lines(&[], &["a", "b"])
+    );
+
+    assert_eq!(
+        vec![DiffOp::Delete("a"), DiffOp::Delete("b")],
+        diff::lines(&["a", "b"], &[])
+    );
+
+    assert_eq!(
+        "@@ -0,0 +1,2 @@\n+a\n+b\n",
+        diff::unified(&diff::lines(&[], &["a", "b"])).to_string()
+    );
+}
+
+#[test]
+fn test_equal() {
+    let lines = ["a", "b", "c"];
+    let ops = diff::lines(&lines, &lines);
+    assert!(ops.iter().all(|op| !op.is_change()));
+    assert_eq!("", diff::unified(&ops).to_string());
+}
+
+#[test]
+fn test_replace() {
+    assert_eq!(
+        vec![
+            DiffOp::Delete("a"),
+            DiffOp::Delete("b"),
+            DiffOp::Insert("c"),
+            DiffOp::Insert("d"),
+        ],
+        diff::lines(&["a", "b"], &["c", "d"])
+    );
+}
+
+#[test]
+fn test_roundtrip() {
+    let old = [
+        "fn main() {",
+        "    let a = 1;",
+        "    let b = 2;",
+        "",
+        "    println!(\"{}\", a + b);",
+        "}",
+        "",
+        "fn other() {",
+        "}",
+    ];
+
+    let new = [
+        "use std::fmt;",
+        "",
+        "fn main() {",
+        "    let b = 2;",
+        "    let a = 1;",
+        "",
+        "    println!(\"{}\", a * b);",
+        "}",
+        "",
+    ];
+
+    let ops = diff::lines(&old, &new);
+    let (a, b) = apply(&ops);
+    assert_eq!(&old[..], &a[..]);
+    assert_eq!(&new[..], &b[..]);
+
+    let (a, b) = apply(&diff::lines(&new, &old));
+    assert_eq!(&new[..], &a[..]);
+    assert_eq!(&old[..], &b[..]);
+
+    // Only the lines which are not part of the longest common subsequence are
+    // changed.
+    assert_eq!(8, ops.iter().filter(|op| op.is_change()).count());
+}
+
+#[test]
+fn test_strings() {
+    let old = vec![String::from("a"), String::from("b")];
+    let new = vec![String::from("a"), String::from("c")];
+
+    assert_eq!(
+        "@@ -1,2 +1,2 @@\n a\n-b\n+c\n",
+        diff::unified(&diff::lines(&old, &new)).to_string()
+    );
+}
+
+#[test]
+fn test_tokens_lang_items() {
+    use genco::prelude::*;
+    use genco::tokens::{self, Item, ItemChange};
+
+    let map = rust::import("std::collections", "HashMap");
+    let set = rust::import("std::collections", "HashSet");
+
+    let old: rust::Tokens = quote!(let a: $(&map) = $(&map)::new(););
+    let new: rust::Tokens = quote!(let mut a: $(&map) = $(&set)::new(););
+
+    let diff = tokens::diff(&old, &new);
+
+    // Language items are compared by what they refer to, so only the changed
+    // import is reported even though the position of every language item in
+    // the stream changed.
+    let changed = diff
+        .changes()
+        .filter_map(|change| match change {
+            ItemChange::Removed {
+                item: Item::Lang(_, item),
+                ..
+            } => Some(('-', item)),
+            ItemChange::Added {
+                item: Item::Lang(_, item),
+                ..
+            } => Some(('+', item)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec![('-', &Box::new(map)), ('+', &Box::new(set))], changed);
+
+    assert_eq!(
+        vec![
+            "+[2] \"mut\"",
+            "+[3] Space",
+            " old: let a: HashMap =",
+            " new: let mut a: HashMap =",
+        ],
+        diff.to_string().lines().take(4).collect::<Vec<_>>()
+    );
+
+    assert!(tokens::diff(&new, &new.clone()).is_empty());
+}
+
+/// Length of the longest common subsequence, used as a reference for the
+/// minimality of the diff.
+fn lcs(a: &[&str], b: &[&str]) -> usize {
+    let mut row = vec![0; b.len() + 1];
+
+    for x in a {
+        let mut prev = 0;
+
+        for (j, y) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if x == y {
+                prev + 1
+            } else {
+                row[j + 1].max(row[j])
+            };
+            prev = cur;
+        }
+    }
+
+    row[b.len()]
+}
+
+#[test]
+fn test_minimal() {
+    const WORDS: [&str; 4] = ["a", "b", "c", "d"];
+
+    let mut state = 0x2545f491u32;
+
+    let mut next = move |n: usize| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as usize % n
+    };
+
+    for _ in 0..200 {
+        let old = (0..next(24)).map(|_| WORDS[next(4)]).collect::<Vec<_>>();
+        let new = (0..next(24)).map(|_| WORDS[next(4)]).collect::<Vec<_>>();
+
+        let ops = diff::lines(&old, &new);
+        let (a, b) = apply(&ops);
+        assert_eq!(old, a);
+        assert_eq!(new, b);
+
+        let equal = ops.iter().filter(|op| !op.is_change()).count();
+        assert_eq!(lcs(&old, &new), equal, "{old:?} -> {new:?}");
+    }
+}
+
+#[test]
+fn test_large_rewrite() {
+    // Keeping every V vector of the forward search around would need about a
+    // gigabyte for this input.
+    let old = (0..4000).map(|n| format!("old {n}")).collect::<Vec<_>>();
+    let new = (0..4000).map(|n| format!("new {n}")).collect::<Vec<_>>();
+    let old = old.iter().map(String::as_str).collect::<Vec<_>>();
+    let new = new.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let ops = diff::lines(&old, &new);
+    assert_eq!(8000, ops.len());
+
+    // Deletions in a changed run come before the insertions.
+    assert!(ops[..4000]
+        .iter()
+        .all(|op| matches!(op, DiffOp::Delete(..))));
+    assert!(ops[4000..]
+        .iter()
+        .all(|op| matches!(op, DiffOp::Insert(..))));
+}
@@ -0,0 +1,63 @@
+use genco::fmt;
+use genco::prelude::*;
+
+fn format(tokens: &rust::Tokens, edition: rust::Edition) -> fmt::Result<Vec<String>> {
+    let fmt = fmt::Config::from_lang::<Rust>();
+    let config = rust::Config::default().with_edition(edition);
+
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    Ok(w.into_vec())
+}
+
+#[test]
+fn test_extern_crate_cfg() -> fmt::Result {
+    let ser = rust::import("serde", "Serialize").with_cfg("feature = \"serde\"");
+    let de = rust::import("serde", "Deserialize").with_cfg("feature = \"de\"");
+    let rng = rust::import("rand::rngs", "SmallRng");
+    let local = rust::import("crate::util", "helper");
+
+    let tokens: rust::Tokens = quote!($ser $de $rng $local);
+
+    assert_eq!(
+        vec![
+            "extern crate rand;",
+            "#[cfg(feature = \"de\")]",
+            "extern crate serde;",
+            "#[cfg(feature = \"serde\")]",
+            "extern crate serde;",
+            "",
+            "use crate::util::helper;",
+            "use rand::rngs::SmallRng;",
+            "#[cfg(feature = \"de\")]",
+            "use serde::Deserialize;",
+            "#[cfg(feature = \"serde\")]",
+            "use serde::Serialize;",
+            "",
+            "Serialize Deserialize SmallRng helper",
+        ],
+        format(&tokens, rust::Edition::E2015)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_prelude() -> fmt::Result {
+    let future = rust::import("core::future", "Future");
+    let from_iter = rust::import("std::iter", "FromIterator");
+
+    let tokens: rust::Tokens = quote!($future $from_iter);
+
+    assert_eq!(
+        vec!["use core::future::Future;", "", "Future FromIterator",],
+        format(&tokens, rust::Edition::E2021)?
+    );
+
+    assert_eq!(
+        vec!["Future FromIterator"],
+        format(&tokens, rust::Edition::E2024)?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use std::path::{Path, PathBuf};
+
+use genco::fmt;
+use genco::prelude::*;
+use genco::testing;
+
+fn golden_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(name)
+}
+
+#[test]
+fn test_golden_rust() {
+    let map = rust::import("std::collections", "HashMap");
+
+    let tokens: rust::Tokens = quote! {
+        pub fn counts(words: &[&str]) -> $(&map)<String, usize> {
+            let mut counts = $(&map)::new();
+
+            for word in words {
+                *counts.entry(word.to_string()).or_default() += 1;
+            }
+
+            counts
+        }
+    };
+
+    testing::golden(golden_path("counts.rs"), &tokens);
+}
+
+#[test]
+fn test_golden_go() {
+    let println = &go::import("fmt", "Println");
+
+    let tokens: go::Tokens = quote! {
+        func main() {
+            $println($(quoted("Hello World")))
+        }
+    };
+
+    let fmt = fmt::Config::from_lang::<Go>();
+    let config = go::Config::default().with_package("main");
+
+    testing::golden_with(golden_path("main.go"), &tokens, &fmt, &config);
+}
@@ -0,0 +1,49 @@
+use genco::prelude::*;
+
+#[test]
+fn test_imports_here() -> genco::fmt::Result {
+    let map = rust::import("std::collections", "HashMap");
+    let set = rust::import("std::collections", "HashSet");
+
+    let mut tokens = rust::Tokens::new();
+    quote_in!(tokens => $("//! Generated with a") $map);
+    tokens.line();
+    tokens.imports_here();
+    quote_in!(tokens => let s = $set::new(););
+    tokens.imports_here();
+
+    assert_eq!(
+        vec![
+            "//! Generated with a HashMap",
+            "",
+            "use std::collections::{HashMap, HashSet};",
+            "",
+            "let s = HashSet::new();",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_imports_here_missing() -> genco::fmt::Result {
+    let map = rust::import("std::collections", "HashMap");
+
+    let mut tokens = rust::Tokens::new();
+    quote_in!(tokens => $("// Banner"));
+    tokens.push();
+    quote_in!(tokens => let m = $map::new(););
+
+    assert_eq!(
+        vec![
+            "use std::collections::HashMap;",
+            "",
+            "// Banner",
+            "let m = HashMap::new();",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
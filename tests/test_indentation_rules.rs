@@ -34,3 +34,75 @@ fn test_indentation_rules() -> genco::fmt::Result {
 
     Ok(())
 }
+
+#[test]
+fn test_unindent_below_zero() -> genco::fmt::Result {
+    use genco::fmt::{self, Warning};
+
+    let mut tokens = rust::Tokens::new();
+    tokens.append("a");
+    tokens.unindent();
+    tokens.append("b");
+    tokens.indent();
+    tokens.append("c");
+
+    // Unindenting below zero is clamped by default, and reported as a warning.
+    assert_eq!(vec!["a", "b", "c"], tokens.to_file_vec()?);
+
+    let fmt = fmt::Config::from_lang::<Rust>();
+    let mut w = fmt::VecWriter::new();
+    let report =
+        tokens.format_file_with_report(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    assert_eq!(
+        &[Warning::UnindentedBelowZero { item: 1 }],
+        report.warnings()
+    );
+
+    // Strict mode for dynamic strings doesn't affect indentation.
+    let fmt = fmt::Config::from_lang::<Rust>().with_strict(true);
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    assert_eq!(vec!["a", "b", "c"], w.into_vec());
+
+    let fmt = fmt::Config::from_lang::<Rust>().with_strict_indentation(true);
+    let mut w = fmt::VecWriter::new();
+    let mut out = w.as_formatter(&fmt);
+
+    assert!(tokens
+        .format_file(&mut out, &rust::Config::default())
+        .is_err());
+    assert_eq!(&[Warning::UnindentedBelowZero { item: 1 }], out.warnings());
+
+    // Balanced indentation is fine in strict mode.
+    let tokens: rust::Tokens = quote!(fn test() {$[indent]a();$[unindent]});
+
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    assert_eq!(vec!["fn test() {", "    a();", "}"], w.into_vec());
+    Ok(())
+}
+
+#[test]
+fn test_unindent_below_zero_in_group() -> genco::fmt::Result {
+    use genco::fmt::{self, Warning};
+    use genco::tokens::Item;
+
+    let mut tokens = rust::Tokens::new();
+    tokens.append("a");
+    tokens.append(Item::OpenGroup);
+    tokens.append("b");
+    tokens.unindent();
+    tokens.append(Item::CloseGroup);
+
+    // The group fits on one line, so the warning from formatting it flat is
+    // reported once with the index of the item in the whole stream.
+    let fmt = fmt::Config::from_lang::<Rust>();
+    let mut w = fmt::VecWriter::new();
+    let report =
+        tokens.format_file_with_report(&mut w.as_formatter(&fmt), &rust::Config::default())?;
+    assert_eq!(
+        &[Warning::UnindentedBelowZero { item: 3 }],
+        report.warnings()
+    );
+    Ok(())
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "ir")]
+
+use genco::prelude::*;
+
+type Result<T = ()> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+#[test]
+fn test_python_roundtrip() -> Result {
+    let sys = python::import_module("sys");
+    let path = python::import("os", "path");
+
+    let tokens: python::Tokens = quote! {
+        print($sys.argv, $path.join("a", $("b")))
+    };
+
+    let decoded = python::Tokens::from_ir(&tokens.to_ir()?)?;
+    assert_eq!(tokens.to_file_string()?, decoded.to_file_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_js_roundtrip() -> Result {
+    let react = js::import("react", "React").into_default();
+    let local = js::import(js::Module::Path("./local".into()), "helper");
+
+    let tokens: js::Tokens = quote! {
+        $react.render($local());
+    };
+
+    let decoded = js::Tokens::from_ir(&tokens.to_ir()?)?;
+    assert_eq!(tokens.to_file_string()?, decoded.to_file_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_nix_roundtrip() -> Result {
+    let pkgs = nix::inherit("inputs", "nixpkgs");
+    let value = nix::variable("value", quote!(import ./value.nix));
+
+    let tokens: nix::Tokens = quote! {
+        { inherit $pkgs; value = $value; }
+    };
+
+    let decoded = nix::Tokens::from_ir(&tokens.to_ir()?)?;
+    assert_eq!(tokens.to_file_string()?, decoded.to_file_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_quoted_roundtrip() -> Result {
+    let tokens: csharp::Tokens = quote!(var s = $[str](Hello $(name)!););
+
+    let decoded = csharp::Tokens::from_ir(&tokens.to_ir()?)?;
+    assert_eq!("var s = $\"Hello {name}!\";", decoded.to_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_display_value_roundtrip() -> Result {
+    let tokens: rust::Tokens = quote!(let a = $(genco::tokens::display_quoted(42u32)););
+
+    let decoded = rust::Tokens::from_ir(&tokens.to_ir()?)?;
+    assert_eq!(tokens.to_string()?, decoded.to_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_invalid_ir() {
+    assert!(rust::Tokens::from_ir("[]").is_err());
+    assert!(rust::Tokens::from_ir(r#"{"version":2,"items":[]}"#).is_err());
+    assert!(rust::Tokens::from_ir(r#"{"version":1,"items":[{"kind":"bogus"}]}"#).is_err());
+}
+
+#[test]
+fn test_config_override_is_not_serialized() {
+    let tokens: rust::Tokens =
+        quote!($(genco::tokens::with_config_override("a", |c: rust::Config| c, quote!(a))));
+    assert!(tokens.to_ir().is_err());
+}
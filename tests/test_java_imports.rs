@@ -0,0 +1,130 @@
+use genco::prelude::*;
+
+#[test]
+fn test_static_import_does_not_shadow_class() -> genco::fmt::Result {
+    let value_of = java::static_import("com.example.Values", "Value");
+    let value = java::import("com.example.model", "Value");
+
+    let toks: java::Tokens = quote! {
+        $(&value) v = $(&value_of)(1);
+    };
+
+    assert_eq!(
+        vec![
+            "import static com.example.Values.Value;",
+            "",
+            "import com.example.model.Value;",
+            "",
+            "Value v = Value(1);",
+        ],
+        toks.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_wildcard_conflicts() -> genco::fmt::Result {
+    let awt = java::import("java.awt", "List");
+    let util = java::import("java.util", "List");
+    let map = java::import("java.util", "Map");
+
+    let toks: java::Tokens = quote! {
+        $(register(java::import_all("java.util")))
+        $(register(java::import_all("java.lang")))
+        $awt $util $map
+    };
+
+    assert_eq!(
+        vec![
+            "import java.awt.List;",
+            "import java.util.*;",
+            "",
+            "List java.util.List Map",
+        ],
+        toks.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_wildcard_threshold_several_packages() -> genco::fmt::Result {
+    use genco::fmt;
+
+    let color = java::import("java.awt", "Color");
+    let frame = java::import("java.awt", "Frame");
+    let map = java::import("java.util", "Map");
+    let optional = java::import("java.util", "Optional");
+    let file = java::import("java.io", "File");
+
+    let toks: java::Tokens = quote!($color $frame $map $optional $file);
+
+    let config = java::Config::default().with_wildcard_threshold(1);
+    let fmt = fmt::Config::from_lang::<Java>();
+
+    let mut w = fmt::VecWriter::new();
+    toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+
+    // Both wildcards could provide a `List`, so neither package is collapsed.
+    assert_eq!(
+        vec![
+            "import java.awt.Color;",
+            "import java.awt.Frame;",
+            "import java.io.File;",
+            "import java.util.Map;",
+            "import java.util.Optional;",
+            "",
+            "Color Frame Map Optional File",
+        ],
+        w.into_vec()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_wildcard_threshold_with_import_all() -> genco::fmt::Result {
+    use genco::fmt;
+
+    let color = java::import("java.awt", "Color");
+    let frame = java::import("java.awt", "Frame");
+    let map = java::import("java.util", "Map");
+
+    let toks: java::Tokens =
+        quote!($(&color) $(&frame) $(&map)$(register(java::import_all("java.util"))));
+
+    let config = java::Config::default().with_wildcard_threshold(1);
+    let fmt = fmt::Config::from_lang::<Java>();
+
+    let mut w = fmt::VecWriter::new();
+    toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+
+    assert_eq!(
+        vec![
+            "import java.awt.Color;",
+            "import java.awt.Frame;",
+            "import java.util.*;",
+            "",
+            "Color Frame Map",
+        ],
+        w.into_vec()
+    );
+
+    let toks: java::Tokens = quote!($color $frame $map);
+
+    let mut w = fmt::VecWriter::new();
+    toks.format_file(&mut w.as_formatter(&fmt), &config)?;
+
+    assert_eq!(
+        vec![
+            "import java.awt.*;",
+            "import java.util.Map;",
+            "",
+            "Color Frame Map"
+        ],
+        w.into_vec()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use genco::fmt;
+use genco::prelude::*;
+use genco::tokens::chain;
+
+type Mappings = Vec<(usize, usize, String)>;
+
+/// Format the given tokens, returning the output and the mappings recorded.
+fn format(tokens: &rust::Tokens, fmt: &fmt::Config) -> fmt::Result<(Vec<String>, Mappings)> {
+    let mut w = fmt::VecWriter::new();
+    tokens.format_file(&mut w.as_formatter(fmt), &rust::Config::default())?;
+
+    let mappings = w
+        .mappings()
+        .iter()
+        .map(|m| (m.line(), m.column(), m.origin().to_owned()))
+        .collect();
+
+    Ok((w.into_vec(), mappings))
+}
+
+fn origin<T>(tokens: T, origin: &'static str) -> impl FormatInto<Rust>
+where
+    T: FormatInto<Rust>,
+{
+    genco::tokens::from_fn(move |t| t.append_with_origin(tokens, origin))
+}
+
+#[test]
+fn test_groups() -> fmt::Result {
+    let calls = [origin(quote!(map(f)), "map"), origin(quote!(sum()), "sum")];
+
+    let tokens: rust::Tokens = quote! {
+        fn total() -> u32 {
+            $(origin(quote!(items.iter()), "receiver"))$(chain(quote!(), calls))
+        }
+    };
+
+    let fmt = fmt::Config::from_lang::<Rust>();
+
+    assert_eq!(
+        (
+            vec![
+                "fn total() -> u32 {".to_owned(),
+                "    items.iter().map(f).sum()".to_owned(),
+                "}".to_owned(),
+            ],
+            vec![
+                (1, 4, "receiver".to_owned()),
+                (1, 17, "map".to_owned()),
+                (1, 24, "sum".to_owned()),
+            ]
+        ),
+        format(&tokens, &fmt)?
+    );
+
+    let fmt = fmt::Config::from_lang::<Rust>().with_max_width(20);
+
+    assert_eq!(
+        (
+            vec![
+                "fn total() -> u32 {".to_owned(),
+                "    items.iter()".to_owned(),
+                "        .map(f)".to_owned(),
+                "        .sum()".to_owned(),
+                "}".to_owned(),
+            ],
+            vec![
+                (1, 4, "receiver".to_owned()),
+                (2, 9, "map".to_owned()),
+                (3, 9, "sum".to_owned()),
+            ]
+        ),
+        format(&tokens, &fmt)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_tabs_and_multiline_literals() -> fmt::Result {
+    let tokens: rust::Tokens = quote! {
+        $(origin(quote!($("/* a\nb */")), "comment"))
+        fn main() {
+            $(origin(quote!(run();), "run"))
+        }
+    };
+
+    let fmt = fmt::Config::from_lang::<Rust>().with_indentation(fmt::Indentation::Tab);
+
+    assert_eq!(
+        (
+            vec![
+                "/* a\nb */".to_owned(),
+                "fn main() {".to_owned(),
+                "\trun();".to_owned(),
+                "}".to_owned(),
+            ],
+            vec![(0, 0, "comment".to_owned()), (3, 1, "run".to_owned())]
+        ),
+        format(&tokens, &fmt)?
+    );
+
+    Ok(())
+}
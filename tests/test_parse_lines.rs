@@ -0,0 +1,39 @@
+use std::path::Path;
+
+use genco::fmt;
+use genco::prelude::*;
+use genco::tokens::parse_lines;
+
+fn golden(name: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(name);
+
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn test_roundtrip_golden() -> fmt::Result {
+    let source = golden("counts.rs");
+    let tokens: rust::Tokens = parse_lines(&source);
+    assert_eq!(source, tokens.to_file_string()?);
+
+    // NB: Go is indented with tabs.
+    let source = golden("main.go");
+    let tokens: go::Tokens = parse_lines(&source);
+    assert_eq!(source, tokens.to_file_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_uneven_indentation() -> fmt::Result {
+    let tokens: js::Tokens = parse_lines("\n\n  a {\n      b\n    c\n  }\n\n\n  d\n");
+
+    assert_eq!(
+        vec!["a {", "    b", "    c", "}", "", "d"],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
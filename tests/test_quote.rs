@@ -77,3 +77,42 @@ fn test_scope() -> genco::fmt::Result {
 
     Ok(())
 }
+
+#[test]
+fn test_soft_space() -> genco::fmt::Result {
+    let modifiers = ["pub", "", "static"];
+
+    let tokens: java::Tokens = quote! {
+        class Foo {
+            $(for m in modifiers => $m$[~])int value;
+            $[~]$("")$[~]void run();
+        }
+    };
+
+    assert_eq!(
+        vec![
+            "class Foo {",
+            "    pub static int value;",
+            "    void run();",
+            "}",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_closure() -> genco::fmt::Result {
+    let name = String::from("value");
+
+    let tokens: rust::Tokens = quote!(let $(move |t| t.append(name)) = $(|t| quote_in!(*t => 42)););
+    assert_eq!("let value = 42;", tokens.to_string()?);
+
+    let count = 2;
+    let mut tokens = rust::Tokens::new();
+    quote_in!(tokens => $(|t| for n in 0..count { quote_in!(*t => $n) }));
+    assert_eq!("01", tokens.to_string()?);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use genco::prelude::*;
+
+macro_rules! check {
+    ($($tt:tt)*) => {{
+        let tokens: rust::Tokens = quote!($($tt)*);
+        assert_eq!(quote_literal!($($tt)*), tokens.to_string().unwrap());
+    }};
+}
+
+#[test]
+fn test_quote_literal_matches_quote() {
+    check! {
+        mod foo {
+            fn bar() {
+                if true {
+                    println!("a \"quoted\" string");
+                }
+
+
+                return;
+            }
+        }
+
+        fn baz() {}
+    };
+
+    check!(a b   c);
+    check!();
+}
+
+#[test]
+fn test_quote_literal_const() {
+    const TEMPLATE: &str = quote_literal! {
+        struct Foo {
+            field: u32,
+        }
+    };
+
+    assert_eq!("struct Foo {\n    field: u32,\n}", TEMPLATE);
+}
+
+#[test]
+fn test_quote_literal_escapes() {
+    check!(let s = "tab\t cr\r nul\0 bell\x07 del\x7f nbsp\u{a0} \"\\");
+}
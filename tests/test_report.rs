@@ -0,0 +1,51 @@
+use genco::fmt;
+use genco::prelude::*;
+
+fn imports<L>(tokens: &Tokens<L>, config: &L::Config) -> fmt::Result<Vec<L::Item>>
+where
+    L: Lang,
+{
+    let mut w = fmt::VecWriter::new();
+    let fmt = fmt::Config::from_lang::<L>();
+    let report = tokens.format_file_with_report(&mut w.as_formatter(&fmt), config)?;
+    Ok(report.imports().to_vec())
+}
+
+#[test]
+fn test_java_qualified_imports() -> fmt::Result {
+    let a = java::import("java.util", "List");
+    let b = java::import("java.awt", "List");
+    let c = java::import("com.example", "Foo");
+    let d = java::import("java.lang", "Integer");
+    let tokens: java::Tokens = quote!($(&a) $(&b) $(&c) $(&d));
+
+    let config = java::Config::default().with_package("com.example");
+    assert_eq!(vec![b], imports(&tokens, &config)?);
+    Ok(())
+}
+
+#[test]
+fn test_rust_conflicting_imports() -> fmt::Result {
+    let a = rust::import("std::fmt", "Result");
+    let b = rust::import("std::io", "Result");
+    let tokens: rust::Tokens = quote!($(&a) $(&b));
+
+    assert_eq!(vec![a.clone()], imports(&tokens, &rust::Config::default())?);
+
+    let config = rust::Config::default().with_conflict_mode(rust::ConflictMode::Alias);
+
+    let mut expected = vec![a, b];
+    expected.sort();
+    assert_eq!(expected, imports(&tokens, &config)?);
+    Ok(())
+}
+
+#[test]
+fn test_csharp_conflicting_imports() -> fmt::Result {
+    let a = csharp::import("Foo.Bar", "B");
+    let b = csharp::import("Foo.Baz", "B");
+    let tokens: csharp::Tokens = quote!($(&a) $(&b));
+
+    assert_eq!(vec![a], imports(&tokens, &csharp::Config::default())?);
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use genco::lang::*;
+use genco::Tokens;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_tokens_are_send_sync() {
+    assert_send_sync::<Tokens<()>>();
+    assert_send_sync::<Tokens<C>>();
+    assert_send_sync::<Tokens<Csharp>>();
+    assert_send_sync::<Tokens<Dart>>();
+    assert_send_sync::<Tokens<Go>>();
+    assert_send_sync::<Tokens<Java>>();
+    assert_send_sync::<Tokens<JavaScript>>();
+    assert_send_sync::<Tokens<Julia>>();
+    assert_send_sync::<Tokens<Matlab>>();
+    assert_send_sync::<Tokens<Nix>>();
+    assert_send_sync::<Tokens<Php>>();
+    assert_send_sync::<Tokens<Python>>();
+    assert_send_sync::<Tokens<Rust>>();
+    assert_send_sync::<Tokens<Swift>>();
+    assert_send_sync::<Tokens<TypeScript>>();
+    assert_send_sync::<Tokens<Verilog>>();
+    assert_send_sync::<Tokens<Vhdl>>();
+    assert_send_sync::<Tokens<Xml>>();
+}
+
+fn assert_send<T: Send>() {}
+
+#[test]
+fn test_boxed_fragments_are_send() {
+    assert_send::<genco::tokens::BoxFormatInto<'static, Rust>>();
+}
+
+#[test]
+fn test_boxed_dyn_fragments_are_send() -> genco::fmt::Result {
+    use genco::tokens::{DynFormatInto, FormatInto};
+
+    assert_send::<Box<dyn DynFormatInto<Rust> + Send>>();
+
+    let fragment: Box<dyn DynFormatInto<Rust> + Send> = Box::new(genco::quote_fn!(a));
+    let fragments = vec![fragment, genco::quote_fn!(b).boxed()];
+    let handle = std::thread::spawn(move || {
+        let mut tokens = Tokens::<Rust>::new();
+        tokens.append(fragments);
+        tokens
+    });
+
+    assert_eq!("ab", handle.join().unwrap().to_string()?);
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use genco::prelude::*;
+use genco::tokens::StaticTokens;
+
+static BODY: StaticTokens<Rust> = static_quote! {
+    fn greet() {
+        println!("Hello World");
+
+        if true {
+            return;
+        }
+    }
+};
+
+#[test]
+fn test_static_quote_matches_quote() -> genco::fmt::Result {
+    let expected: rust::Tokens = quote! {
+        fn greet() {
+            println!("Hello World");
+
+            if true {
+                return;
+            }
+        }
+    };
+
+    let tokens: rust::Tokens = quote!($BODY);
+
+    assert_eq!(expected.to_file_vec()?, tokens.to_file_vec()?);
+    Ok(())
+}
+
+#[test]
+fn test_static_quote_reuse() -> genco::fmt::Result {
+    const HEADER: StaticTokens<Rust> = static_quote!(#![allow(dead_code)]);
+
+    let tokens: rust::Tokens = quote! {
+        $HEADER
+        $HEADER
+    };
+
+    assert_eq!(
+        vec!["#![allow(dead_code)]", "#![allow(dead_code)]"],
+        tokens.to_file_vec()?
+    );
+
+    let empty: StaticTokens<Rust> = static_quote!();
+    assert!(empty.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_static_quote_controls_and_strings() -> genco::fmt::Result {
+    const TOKENS: StaticTokens<Rust> = static_quote!(let a =$[~]$[str](hello world); $("b"));
+
+    let expected: rust::Tokens = quote!(let a =$[~]$[str](hello world); $("b"));
+    let tokens: rust::Tokens = quote!($TOKENS);
+
+    assert_eq!(expected, tokens);
+    assert_eq!("let a = \"hello world\"; b", tokens.to_string()?);
+    Ok(())
+}
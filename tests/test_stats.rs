@@ -0,0 +1,23 @@
+use genco::prelude::*;
+use genco::tokens::delimited;
+
+#[test]
+fn test_nested_depth() {
+    let toks: js::Tokens = quote! {
+        function foo() {
+            return $(delimited("[", "]", quote!($(quoted("a")), $(quoted("b")))));
+        }
+    };
+
+    let stats = toks.stats();
+
+    // NB: indentation, the delimited group, its soft indentation and the
+    // quotes of the strings.
+    assert_eq!(4, stats.depth());
+    assert_eq!(0, stats.lang_items());
+    // NB: strings which are passed by reference are copied.
+    assert_eq!("[ab]".len(), stats.boxed_bytes());
+
+    let empty = js::Tokens::new();
+    assert_eq!(genco::tokens::TokenStats::default(), empty.stats());
+}
@@ -25,3 +25,53 @@ fn test_string_in_string_in() -> genco::fmt::Result {
     assert_eq!("`Hello ${`${\"World\"}`}`", t.to_string()?);
     Ok(())
 }
+
+#[test]
+fn test_clipped() -> genco::fmt::Result {
+    use genco::tokens::clipped;
+
+    let t: rust::Tokens = quote!($(quoted(clipped("😊😊😊😊😊", 5))));
+    assert_eq!("\"😊😊😊😊😊\"", t.to_string()?);
+
+    let t: rust::Tokens = quote!($(quoted(clipped("😊😊😊😊😊😊", 5))));
+    assert_eq!("\"😊😊...\"", t.to_string()?);
+
+    let t: rust::Tokens = quote!($(quoted(clipped("abcdef", 2))));
+    assert_eq!("\"..\"", t.to_string()?);
+
+    let t: rust::Tokens = quote!($(quoted(clipped("a\r\nb\0c", 10))));
+    assert_eq!("\"a  bc\"", t.to_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_display_quoted() -> genco::fmt::Result {
+    use genco::fmt;
+    use genco::tokens::{display, display_quoted, DisplayValue};
+
+    let t: rust::Tokens = quote!(f($(display_quoted("a \"b\"")), $(display_quoted(1.5f32))));
+    assert_eq!("f(\"a \\\"b\\\"\", \"1.5\")", t.to_string()?);
+
+    // Displayed values compare the same as the literal they display as.
+    let t: rust::Tokens = quote!($(DisplayValue::new(42u32)));
+    let expected: rust::Tokens = quote!($(display(42u32)));
+    assert_ne!(expected, t);
+    assert_eq!(quote!($(DisplayValue::new(String::from("42")))), t);
+
+    // Inside of strings, they are escaped like the rest of the string.
+    let t: rust::Tokens = quote!($[str](v$[const](DisplayValue::new("\"2\""))));
+    assert_eq!("\"v\\\"2\\\"\"", t.to_string()?);
+
+    // Dynamic values are checked in strict mode.
+    let t: rust::Tokens = quote!(let a = $(DisplayValue::new("\"); b(\"")););
+    let fmt = fmt::Config::from_lang::<Rust>().with_strict(true);
+    let mut w = fmt::VecWriter::new();
+    assert!(t
+        .format(
+            &mut w.as_formatter(&fmt),
+            &rust::Config::default(),
+            &rust::Format::default()
+        )
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use genco::prelude::*;
+use genco::template;
+
+#[test]
+fn test_whitespace() -> Result<(), Box<dyn std::error::Error>> {
+    let template = template::parse::<Python>(
+        "
+        class $name:
+            def first(self):
+                return   $value
+
+                    # nested
+
+
+            def second(self):
+                pass
+
+        print($name)
+        ",
+    )?;
+
+    let tokens = template.render([("name", "Foo"), ("value", "42")])?;
+
+    assert_eq!(
+        vec![
+            "class Foo:",
+            "    def first(self):",
+            "        return 42",
+            "",
+            "            # nested",
+            "",
+            "    def second(self):",
+            "        pass",
+            "",
+            "print(Foo)",
+        ],
+        tokens.to_file_vec()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_errors() {
+    let error = template::parse::<()>("call($(name").unwrap_err();
+    assert_eq!((1, 6), (error.line(), error.column()));
+
+    let error = template::parse::<()>("$(1abc)").unwrap_err();
+    assert_eq!("1:1: expected a name after `$`", error.to_string());
+}
+
+#[test]
+fn test_error_columns_count_chars() {
+    let error = template::parse::<()>("é $!").unwrap_err();
+    assert_eq!((1, 3), (error.line(), error.column()));
+
+    let error = template::parse::<()>("  ö\n  ä $!").unwrap_err();
+    assert_eq!((2, 5), (error.line(), error.column()));
+
+    let error = template::parse::<()>("\"ü \\q\"").unwrap_err();
+    assert_eq!("1:4: unknown escape in string", error.to_string());
+}
+
+#[test]
+fn test_strings() -> Result<(), Box<dyn std::error::Error>> {
+    let template = template::parse::<Rust>("let $name = \"a  \\\"b\\\"\\n$$\";")?;
+    let tokens = template.render([("name", "s")])?;
+    assert_eq!("let s = \"a  \\\"b\\\"\\n$\";", tokens.to_string()?);
+
+    let template = template::parse::<JavaScript>("greet(\"Hello $name!\")")?;
+    assert_eq!(vec!["name"], template.params().collect::<Vec<_>>());
+    let tokens = template.render([("name", quote!(user.name))])?;
+    assert_eq!("greet(`Hello ${user.name}!`)", tokens.to_string()?);
+
+    let template = template::parse::<Python>("print(\"$(greeting), $name\")")?;
+    let tokens = template.render([("greeting", "hi"), ("name", "x")])?;
+    assert_eq!("print(f\"{hi}, {x}\")", tokens.to_string()?);
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use genco::fmt;
+use genco::prelude::*;
+use genco::tokens::{delimited, separated_with_style};
+
+const ITEMS: &[&str] = &["first_item", "second_item", "third_item"];
+
+fn format<L>(toks: &Tokens<L>, config: &L::Config) -> fmt::Result<Vec<String>>
+where
+    L: genco::lang::Lang,
+{
+    let fmt = fmt::Config::from_lang::<L>().with_max_width(30);
+    let mut w = fmt::VecWriter::new();
+    toks.format_file(&mut w.as_formatter(&fmt), config)?;
+    Ok(w.into_vec())
+}
+
+#[test]
+fn test_trailing_commas_per_language() -> fmt::Result {
+    let rust: rust::Tokens = quote!(let a = vec!$(delimited("[", "]", separated_with_style(",", ITEMS.iter().copied()))););
+    let js: js::Tokens =
+        quote!(const a = $(delimited("[", "]", separated_with_style(",", ITEMS.iter().copied()))););
+    let java: java::Tokens =
+        quote!(int[] a = $(delimited("{", "}", separated_with_style(",", ITEMS.iter().copied()))););
+
+    assert_eq!(
+        vec![
+            "let a = vec![",
+            "    first_item,",
+            "    second_item,",
+            "    third_item,",
+            "];",
+        ],
+        format(&rust, &rust::Config::default().with_trailing_commas(true))?
+    );
+
+    assert_eq!(
+        vec![
+            "const a = [",
+            "    first_item,",
+            "    second_item,",
+            "    third_item,",
+            "];",
+        ],
+        format(&js, &js::Config::default().with_trailing_commas(true))?
+    );
+
+    assert_eq!(
+        vec![
+            "int[] a = {",
+            "    first_item,",
+            "    second_item,",
+            "    third_item",
+            "};",
+        ],
+        format(&java, &java::Config::default())?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_no_trailing_comma_on_one_line() -> fmt::Result {
+    let toks: rust::Tokens =
+        quote!(foo$(delimited("(", ")", separated_with_style(",", ["a", "b"]))););
+    let config = rust::Config::default().with_trailing_commas(true);
+    assert_eq!(vec!["foo(a, b);"], toks.with_config(config).to_vec()?);
+    Ok(())
+}
+
+#[test]
+fn test_where_clause_trailing_commas() -> fmt::Result {
+    let toks: rust::Tokens = quote! {
+        impl<T, U> Foo for Bar$(rust::where_clause([rust::bounded("T", ["Clone", "Send"]), rust::bounded("U", ["Sync", "Unpin"])])) {}
+    };
+
+    assert_eq!(
+        vec![
+            "impl<T, U> Foo for Bar",
+            "where",
+            "    T: Clone + Send,",
+            "    U: Sync + Unpin,",
+            "{}",
+        ],
+        format(&toks, &rust::Config::default().with_trailing_commas(true))?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_separated_outside_of_delimited() -> fmt::Result {
+    let short: rust::Tokens = quote!(let x = [$(separated_with_style(",", ["a", "b"]))];);
+    let long: rust::Tokens = quote!(let x = [$(separated_with_style(",", ITEMS.iter().copied()))];);
+
+    let config = rust::Config::default().with_trailing_commas(true);
+
+    assert_eq!(vec!["let x = [a, b];"], format(&short, &config)?);
+
+    assert_eq!(
+        vec!["let x = [first_item,", "second_item,", "third_item,];"],
+        format(&long, &config)?
+    );
+
+    Ok(())
+}
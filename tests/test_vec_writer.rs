@@ -0,0 +1,24 @@
+use genco::prelude::*;
+
+#[test]
+fn test_reuses_vector() -> genco::fmt::Result {
+    let long: rust::Tokens = quote!(a$['\r']b$['\r']c$['\r']d);
+    let short: rust::Tokens = quote!(e$['\r']f);
+
+    let mut lines = Vec::new();
+
+    long.to_file_vec_into(&mut lines)?;
+    assert_eq!(vec!["a", "b", "c", "d"], lines);
+
+    let ptr = lines.as_ptr();
+    let strings = lines.iter().map(|line| line.as_ptr()).collect::<Vec<_>>();
+
+    short.to_file_vec_into(&mut lines)?;
+    assert_eq!(vec!["e", "f"], lines);
+    assert_eq!(ptr, lines.as_ptr());
+    assert!(lines.iter().all(|line| strings.contains(&line.as_ptr())));
+
+    long.to_file_vec_into(&mut lines)?;
+    assert_eq!(vec!["a", "b", "c", "d"], lines);
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use genco::fmt::{self, Conflict, Warning};
+use genco::prelude::*;
+
+fn warnings<L>(tokens: &Tokens<L>, config: &L::Config) -> fmt::Result<Vec<Warning>>
+where
+    L: Lang,
+{
+    let mut w = fmt::VecWriter::new();
+    let fmt = fmt::Config::from_lang::<L>();
+    let report = tokens.format_file_with_report(&mut w.as_formatter(&fmt), config)?;
+    Ok(report.warnings().to_vec())
+}
+
+#[test]
+fn test_rust_conflicts() -> fmt::Result {
+    let a = rust::import("std::fmt", "Result");
+    let b = rust::import("std::io", "Result");
+    let tokens: rust::Tokens = quote!($a $b);
+
+    assert_eq!(
+        vec![Warning::Qualified {
+            module: String::from("std::io"),
+            name: String::from("Result"),
+            conflict: Conflict::Module(String::from("std::fmt")),
+        }],
+        warnings(&tokens, &rust::Config::default())?
+    );
+
+    let config = rust::Config::default().with_conflict_mode(rust::ConflictMode::Alias);
+
+    assert_eq!(
+        vec![Warning::Aliased {
+            module: String::from("std::io"),
+            name: String::from("Result"),
+            alias: String::from("IoResult"),
+            conflict: Conflict::Module(String::from("std::fmt")),
+        }],
+        warnings(&tokens, &config)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_csharp_conflicts() -> fmt::Result {
+    let a = csharp::import("Foo.Bar", "B");
+    let b = csharp::import("Foo.Baz", "B");
+    let tokens: csharp::Tokens = quote!($a $b);
+
+    assert_eq!(
+        vec![Warning::Qualified {
+            module: String::from("Foo.Baz"),
+            name: String::from("B"),
+            conflict: Conflict::Module(String::from("Foo.Bar")),
+        }],
+        warnings(&tokens, &csharp::Config::default())?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_python_conflicts() -> fmt::Result {
+    let a = python::import("collections", "OrderedDict");
+    let b = python::import("ordered", "Dict").with_alias("OrderedDict");
+    let tokens: python::Tokens = quote!($a $b);
+
+    assert_eq!(
+        vec![Warning::Qualified {
+            module: String::from("ordered"),
+            name: String::from("Dict"),
+            conflict: Conflict::Module(String::from("collections")),
+        }],
+        warnings(&tokens, &python::Config::default())?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_no_conflicts() -> fmt::Result {
+    let a = rust::import("std::fmt", "Debug");
+    let tokens: rust::Tokens = quote!($a);
+    assert!(warnings(&tokens, &rust::Config::default())?.is_empty());
+    Ok(())
+}